@@ -1,3 +1,5 @@
+use egui::Color32;
+use egui::Mesh;
 use egui::Shape;
 use egui::Stroke;
 use egui::epaint::ColorMode;
@@ -11,8 +13,15 @@ use emath::pos2;
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum LineStyle {
     Solid,
-    Dotted { spacing: f32 },
-    Dashed { length: f32 },
+    Dotted {
+        spacing: f32,
+    },
+    Dashed {
+        length: f32,
+    },
+
+    /// An arbitrary sequence of dashes and gaps.
+    Pattern(DashPattern),
 }
 
 impl LineStyle {
@@ -32,6 +41,11 @@ impl LineStyle {
         Self::Dotted { spacing: 5.0 }
     }
 
+    /// Dashes and gaps of the given lengths, see [`DashPattern::new`].
+    pub fn dash_pattern(lengths: &[f32]) -> Self {
+        Self::Pattern(DashPattern::new(lengths))
+    }
+
     pub(crate) fn style_line(&self, line: Vec<Pos2>, mut stroke: PathStroke, highlight: bool, shapes: &mut Vec<Shape>) {
         let path_stroke_color = match &stroke.color {
             ColorMode::Solid(c) => *c,
@@ -75,6 +89,12 @@ impl LineStyle {
                             length * golden_ratio,
                         ));
                     }
+                    Self::Pattern(pattern) => {
+                        if highlight {
+                            stroke.width *= 2.0;
+                        }
+                        pattern.dash(&line, Stroke::new(stroke.width, path_stroke_color), shapes);
+                    }
                 }
             }
         }
@@ -87,10 +107,257 @@ impl std::fmt::Display for LineStyle {
             Self::Solid => write!(f, "Solid"),
             Self::Dotted { spacing } => write!(f, "Dotted({spacing} px)"),
             Self::Dashed { length } => write!(f, "Dashed({length} px)"),
+            Self::Pattern(pattern) => write!(f, "Pattern({:?} px)", pattern.lengths()),
         }
     }
 }
 
+/// A repeating sequence of dash and gap lengths along a line, in ui points.
+///
+/// See [`LineStyle::Pattern`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DashPattern {
+    lengths: [f32; Self::MAX_LEN],
+    len: usize,
+    phase: f32,
+}
+
+impl DashPattern {
+    /// The maximum number of lengths in a pattern.
+    pub const MAX_LEN: usize = 8;
+
+    /// Alternating dash and gap lengths, starting with a dash, e.g.
+    /// `[6.0, 3.0, 1.0, 3.0]` for dash-dot.
+    ///
+    /// As in SVG, an odd number of lengths is repeated to make it even. Only
+    /// the first [`Self::MAX_LEN`] lengths are used, and negative ones count
+    /// as zero. A pattern without any length draws a solid line.
+    pub fn new(lengths: &[f32]) -> Self {
+        let mut pattern = [0.0; Self::MAX_LEN];
+        let mut len = lengths.len().min(Self::MAX_LEN);
+        for (target, length) in pattern.iter_mut().zip(lengths) {
+            *target = length.max(0.0);
+        }
+        if len % 2 == 1 {
+            if 2 * len <= Self::MAX_LEN {
+                pattern.copy_within(..len, len);
+                len *= 2;
+            } else {
+                len -= 1;
+            }
+        }
+        Self {
+            lengths: pattern,
+            len,
+            phase: 0.0,
+        }
+    }
+
+    /// Start this far into the pattern, in ui points. Default: `0.0`.
+    #[inline]
+    pub fn phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// The dash and gap lengths, alternating.
+    pub fn lengths(&self) -> &[f32] {
+        &self.lengths[..self.len]
+    }
+
+    /// Push the dashes along `line`.
+    pub(crate) fn dash(&self, line: &[Pos2], stroke: Stroke, shapes: &mut Vec<Shape>) {
+        let lengths = self.lengths();
+        let total: f32 = lengths.iter().sum();
+        if total <= 0.0 || !total.is_finite() {
+            shapes.push(Shape::line(line.to_vec(), stroke));
+            return;
+        }
+
+        // Find where the phase falls in the pattern. Even steps are dashes.
+        let mut step = 0;
+        let mut remaining = lengths[0];
+        let mut offset = self.phase.rem_euclid(total);
+        while offset >= remaining {
+            offset -= remaining;
+            step = (step + 1) % lengths.len();
+            remaining = lengths[step];
+        }
+        remaining -= offset;
+
+        let mut dash = Vec::new();
+        if step % 2 == 0 {
+            dash.extend(line.first());
+        }
+        for segment in line.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let length = start.distance(end);
+            let mut position = 0.0;
+            while length - position > remaining {
+                position += remaining;
+                let point = start.lerp(end, position / length);
+                dash.push(point);
+                if step % 2 == 0 {
+                    shapes.push(Shape::line(std::mem::take(&mut dash), stroke));
+                }
+                step = (step + 1) % lengths.len();
+                remaining = lengths[step];
+            }
+            remaining -= length - position;
+            if step % 2 == 0 {
+                dash.push(end);
+            }
+        }
+        if step % 2 == 0 && dash.len() >= 2 {
+            shapes.push(Shape::line(dash, stroke));
+        }
+    }
+}
+
+/// Where a step line jumps between two consecutive values.
+///
+/// See [`crate::Line::step`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StepMode {
+    /// Jump at the start of the interval: each value holds to its left, back
+    /// to the previous point.
+    Pre,
+
+    /// Jump at the end of the interval: each value holds to its right, up to
+    /// the next point. This is a zero-order hold.
+    Post,
+
+    /// Jump halfway between two points.
+    Mid,
+}
+
+impl StepMode {
+    /// Insert the corners of the steps between the given points.
+    pub(crate) fn steps(self, points: &[Pos2]) -> Vec<Pos2> {
+        let mut steps = Vec::with_capacity(2 * points.len());
+        for (i, &point) in points.iter().enumerate() {
+            if let Some(&previous) = i.checked_sub(1).and_then(|i| points.get(i)) {
+                match self {
+                    Self::Pre => steps.push(pos2(previous.x, point.y)),
+                    Self::Post => steps.push(pos2(point.x, previous.y)),
+                    Self::Mid => {
+                        let x = 0.5 * (previous.x + point.x);
+                        steps.push(pos2(x, previous.y));
+                        steps.push(pos2(x, point.y));
+                    }
+                }
+            }
+            steps.push(point);
+        }
+        steps
+    }
+}
+
+/// Which regions of a self-overlapping outline, or of nested rings, are
+/// filled.
+///
+/// See [`crate::Polygon::fill_rule`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FillRule {
+    /// Fill where a ray to infinity crosses the outline an odd number of times.
+    /// Holes are left empty whatever their orientation.
+    #[default]
+    EvenOdd,
+
+    /// Fill where the outline winds around a non-zero number of times. Holes
+    /// must be oriented opposite to the outer ring to be left empty.
+    NonZero,
+}
+
+impl FillRule {
+    fn is_inside(self, winding: i32) -> bool {
+        match self {
+            Self::EvenOdd => winding % 2 != 0,
+            Self::NonZero => winding != 0,
+        }
+    }
+
+    /// Triangulate the area enclosed by the given closed rings.
+    ///
+    /// The area is cut into horizontal bands at every vertex and crossing of
+    /// edges, each band being a set of trapezoids.
+    pub(crate) fn fill(self, rings: &[Vec<Pos2>], color: Color32) -> Mesh {
+        // Edges as (top, bottom, direction), without horizontal ones.
+        let edges: Vec<(Pos2, Pos2, i32)> = rings
+            .iter()
+            .filter(|ring| ring.len() >= 3)
+            .flat_map(|ring| ring.iter().zip(ring.iter().cycle().skip(1)))
+            .filter(|(a, b)| a.y != b.y && a.is_finite() && b.is_finite())
+            .map(|(&a, &b)| if a.y < b.y { (a, b, 1) } else { (b, a, -1) })
+            .collect();
+        let x_at = |(top, bottom, _): &(Pos2, Pos2, i32), y: f32| {
+            top.x + (bottom.x - top.x) * (y - top.y) / (bottom.y - top.y)
+        };
+
+        let mut ys: Vec<f32> = edges.iter().flat_map(|(top, bottom, _)| [top.y, bottom.y]).collect();
+        ys.sort_by(f32::total_cmp);
+        ys.dedup();
+
+        let mut mesh = Mesh::default();
+        for band in ys.windows(2) {
+            let (y0, y1) = (band[0], band[1]);
+            let active: Vec<_> = edges
+                .iter()
+                .filter(|(top, bottom, _)| top.y <= y0 && bottom.y >= y1)
+                .collect();
+
+            // Split the band where edges cross, so their order is fixed in
+            // each part.
+            let mut cuts = vec![y0, y1];
+            for (i, a) in active.iter().enumerate() {
+                for b in &active[i + 1..] {
+                    let (d0, d1) = (x_at(a, y0) - x_at(b, y0), x_at(a, y1) - x_at(b, y1));
+                    if d0 * d1 < 0.0 {
+                        cuts.push(y0 + (y1 - y0) * d0 / (d0 - d1));
+                    }
+                }
+            }
+            cuts.sort_by(f32::total_cmp);
+
+            for part in cuts.windows(2) {
+                let (top, bottom) = (part[0], part[1]);
+                if bottom <= top {
+                    continue;
+                }
+                let mid = 0.5 * (top + bottom);
+                let mut crossings: Vec<_> = active.iter().map(|edge| (x_at(edge, mid), *edge)).collect();
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                let mut left = None;
+                for (_, edge) in crossings {
+                    let was_inside = self.is_inside(winding);
+                    winding += edge.2;
+                    match (was_inside, self.is_inside(winding)) {
+                        (false, true) => left = Some(edge),
+                        (true, false) => {
+                            if let Some(left) = left.take() {
+                                let first = mesh.vertices.len() as u32;
+                                mesh.colored_vertex(pos2(x_at(left, top), top), color);
+                                mesh.colored_vertex(pos2(x_at(edge, top), top), color);
+                                mesh.colored_vertex(pos2(x_at(edge, bottom), bottom), color);
+                                mesh.colored_vertex(pos2(x_at(left, bottom), bottom), color);
+                                mesh.add_triangle(first, first + 1, first + 2);
+                                mesh.add_triangle(first, first + 2, first + 3);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        mesh
+    }
+}
+
 /// Determines whether a plot element is vertically or horizontally oriented.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Orientation {
@@ -104,6 +371,23 @@ impl Default for Orientation {
     }
 }
 
+/// The head drawn at the tip of an arrow.
+///
+/// See [`crate::Arrows::head`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ArrowHead {
+    /// Two strokes forming a V.
+    #[default]
+    Open,
+
+    /// A filled triangle.
+    Filled,
+
+    /// No head, just the shaft.
+    None,
+}
+
 /// Circle, Diamond, Square, Cross, …
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MarkerShape {
@@ -117,10 +401,13 @@ pub enum MarkerShape {
     Left,
     Right,
     Asterisk,
+
+    /// A user-defined glyph, e.g. a wind barb or an error cross.
+    Custom(MarkerPath),
 }
 
 impl MarkerShape {
-    /// Get a vector containing all marker shapes.
+    /// Get a vector containing all built-in marker shapes.
     pub fn all() -> impl ExactSizeIterator<Item = Self> {
         [
             Self::Circle,
@@ -138,3 +425,105 @@ impl MarkerShape {
         .copied()
     }
 }
+
+/// The outline of a user-defined marker, see [`MarkerShape::Custom`].
+///
+/// Coordinates are relative to the center of the marker, in units of its
+/// radius, with `y` pointing up. Like the built-in shapes, the glyph grows
+/// when its item is highlighted and is drawn in the item's color.
+///
+/// ```
+/// # use egui_plot::{MarkerPath, MarkerShape};
+/// const ERROR_CROSS: MarkerShape = MarkerShape::Custom(MarkerPath {
+///     outlines: &[],
+///     lines: &[
+///         &[[-1.0, 0.0], [1.0, 0.0]],
+///         &[[0.0, -1.0], [0.0, 1.0]],
+///         &[[-0.3, 1.0], [0.3, 1.0]],
+///         &[[-0.3, -1.0], [0.3, -1.0]],
+///     ],
+/// });
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MarkerPath {
+    /// Closed outlines, filled if the item's markers are filled and
+    /// stroked otherwise.
+    pub outlines: &'static [&'static [[f32; 2]]],
+
+    /// Open polylines, always stroked.
+    pub lines: &'static [&'static [[f32; 2]]],
+}
+
+impl Eq for MarkerPath {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_corners() {
+        let points = [pos2(0.0, 0.0), pos2(2.0, 1.0)];
+        assert_eq!(
+            StepMode::Pre.steps(&points),
+            [pos2(0.0, 0.0), pos2(0.0, 1.0), pos2(2.0, 1.0)]
+        );
+        assert_eq!(
+            StepMode::Post.steps(&points),
+            [pos2(0.0, 0.0), pos2(2.0, 0.0), pos2(2.0, 1.0)]
+        );
+        assert_eq!(
+            StepMode::Mid.steps(&points),
+            [pos2(0.0, 0.0), pos2(1.0, 0.0), pos2(1.0, 1.0), pos2(2.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn dash_pattern() {
+        assert_eq!(DashPattern::new(&[4.0]).lengths(), [4.0, 4.0]);
+        assert_eq!(
+            DashPattern::new(&[1.0, 2.0, 3.0]).lengths(),
+            [1.0, 2.0, 3.0, 1.0, 2.0, 3.0]
+        );
+
+        // A 10 long line with 2 on, 1 off: dashes start at 0, 3, 6 and 9.
+        let line = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+        let mut shapes = Vec::new();
+        DashPattern::new(&[2.0, 1.0]).dash(&line, Stroke::new(1.0, Color32::RED), &mut shapes);
+        assert_eq!(shapes.len(), 4);
+
+        // Starting in the middle of the first gap.
+        let mut shapes = Vec::new();
+        DashPattern::new(&[2.0, 1.0])
+            .phase(2.5)
+            .dash(&line, Stroke::new(1.0, Color32::RED), &mut shapes);
+        let Shape::Path(first) = &shapes[0] else {
+            panic!("expected a path");
+        };
+        assert_eq!(first.points, [pos2(0.5, 0.0), pos2(2.5, 0.0)]);
+    }
+
+    #[test]
+    fn holes_are_left_empty() {
+        let square = |min: f32, max: f32| vec![pos2(min, min), pos2(max, min), pos2(max, max), pos2(min, max)];
+        let area = |mesh: &Mesh| -> f32 {
+            mesh.indices
+                .chunks(3)
+                .map(|t| {
+                    let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                    0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+                })
+                .sum()
+        };
+
+        let rings = [square(0.0, 10.0), square(2.0, 8.0)];
+        assert_eq!(area(&FillRule::EvenOdd.fill(&rings, Color32::RED)), 64.0);
+
+        // Same orientation: the hole winds twice, so it is filled.
+        assert_eq!(area(&FillRule::NonZero.fill(&rings, Color32::RED)), 100.0);
+
+        let mut reversed = square(2.0, 8.0);
+        reversed.reverse();
+        let rings = [square(0.0, 10.0), reversed];
+        assert_eq!(area(&FillRule::NonZero.fill(&rings, Color32::RED)), 64.0);
+    }
+}
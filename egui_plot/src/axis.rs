@@ -2,6 +2,7 @@ use std::fmt::Debug;
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+use egui::Color32;
 use egui::Pos2;
 use egui::Rangef;
 use egui::Rect;
@@ -19,6 +20,12 @@ use emath::Vec2b;
 use emath::pos2;
 use emath::remap;
 
+use crate::axis_transform::AxisTransform;
+use crate::axis_transform::AxisTransforms;
+use crate::axis_transform::bounds_to_plot;
+use crate::axis_transform::data_to_plot;
+use crate::axis_transform::modify_in_plot_space;
+use crate::axis_transform::plot_to_data;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
 use crate::colors;
@@ -30,6 +37,9 @@ use crate::placement::VPlacement;
 // Gap between tick labels and axis label in units of the axis label height
 const AXIS_LABEL_GAP: f32 = 0.25;
 
+// Space between the text of a crosshair readout and its box, in ui points
+const READOUT_MARGIN: f32 = 2.0;
+
 pub(super) type AxisFormatterFn<'a> = dyn Fn(GridMark, &RangeInclusive<f64>) -> String + 'a;
 
 /// X or Y axis.
@@ -42,6 +52,19 @@ pub enum Axis {
     Y = 1,
 }
 
+/// Which Y axis a plot item is drawn against.
+///
+/// See [`crate::Plot::secondary_y_axis`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum YAxis {
+    /// The main Y axis, usually on the left.
+    #[default]
+    Primary,
+
+    /// The secondary Y axis on the right, with its own bounds.
+    Secondary,
+}
+
 impl From<Axis> for usize {
     #[inline]
     fn from(value: Axis) -> Self {
@@ -52,6 +75,21 @@ impl From<Axis> for usize {
     }
 }
 
+/// What dragging or scrolling over an axis does, see
+/// [`AxisHints::drag_interaction`] and [`AxisHints::scroll_interaction`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AxisInteraction {
+    /// Zoom along this axis only, around the pointer.
+    #[default]
+    Zoom,
+
+    /// Pan along this axis only.
+    Pan,
+
+    /// Nothing.
+    None,
+}
+
 /// Axis configuration.
 ///
 /// Used to configure axis label and ticks.
@@ -62,6 +100,16 @@ pub struct AxisHints<'a> {
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+
+    /// Counter-clockwise rotation of the tick labels, in radians.
+    pub(super) label_rotation: f32,
+
+    /// Empty for no unit.
+    pub(super) unit: String,
+    pub(super) unit_at_axis_end: bool,
+
+    pub(super) drag_interaction: AxisInteraction,
+    pub(super) scroll_interaction: AxisInteraction,
 }
 
 impl<'a> AxisHints<'a> {
@@ -89,6 +137,11 @@ impl<'a> AxisHints<'a> {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            label_rotation: 0.0,
+            unit: String::new(),
+            unit_at_axis_end: false,
+            drag_interaction: AxisInteraction::Zoom,
+            scroll_interaction: AxisInteraction::Zoom,
         }
     }
 
@@ -103,10 +156,60 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// Format the ticks with SI prefixes, e.g. `1.2k`, `3.4M` or `560µ`,
+    /// followed by `unit` (which may be empty).
+    ///
+    /// All ticks in view share one prefix, picked from the shown range, and
+    /// get as many decimals as the grid step needs, e.g. `0.5 kHz`, `1.0 kHz`,
+    /// `1.5 kHz`.
+    #[inline]
+    pub fn formatter_si(self, unit: impl Into<String>) -> Self {
+        let unit = unit.into();
+        self.formatter(move |mark, range| crate::label::format_si(mark.value, mark.step_size, range, &unit))
+    }
+
+    /// Format the ticks in scientific notation, e.g. `1.23e-5`.
+    ///
+    /// The number of significant digits follows the grid step, so that
+    /// neighboring labels stay distinct when zooming in.
+    #[inline]
+    pub fn formatter_scientific(self) -> Self {
+        self.formatter(|mark, _range| crate::label::format_scientific(mark.value, mark.step_size))
+    }
+
+    /// Specify the unit of the axis, e.g. `"ms"`.
+    ///
+    /// By default it is appended to every tick label, e.g. `12.5 ms`. Use
+    /// [`Self::unit_at_axis_end`] to show it only once instead.
+    ///
+    /// On the main axes, the unit is also shown by the default
+    /// [`crate::CoordinatesFormatter`].
+    #[inline]
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Show the [`Self::unit`] once at the end of the axis (right or top),
+    /// instead of after every tick label.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn unit_at_axis_end(mut self, unit_at_axis_end: bool) -> Self {
+        self.unit_at_axis_end = unit_at_axis_end;
+        self
+    }
+
     fn default_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
         // Example: If the step to the next tick is `0.01`, we should use 2 decimals of
         // precision:
-        let num_decimals = -mark.step_size.log10().round() as usize;
+        let mut num_decimals = -mark.step_size.log10().round() as usize;
+
+        // On a non-linear axis the step size is in plot space, and can be much larger
+        // than the value itself (e.g. `0.01` on a logarithmic axis):
+        if mark.value != 0.0 && mark.value.abs() < mark.step_size {
+            num_decimals = num_decimals.max(-mark.value.abs().log10().floor() as usize);
+        }
 
         emath::format_with_decimals_in_range(mark.value, num_decimals..=num_decimals)
     }
@@ -157,6 +260,49 @@ impl<'a> AxisHints<'a> {
         self.label_spacing = range.into();
         self
     }
+
+    /// Rotate the tick labels counter-clockwise by the given angle, in
+    /// degrees.
+    ///
+    /// Useful for long labels that would otherwise overlap, e.g. `45.0` or
+    /// `90.0` for timestamps on the X axis. The axis gets thick enough to fit
+    /// the rotated labels, and the labels are spaced by their rotated size.
+    ///
+    /// On the X axis, rotated labels end (or start, for negative angles) at
+    /// their tick. On the Y axis, they are centered on it.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn label_rotation(mut self, degrees: f32) -> Self {
+        self.label_rotation = degrees.to_radians();
+        self
+    }
+
+    /// What dragging over this axis does. It only ever changes the range of
+    /// this axis, unless the plot has a [`crate::Plot::data_aspect`].
+    ///
+    /// Dragging over the axes can be turned off altogether with
+    /// [`crate::Plot::allow_axis_zoom_drag`].
+    ///
+    /// Default: [`AxisInteraction::Zoom`].
+    #[inline]
+    pub fn drag_interaction(mut self, interaction: AxisInteraction) -> Self {
+        self.drag_interaction = interaction;
+        self
+    }
+
+    /// What scrolling over this axis does. It only ever changes the range of
+    /// this axis, unless the plot has a [`crate::Plot::data_aspect`].
+    ///
+    /// Zooming obeys [`crate::Plot::allow_zoom`] and panning
+    /// [`crate::Plot::allow_scroll`].
+    ///
+    /// Default: [`AxisInteraction::Zoom`].
+    #[inline]
+    pub fn scroll_interaction(mut self, interaction: AxisInteraction) -> Self {
+        self.scroll_interaction = interaction;
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -191,7 +337,7 @@ impl<'a> AxisWidget<'a> {
             return (response, 0.0);
         }
 
-        let Some(transform) = self.transform else {
+        let Some(transform) = self.transform.as_ref() else {
             return (response, 0.0);
         };
         let tick_labels_thickness = self.add_tick_labels(ui, transform, axis);
@@ -252,92 +398,282 @@ impl<'a> AxisWidget<'a> {
     }
 
     /// Add tick labels to the axis. Returns the thickness of the axis.
-    fn add_tick_labels(&self, ui: &Ui, transform: PlotTransform, axis: Axis) -> f32 {
+    fn add_tick_labels(&self, ui: &Ui, transform: &PlotTransform, axis: Axis) -> f32 {
         let font_id = TextStyle::Body.resolve(ui.style());
         let label_spacing = self.hints.label_spacing;
         let mut thickness: f32 = 0.0;
 
         const SIDE_MARGIN: f32 = 4.0; // Add some margin to both sides of the text on the Y axis.
+        const LABEL_GAP: f32 = 4.0; // Minimum gap between two tick labels.
         let painter = ui.painter();
 
-        // Add tick labels:
+        // Measure the tick labels that aren't too close together to begin with:
+        let mut candidates = Vec::new();
         for step in self.steps.iter() {
-            let text = (self.hints.formatter)(*step, &self.range);
-            if !text.is_empty() {
-                let spacing_in_points = (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
+            let mut text = (self.hints.formatter)(*step, &self.range);
+            if !self.hints.unit_at_axis_end {
+                text = crate::label::with_unit(text, &self.hints.unit);
+            }
+            if text.is_empty() {
+                continue;
+            }
+            let spacing_in_points = (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
+            if spacing_in_points <= label_spacing.min {
+                // Labels are too close together - don't paint them.
+                continue;
+            }
 
-                if spacing_in_points <= label_spacing.min {
-                    // Labels are too close together - don't paint them.
-                    continue;
-                }
+            // The color depends on the spacing needed, which we only know once all labels
+            // are measured.
+            let galley = painter.layout_no_wrap(text, font_id.clone(), Color32::PLACEHOLDER);
+            let label = RotatedLabel::new(galley.size(), self.hints.label_rotation, axis, self.hints.placement);
+            candidates.push((*step, spacing_in_points, galley, label));
+        }
 
-                // Fade in labels as they get further apart:
-                let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
+        // The widest label of each step size decides the spacing needed by all of them,
+        // so that wide labels (dates, long numbers) move to a coarser step as a whole
+        // instead of leaving irregular gaps.
+        let mut needed_spacings: Vec<(f64, f32)> = Vec::new();
+        for (step, _, _, label) in &candidates {
+            let needed = label.spacing(axis) + LABEL_GAP;
+            match needed_spacings
+                .iter_mut()
+                .find(|(step_size, _)| *step_size == step.step_size)
+            {
+                Some((_, spacing)) => *spacing = spacing.max(needed),
+                None => needed_spacings.push((step.step_size, needed)),
+            }
+        }
 
-                let text_color = colors::color_from_strength(ui, strength);
-                let galley = painter.layout_no_wrap(text, font_id.clone(), text_color);
-                let galley_size = match axis {
-                    Axis::X => galley.size(),
-                    Axis::Y => galley.size() + 2.0 * SIDE_MARGIN * Vec2::X,
-                };
+        // Lay out the tick labels, as `(step_size, screen rect, shape)`:
+        let mut labels = Vec::new();
+        for (step, spacing_in_points, galley, label) in candidates {
+            let needed = needed_spacings
+                .iter()
+                .find(|(step_size, _)| *step_size == step.step_size)
+                .map_or(0.0, |(_, spacing)| *spacing);
+            if spacing_in_points < needed {
+                continue; // the galleys won't fit (likely too wide on the X axis).
+            }
 
-                if spacing_in_points < galley_size[axis as usize] {
-                    continue; // the galley won't fit (likely too wide on the X axis).
-                }
+            // Fade in labels as they get further apart:
+            let fade_start = label_spacing.min.max(needed);
+            let fade = Rangef::new(fade_start, fade_start + label_spacing.span());
+            let strength = remap_clamp(spacing_in_points, fade, 0.0..=1.0);
+            let text_color = colors::color_from_strength(ui, strength);
 
-                match axis {
-                    Axis::X => {
-                        thickness = thickness.max(galley_size.y);
-
-                        let projected_point = super::PlotPoint::new(step.value, 0.0);
-                        let center_x = transform.position_from_point(&projected_point).x;
-                        let y = match VPlacement::from(self.hints.placement) {
-                            VPlacement::Bottom => self.rect.min.y,
-                            VPlacement::Top => self.rect.max.y - galley_size.y,
-                        };
-                        let pos = Pos2::new(center_x - galley_size.x / 2.0, y);
-                        painter.add(TextShape::new(pos, galley, text_color));
-                    }
-                    Axis::Y => {
-                        thickness = thickness.max(galley_size.x);
-
-                        let projected_point = super::PlotPoint::new(0.0, step.value);
-                        let center_y = transform.position_from_point(&projected_point).y;
-
-                        match HPlacement::from(self.hints.placement) {
-                            HPlacement::Left => {
-                                let angle = 0.0; // TODO(#162): allow users to rotate text
-
-                                if angle == 0.0 {
-                                    let x = self.rect.max.x - galley_size.x + SIDE_MARGIN;
-                                    let pos = Pos2::new(x, center_y - galley_size.y / 2.0);
-                                    painter.add(TextShape::new(pos, galley, text_color));
-                                } else {
-                                    let right = Pos2::new(self.rect.max.x, center_y - galley_size.y / 2.0);
-                                    let width = galley_size.x;
-                                    let left = right - Rot2::from_angle(angle) * Vec2::new(width, 0.0);
-
-                                    painter.add(TextShape::new(left, galley, text_color).with_angle(angle));
-                                }
-                            }
-                            HPlacement::Right => {
-                                let x = self.rect.min.x + SIDE_MARGIN;
-                                let pos = Pos2::new(x, center_y - galley_size.y / 2.0);
-                                painter.add(TextShape::new(pos, galley, text_color));
-                            }
-                        }
-                    }
+            let anchor = match axis {
+                Axis::X => {
+                    let y = match VPlacement::from(self.hints.placement) {
+                        VPlacement::Bottom => self.rect.min.y - label.bounding_rect.min.y,
+                        VPlacement::Top => self.rect.max.y - label.bounding_rect.max.y,
+                    };
+                    Pos2::new(transform.position_from_point_x(step.value), y)
+                }
+                Axis::Y => {
+                    let x = match HPlacement::from(self.hints.placement) {
+                        HPlacement::Left => self.rect.max.x - SIDE_MARGIN - label.bounding_rect.max.x,
+                        HPlacement::Right => self.rect.min.x + SIDE_MARGIN - label.bounding_rect.min.x,
+                    };
+                    Pos2::new(x, transform.position_from_point_y(step.value))
                 }
+            };
+
+            let pos = anchor - label.rotation * label.anchor;
+            let rect = label.bounding_rect.translate(anchor.to_vec2());
+            labels.push((
+                step.step_size,
+                rect,
+                TextShape::new(pos, galley, text_color).with_angle(label.angle),
+            ));
+        }
+
+        // The unit at the end of the axis takes precedence over any tick label.
+        if self.hints.unit_at_axis_end && !self.hints.unit.is_empty() {
+            let text_color = ui.visuals().text_color();
+            let galley = painter.layout_no_wrap(self.hints.unit.clone(), font_id, text_color);
+            let size = galley.size();
+            let min = match axis {
+                Axis::X => Pos2::new(
+                    self.rect.max.x - size.x,
+                    match VPlacement::from(self.hints.placement) {
+                        VPlacement::Bottom => self.rect.min.y,
+                        VPlacement::Top => self.rect.max.y - size.y,
+                    },
+                ),
+                Axis::Y => Pos2::new(
+                    match HPlacement::from(self.hints.placement) {
+                        HPlacement::Left => self.rect.max.x - SIDE_MARGIN - size.x,
+                        HPlacement::Right => self.rect.min.x + SIDE_MARGIN,
+                    },
+                    self.rect.min.y,
+                ),
+            };
+            labels.push((
+                f64::INFINITY,
+                Rect::from_min_size(min, size),
+                TextShape::new(min, galley, text_color),
+            ));
+        }
+
+        // Drop labels that would overlap, keeping those of the coarsest marks. The grid
+        // lines stay.
+        let mut order: Vec<usize> = (0..labels.len()).collect();
+        order.sort_by(|&a, &b| labels[b].0.total_cmp(&labels[a].0));
+        let mut kept = vec![false; labels.len()];
+        let mut kept_rects: Vec<Rect> = Vec::new();
+        for i in order {
+            let rect = labels[i].1;
+            if kept_rects.iter().all(|kept| !kept.expand(LABEL_GAP).intersects(rect)) {
+                kept[i] = true;
+                kept_rects.push(rect);
+            }
+        }
+
+        for ((_, rect, shape), kept) in labels.into_iter().zip(kept) {
+            if kept {
+                thickness = match axis {
+                    Axis::X => thickness.max(rect.height()),
+                    Axis::Y => thickness.max(rect.width() + 2.0 * SIDE_MARGIN),
+                };
+                painter.add(shape);
             }
         }
         thickness
     }
+
+    /// Paint the value at the screen coordinate `pos` along the axis in a box
+    /// on the axis, at the edge facing the plot, formatted like the tick
+    /// labels. Used to read off the crosshair.
+    pub fn paint_readout(&self, ui: &Ui, axis: Axis, pos: f32) {
+        let Some(transform) = self.transform.as_ref() else {
+            return;
+        };
+        let iaxis = usize::from(axis);
+        if !(self.rect.min[iaxis]..=self.rect.max[iaxis]).contains(&pos) {
+            return;
+        }
+        let value = match axis {
+            Axis::X => transform.value_from_position(pos2(pos, 0.0)).x,
+            Axis::Y => transform.value_from_position(pos2(0.0, pos)).y,
+        };
+
+        // Show as many decimals as the finest tick labels.
+        let step_size = self
+            .steps
+            .iter()
+            .map(|mark| mark.step_size)
+            .fold(f64::INFINITY, f64::min);
+        let step_size = if step_size.is_finite() {
+            step_size
+        } else {
+            (self.range.end() - self.range.start()).abs() / 100.0
+        };
+        let text = (self.hints.formatter)(GridMark { value, step_size }, &self.range);
+        let text = crate::label::with_unit(text, &self.hints.unit);
+        if text.is_empty() {
+            return;
+        }
+
+        let visuals = ui.visuals();
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let galley = ui.painter().layout_no_wrap(text, font_id, visuals.strong_text_color());
+        let size = galley.size() + Vec2::splat(2.0 * READOUT_MARGIN);
+        let min = match axis {
+            Axis::X => pos2(
+                (pos - 0.5 * size.x).min(self.rect.max.x - size.x).max(self.rect.min.x),
+                match VPlacement::from(self.hints.placement) {
+                    VPlacement::Bottom => self.rect.min.y,
+                    VPlacement::Top => self.rect.max.y - size.y,
+                },
+            ),
+            Axis::Y => pos2(
+                match HPlacement::from(self.hints.placement) {
+                    HPlacement::Left => self.rect.max.x - size.x,
+                    HPlacement::Right => self.rect.min.x,
+                },
+                (pos - 0.5 * size.y).min(self.rect.max.y - size.y).max(self.rect.min.y),
+            ),
+        };
+        let rect = Rect::from_min_size(min, size);
+        ui.painter().rect(
+            rect,
+            2.0,
+            visuals.extreme_bg_color,
+            visuals.widgets.noninteractive.fg_stroke,
+            egui::StrokeKind::Inside,
+        );
+        ui.painter()
+            .galley(min + Vec2::splat(READOUT_MARGIN), galley, visuals.strong_text_color());
+    }
+}
+
+/// The layout of a tick label, rotated around its anchor.
+struct RotatedLabel {
+    /// Clockwise rotation on screen, as expected by [`TextShape::angle`].
+    angle: f32,
+    rotation: Rot2,
+
+    /// The point of the unrotated galley that is aligned with the tick,
+    /// relative to its top-left corner.
+    anchor: Vec2,
+
+    /// The rotated galley, relative to the anchor.
+    bounding_rect: Rect,
+
+    /// The height of the galley.
+    height: f32,
+}
+
+impl RotatedLabel {
+    fn new(size: Vec2, label_rotation: f32, axis: Axis, placement: Placement) -> Self {
+        let angle = -label_rotation;
+        let rotation = Rot2::from_angle(angle);
+
+        // Rotated X labels hang off their tick by one end, so that they don't
+        // overlap their neighbors.
+        let anchor = match axis {
+            Axis::X if label_rotation != 0.0 => {
+                let at_end = (label_rotation > 0.0) == (VPlacement::from(placement) == VPlacement::Bottom);
+                Vec2::new(if at_end { size.x } else { 0.0 }, size.y / 2.0)
+            }
+            _ => size / 2.0,
+        };
+
+        let corners = [Vec2::ZERO, Vec2::new(size.x, 0.0), Vec2::new(0.0, size.y), size];
+        let bounding_rect = Rect::from_points(&corners.map(|corner| (rotation * (corner - anchor)).to_pos2()));
+
+        Self {
+            angle,
+            rotation,
+            anchor,
+            bounding_rect,
+            height: size.y,
+        }
+    }
+
+    /// The minimum distance between two ticks along `axis` for their labels not
+    /// to overlap.
+    fn spacing(&self, axis: Axis) -> f32 {
+        // Neighboring labels are parallel, so they only need to be apart by their height,
+        // measured perpendicular to the text.
+        let normal = self.rotation * Vec2::Y;
+        let perpendicular = self.height / normal[usize::from(axis)].abs();
+        self.bounding_rect.size()[usize::from(axis)].min(perpendicular)
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to
 /// transform between them.
+///
+/// The bounds, and all points passed in and returned, are data values. If an
+/// axis has an [`AxisTransform`], the screen position along that axis is
+/// linear in the plot space of the transform instead.
+///
+/// Since it holds the axis transforms, this is `Clone` but not `Copy`.
+/// Cloning it only counts up references.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone)]
 pub struct PlotTransform {
     /// The screen rectangle.
     frame: Rect,
@@ -350,71 +686,110 @@ pub struct PlotTransform {
 
     /// Whether to always invert the x and/or y axis
     inverted_axis: Vec2b,
+
+    /// The transforms of the x and y axis. `None` means linear.
+    ///
+    /// Not persisted, since the [`crate::Plot`] sets them every frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    axis_transforms: AxisTransforms,
+
+    /// The bounds in the plot space of the axis transforms, if any axis has
+    /// one, so that they aren't transformed again for every point.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    transformed_bounds: Option<PlotBounds>,
+}
+
+impl Debug for PlotTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlotTransform")
+            .field("frame", &self.frame)
+            .field("bounds", &self.bounds)
+            .field("centered", &self.centered)
+            .field("inverted_axis", &self.inverted_axis)
+            .field("axis_transforms", &self.axis_transforms.each_ref().map(Option::is_some))
+            .finish()
+    }
 }
 
 impl PlotTransform {
     pub fn new(frame: Rect, bounds: PlotBounds, center_axis: impl Into<Vec2b>) -> Self {
+        Self::new_with_axis_transforms(frame, bounds, center_axis, false, Default::default())
+    }
+
+    pub fn new_with_invert_axis(
+        frame: Rect,
+        bounds: PlotBounds,
+        center_axis: impl Into<Vec2b>,
+        invert_axis: impl Into<Vec2b>,
+    ) -> Self {
+        Self::new_with_axis_transforms(frame, bounds, center_axis, invert_axis, Default::default())
+    }
+
+    pub(crate) fn new_with_axis_transforms(
+        frame: Rect,
+        bounds: PlotBounds,
+        center_axis: impl Into<Vec2b>,
+        invert_axis: impl Into<Vec2b>,
+        axis_transforms: AxisTransforms,
+    ) -> Self {
         debug_assert!(
             0.0 <= frame.width() && 0.0 <= frame.height(),
             "Bad plot frame: {frame:?}"
         );
         let center_axis = center_axis.into();
 
-        // Since the current Y bounds an affect the final X bounds and vice versa, we
-        // need to keep the original version of the `bounds` before we start
-        // modifying it.
-        let mut new_bounds = bounds;
-
-        // Sanitize bounds.
-        //
-        // When a given bound axis is "thin" (e.g. width or height is 0) but finite, we
-        // center the bounds around that value. If the other axis is "fat", we
-        // reuse its extent for the thin axis, and default to +/- 1.0 otherwise.
-        if !bounds.is_finite_x() {
-            new_bounds.set_x(&PlotBounds::new_symmetrical(1.0));
-        } else if bounds.width() <= 0.0 {
-            new_bounds.set_x_center_width(
-                bounds.center().x,
-                if bounds.is_valid_y() { bounds.height() } else { 1.0 },
-            );
-        }
+        // All sanitizing happens in plot space, where the axes are linear.
+        let mut final_bounds = bounds;
+        modify_in_plot_space(&axis_transforms, &mut final_bounds, |new_bounds| {
+            // Since the current Y bounds an affect the final X bounds and vice versa, we
+            // need to keep the original version of the `bounds` before we start
+            // modifying it.
+            let bounds = *new_bounds;
+
+            // Sanitize bounds.
+            //
+            // When a given bound axis is "thin" (e.g. width or height is 0) but finite, we
+            // center the bounds around that value. If the other axis is "fat", we
+            // reuse its extent for the thin axis, and default to +/- 1.0 otherwise.
+            if !bounds.is_finite_x() {
+                new_bounds.set_x(&PlotBounds::new_symmetrical(1.0));
+            } else if bounds.width() <= 0.0 {
+                new_bounds.set_x_center_width(
+                    bounds.center().x,
+                    if bounds.is_valid_y() { bounds.height() } else { 1.0 },
+                );
+            }
 
-        if !bounds.is_finite_y() {
-            new_bounds.set_y(&PlotBounds::new_symmetrical(1.0));
-        } else if bounds.height() <= 0.0 {
-            new_bounds.set_y_center_height(
-                bounds.center().y,
-                if bounds.is_valid_x() { bounds.width() } else { 1.0 },
-            );
-        }
+            if !bounds.is_finite_y() {
+                new_bounds.set_y(&PlotBounds::new_symmetrical(1.0));
+            } else if bounds.height() <= 0.0 {
+                new_bounds.set_y_center_height(
+                    bounds.center().y,
+                    if bounds.is_valid_x() { bounds.width() } else { 1.0 },
+                );
+            }
 
-        // Scale axes so that the origin is in the center.
-        if center_axis.x {
-            new_bounds.make_x_symmetrical();
-        }
-        if center_axis.y {
-            new_bounds.make_y_symmetrical();
-        }
+            // Scale axes so that the origin is in the center.
+            if center_axis.x {
+                new_bounds.make_x_symmetrical();
+            }
+            if center_axis.y {
+                new_bounds.make_y_symmetrical();
+            }
 
-        debug_assert!(new_bounds.is_valid(), "Bad final plot bounds: {new_bounds:?}");
+            debug_assert!(new_bounds.is_valid(), "Bad final plot bounds: {new_bounds:?}");
+        });
 
-        Self {
+        let mut transform = Self {
             frame,
-            bounds: new_bounds,
+            bounds: final_bounds,
             centered: center_axis,
-            inverted_axis: Vec2b::new(false, false),
-        }
-    }
-
-    pub fn new_with_invert_axis(
-        frame: Rect,
-        bounds: PlotBounds,
-        center_axis: impl Into<Vec2b>,
-        invert_axis: impl Into<Vec2b>,
-    ) -> Self {
-        let mut new = Self::new(frame, bounds, center_axis);
-        new.inverted_axis = invert_axis.into();
-        new
+            inverted_axis: invert_axis.into(),
+            axis_transforms,
+            transformed_bounds: None,
+        };
+        transform.update_transformed_bounds();
+        transform
     }
 
     /// ui-space rectangle.
@@ -432,6 +807,43 @@ impl PlotTransform {
     #[inline]
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.bounds = bounds;
+        self.update_transformed_bounds();
+    }
+
+    /// The transform of the given axis, if it isn't linear.
+    #[inline]
+    pub fn axis_transform(&self, axis: Axis) -> Option<&dyn AxisTransform> {
+        self.axis_transforms[usize::from(axis)].as_deref()
+    }
+
+    #[inline]
+    pub(crate) fn set_axis_transforms(&mut self, axis_transforms: AxisTransforms) {
+        self.axis_transforms = axis_transforms;
+        self.update_transformed_bounds();
+    }
+
+    /// Keep [`Self::transformed_bounds`] in step with the bounds and the axis
+    /// transforms.
+    fn update_transformed_bounds(&mut self) {
+        self.transformed_bounds = self
+            .axis_transforms
+            .iter()
+            .any(Option::is_some)
+            .then(|| bounds_to_plot(&self.axis_transforms, &self.bounds));
+    }
+
+    /// The bounds in the plot space of the axis transforms, where positions on
+    /// screen are linear.
+    #[inline]
+    pub(crate) fn linear_bounds(&self) -> PlotBounds {
+        self.transformed_bounds.unwrap_or(self.bounds)
+    }
+
+    /// Apply `modify` to the bounds in the plot space of the axis transforms.
+    #[inline]
+    fn modify_linear_bounds(&mut self, modify: impl FnOnce(&mut PlotBounds)) {
+        modify_in_plot_space(&self.axis_transforms, &mut self.bounds, modify);
+        self.update_transformed_bounds();
     }
 
     pub fn translate_bounds(&mut self, mut delta_pos: (f64, f64)) {
@@ -443,43 +855,53 @@ impl PlotTransform {
         }
         delta_pos.0 *= self.dvalue_dpos()[0];
         delta_pos.1 *= self.dvalue_dpos()[1];
-        self.bounds.translate((delta_pos.0, delta_pos.1));
+        self.modify_linear_bounds(|bounds| bounds.translate(delta_pos));
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = self.linear_value_from_position(center);
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.linear_bounds();
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.modify_linear_bounds(|bounds| *bounds = new_bounds);
+        }
+    }
+
+    fn x_screen_range(&self) -> RangeInclusive<f64> {
+        if self.inverted_axis[0] {
+            (self.frame.right() as f64)..=(self.frame.left() as f64)
+        } else {
+            (self.frame.left() as f64)..=(self.frame.right() as f64)
+        }
+    }
+
+    fn y_screen_range(&self) -> RangeInclusive<f64> {
+        // negated y axis by default
+        if self.inverted_axis[1] {
+            (self.frame.top() as f64)..=(self.frame.bottom() as f64)
+        } else {
+            (self.frame.bottom() as f64)..=(self.frame.top() as f64)
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let bounds = self.transformed_bounds.as_ref().unwrap_or(&self.bounds);
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
-            if self.inverted_axis[0] {
-                (self.frame.right() as f64)..=(self.frame.left() as f64)
-            } else {
-                (self.frame.left() as f64)..=(self.frame.right() as f64)
-            },
+            data_to_plot(self.axis_transforms[0].as_ref(), value),
+            bounds.min[0]..=bounds.max[0],
+            self.x_screen_range(),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let bounds = self.transformed_bounds.as_ref().unwrap_or(&self.bounds);
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
-            // negated y axis by default
-            if self.inverted_axis[1] {
-                (self.frame.top() as f64)..=(self.frame.bottom() as f64)
-            } else {
-                (self.frame.bottom() as f64)..=(self.frame.top() as f64)
-            },
+            data_to_plot(self.axis_transforms[1].as_ref(), value),
+            bounds.min[1]..=bounds.max[1],
+            self.y_screen_range(),
         ) as f32
     }
 
@@ -490,26 +912,19 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
-        let x = remap(
-            pos.x as f64,
-            if self.inverted_axis[0] {
-                (self.frame.right() as f64)..=(self.frame.left() as f64)
-            } else {
-                (self.frame.left() as f64)..=(self.frame.right() as f64)
-            },
-            self.bounds.range_x(),
-        );
-        let y = remap(
-            pos.y as f64,
-            // negated y axis by default
-            if self.inverted_axis[1] {
-                (self.frame.top() as f64)..=(self.frame.bottom() as f64)
-            } else {
-                (self.frame.bottom() as f64)..=(self.frame.top() as f64)
-            },
-            self.bounds.range_y(),
-        );
+        let linear = self.linear_value_from_position(pos);
+        PlotPoint::new(
+            plot_to_data(self.axis_transforms[0].as_ref(), linear.x),
+            plot_to_data(self.axis_transforms[1].as_ref(), linear.y),
+        )
+    }
 
+    /// Like [`Self::value_from_position`], but in the plot space of the axis
+    /// transforms.
+    fn linear_value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let bounds = self.linear_bounds();
+        let x = remap(pos.x as f64, self.x_screen_range(), bounds.range_x());
+        let y = remap(pos.y as f64, self.y_screen_range(), bounds.range_y());
         PlotPoint::new(x, y)
     }
 
@@ -530,16 +945,22 @@ impl PlotTransform {
 
     /// delta position / delta value = how many ui points per step in the X axis
     /// in "plot space"
+    ///
+    /// For an axis with an [`AxisTransform`], this is per step in the plot
+    /// space of the transform.
     pub fn dpos_dvalue_x(&self) -> f64 {
         let flip = if self.inverted_axis[0] { -1.0 } else { 1.0 };
-        flip * (self.frame.width() as f64) / self.bounds.width()
+        flip * (self.frame.width() as f64) / self.linear_bounds().width()
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis
     /// in "plot space"
+    ///
+    /// For an axis with an [`AxisTransform`], this is per step in the plot
+    /// space of the transform.
     pub fn dpos_dvalue_y(&self) -> f64 {
         let flip = if self.inverted_axis[1] { 1.0 } else { -1.0 };
-        flip * (self.frame.height() as f64) / self.bounds.height()
+        flip * (self.frame.height() as f64) / self.linear_bounds().height()
     }
 
     /// delta position / delta value = how many ui points per step in "plot
@@ -558,9 +979,10 @@ impl PlotTransform {
     ///
     /// If 1.0, it means the scale factor is the same in both axes.
     fn aspect(&self) -> f64 {
+        let bounds = self.linear_bounds();
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        (bounds.width() / rw) / (bounds.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -575,13 +997,13 @@ impl PlotTransform {
             return;
         }
 
-        if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
-        } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
-        }
+        self.modify_linear_bounds(|bounds| {
+            if current_aspect < aspect {
+                bounds.expand_x((aspect / current_aspect - 1.0) * bounds.width() * 0.5);
+            } else {
+                bounds.expand_y((current_aspect / aspect - 1.0) * bounds.height() * 0.5);
+            }
+        });
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers
@@ -595,15 +1017,13 @@ impl PlotTransform {
             return;
         }
 
-        match axis {
+        self.modify_linear_bounds(|bounds| match axis {
             Axis::X => {
-                self.bounds
-                    .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+                bounds.expand_x((aspect / current_aspect - 1.0) * bounds.width() * 0.5);
             }
             Axis::Y => {
-                self.bounds
-                    .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+                bounds.expand_y((current_aspect / aspect - 1.0) * bounds.height() * 0.5);
             }
-        }
+        });
     }
 }
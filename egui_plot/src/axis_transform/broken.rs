@@ -0,0 +1,161 @@
+use std::ops::RangeInclusive;
+
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::log_grid_marks;
+
+/// A broken (discontinuous) axis, which skips one or more ranges.
+///
+/// Useful when the data forms clusters far apart, e.g. `0..100` and
+/// `10_000..10_100`: hiding `100..=10_000` puts both next to each other. The
+/// remaining segments are concatenated without a gap, and the plot draws a
+/// break indicator at each seam.
+///
+/// Values inside a hidden range end up at its seam.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BrokenAxisTransform {
+    /// Sorted, non-overlapping, non-empty hidden ranges.
+    hidden: Vec<(f64, f64)>,
+}
+
+impl BrokenAxisTransform {
+    /// An axis which hides the given data ranges.
+    ///
+    /// The ranges may be given in any order, and overlapping ranges are merged.
+    /// Empty ranges are ignored.
+    pub fn new(hidden: impl IntoIterator<Item = RangeInclusive<f64>>) -> Self {
+        let mut ranges: Vec<(f64, f64)> = hidden
+            .into_iter()
+            .map(|range| (*range.start(), *range.end()))
+            .filter(|(start, end)| start < end)
+            .collect();
+        ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut merged: Vec<(f64, f64)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { hidden: merged }
+    }
+
+    /// The hidden data ranges, sorted and merged.
+    pub fn hidden_ranges(&self) -> impl Iterator<Item = RangeInclusive<f64>> + '_ {
+        self.hidden.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The visible data segments overlapping `min..=max`.
+    fn segments(&self, min: f64, max: f64) -> Vec<(f64, f64)> {
+        let mut segments = Vec::new();
+        let mut start = min;
+        for &(hidden_start, hidden_end) in &self.hidden {
+            if hidden_start >= max {
+                break;
+            }
+            if hidden_end > start {
+                if hidden_start > start {
+                    segments.push((start, hidden_start));
+                }
+                start = hidden_end;
+            }
+        }
+        if start < max {
+            segments.push((start, max));
+        }
+        segments
+    }
+}
+
+impl AxisTransform for BrokenAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        let mut shift = 0.0;
+        for &(start, end) in &self.hidden {
+            if value >= end {
+                shift += end - start;
+            } else if value > start {
+                return start - shift;
+            } else {
+                break;
+            }
+        }
+        value - shift
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        let mut shift = 0.0;
+        for &(start, end) in &self.hidden {
+            if value <= start - shift {
+                break;
+            }
+            shift += end - start;
+        }
+        value + shift
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() {
+            return Vec::new();
+        }
+
+        // Each segment is linear, with the same scale as the plot space. Marks right at
+        // a seam are dropped, since they would overlap with the other side.
+        self.segments(self.plot_to_data(min), self.plot_to_data(max))
+            .into_iter()
+            .flat_map(|bounds| {
+                log_grid_marks(
+                    &GridInput {
+                        bounds,
+                        base_step_size: input.base_step_size,
+                    },
+                    10.0,
+                )
+            })
+            .filter(|mark| {
+                !self
+                    .hidden
+                    .iter()
+                    .any(|&(start, end)| start <= mark.value && mark.value <= end)
+            })
+            .collect()
+    }
+
+    fn breaks(&self) -> Vec<f64> {
+        self.hidden.iter().map(|&(start, _)| start).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_are_concatenated() {
+        let transform = BrokenAxisTransform::new([300.0..=400.0, 100.0..=10_000.0, 5.0..=5.0]);
+        assert_eq!(transform.hidden_ranges().collect::<Vec<_>>(), vec![100.0..=10_000.0]);
+
+        assert_eq!(transform.data_to_plot(50.0), 50.0);
+        assert_eq!(transform.data_to_plot(10_050.0), 150.0);
+        assert_eq!(transform.data_to_plot(500.0), 100.0, "Hidden values go to the seam");
+        assert_eq!(transform.plot_to_data(150.0), 10_050.0);
+        assert_eq!(transform.plot_to_data(100.0), 100.0);
+        assert_eq!(transform.breaks(), vec![100.0]);
+    }
+
+    #[test]
+    fn marks_per_segment() {
+        let transform = BrokenAxisTransform::new([100.0..=10_000.0]);
+        let marks = transform.grid_marks(GridInput {
+            bounds: (0.0, 200.0),
+            base_step_size: 5.0,
+        });
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+
+        assert!(values.contains(&50.0) && values.contains(&10_050.0), "{values:?}");
+        assert!(!values.contains(&100.0) && !values.contains(&10_000.0), "{values:?}");
+        assert!(values.len() < 100, "Too many marks: {}", values.len());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+
+/// An axis of named categories, e.g. for bar charts.
+///
+/// Category `i` is centered on the value `i` (so the first category is at
+/// `0.0`, the second at `1.0`, and so on). Grid marks only land on category
+/// centers, and [`Self::format`] prints the category label.
+///
+/// When zoomed out so far that the labels would overlap, only every 2nd,
+/// 5th, 10th, … category gets a mark.
+///
+/// See [`crate::Plot::x_categories`] for the easiest way to use this.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CategoricalAxisTransform {
+    labels: Vec<String>,
+}
+
+impl CategoricalAxisTransform {
+    /// An axis with one slot per label, in order.
+    pub fn new<S: Into<String>>(labels: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The category labels.
+    #[inline]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// The position of the category with the given label, if any.
+    pub fn position(&self, label: &str) -> Option<f64> {
+        self.labels.iter().position(|l| l == label).map(|i| i as f64)
+    }
+
+    /// The label of the category at the given position.
+    ///
+    /// Returns `None` if the value isn't the center of a category.
+    pub fn label(&self, value: f64) -> Option<&str> {
+        if value.fract() != 0.0 || value < 0.0 {
+            return None;
+        }
+        self.labels.get(value as usize).map(String::as_str)
+    }
+
+    /// Format a grid mark as its category label.
+    ///
+    /// Marks that aren't on a category center get an empty label.
+    pub fn format(&self, mark: GridMark) -> String {
+        self.label(mark.value).unwrap_or_default().to_owned()
+    }
+}
+
+impl AxisTransform for CategoricalAxisTransform {
+    #[inline]
+    fn data_to_plot(&self, value: f64) -> f64 {
+        value
+    }
+
+    #[inline]
+    fn plot_to_data(&self, value: f64) -> f64 {
+        value
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if self.labels.is_empty() || !min.is_finite() || !max.is_finite() || !input.base_step_size.is_finite() {
+            return Vec::new();
+        }
+
+        // Every category if there is room for it, otherwise every 2nd, 5th, 10th, …
+        let stride = (0..)
+            .flat_map(|exponent| [1, 2, 5].map(|m| 10_usize.saturating_pow(exponent).saturating_mul(m)))
+            .find(|&stride| stride as f64 >= input.base_step_size)
+            .unwrap_or(1);
+
+        let first = min.ceil().max(0.0) as usize;
+        let last = max.floor().min((self.labels.len() - 1) as f64);
+        if last < 0.0 {
+            return Vec::new();
+        }
+        (first..=last as usize)
+            .filter(|i| i % stride == 0)
+            .map(|i| GridMark {
+                value: i as f64,
+                step_size: stride as f64,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_on_category_centers() {
+        let categories = CategoricalAxisTransform::new(["apples", "pears", "plums"]);
+        let marks = categories.grid_marks(GridInput {
+            bounds: (-0.7, 2.3),
+            base_step_size: 0.1,
+        });
+        let labels: Vec<String> = marks.iter().map(|mark| categories.format(*mark)).collect();
+        assert_eq!(labels, vec!["apples", "pears", "plums"]);
+
+        assert_eq!(categories.position("plums"), Some(2.0));
+        assert_eq!(categories.label(1.5), None);
+    }
+
+    #[test]
+    fn thinned_when_zoomed_out() {
+        let categories = CategoricalAxisTransform::new((0..100).map(|i| format!("#{i}")));
+        let marks = categories.grid_marks(GridInput {
+            bounds: (0.0, 99.0),
+            base_step_size: 3.0,
+        });
+        assert_eq!(marks.len(), 20);
+        assert!(
+            marks
+                .iter()
+                .all(|mark| mark.value % 5.0 == 0.0 && mark.step_size == 5.0)
+        );
+    }
+}
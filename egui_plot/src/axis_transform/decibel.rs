@@ -0,0 +1,172 @@
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::generate_marks;
+use crate::grid::log_grid_marks;
+
+/// Lowest level shown on a decibel axis. Zero and negative values end up
+/// here.
+const MIN_DB: f64 = -300.0;
+
+/// A decibel axis, e.g. for audio levels or frequency responses.
+///
+/// Data values are linear amplitudes (or powers, see [`Self::new_power`]),
+/// shown in dB relative to a reference level. Zero and negative values are
+/// clamped to -300 dB instead of going to infinity.
+///
+/// Zoomed in, grid marks land on multiples of 3 and 6 dB; zoomed out, on
+/// multiples of 10 and 20 dB. Pair this with [`Self::format`] to label the
+/// ticks in dB.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecibelAxisTransform {
+    reference: f64,
+
+    /// `20` for amplitudes, `10` for powers.
+    factor: f64,
+}
+
+impl Default for DecibelAxisTransform {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl DecibelAxisTransform {
+    /// A dB axis for amplitudes (`20 * log10(value / reference)`).
+    ///
+    /// Panics in debug builds if `reference <= 0`.
+    pub fn new(reference: f64) -> Self {
+        debug_assert!(
+            reference > 0.0,
+            "The reference level of a decibel axis must be > 0, got {reference}"
+        );
+        Self {
+            reference,
+            factor: 20.0,
+        }
+    }
+
+    /// A dB axis for powers (`10 * log10(value / reference)`).
+    ///
+    /// Panics in debug builds if `reference <= 0`.
+    pub fn new_power(reference: f64) -> Self {
+        Self {
+            factor: 10.0,
+            ..Self::new(reference)
+        }
+    }
+
+    /// The value shown as 0 dB.
+    #[inline]
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+
+    /// Format a grid mark in dB, e.g. `-6 dB`.
+    pub fn format(&self, mark: GridMark) -> String {
+        let db = self.data_to_plot(mark.value);
+        let num_decimals = if mark.step_size < 1.0 {
+            (-mark.step_size.log10()).ceil() as usize
+        } else {
+            0
+        };
+        format!(
+            "{} dB",
+            emath::format_with_decimals_in_range(db, num_decimals..=num_decimals)
+        )
+    }
+}
+
+/// The steps of the three grid line thicknesses, for the finest step `index`.
+///
+/// Each step is a multiple of the previous one: 1, 3, 6, 10, 20, 50, 100, 200,
+/// 500, …
+fn step_sizes(index: usize) -> [f64; 3] {
+    match index {
+        0 => [1.0, 3.0, 6.0],
+        1 => [3.0, 6.0, 30.0],
+        2 => [6.0, 30.0, 60.0],
+        _ => {
+            let index = index - 3;
+            let decade = 10_f64.powi((index / 3) as i32 + 1);
+            match index % 3 {
+                0 => [decade, 2.0 * decade, 10.0 * decade],
+                1 => [2.0 * decade, 10.0 * decade, 20.0 * decade],
+                _ => [5.0 * decade, 10.0 * decade, 50.0 * decade],
+            }
+        }
+    }
+}
+
+impl AxisTransform for DecibelAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        if value <= 0.0 {
+            return MIN_DB;
+        }
+        (self.factor * (value / self.reference).log10()).max(MIN_DB)
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        self.reference * 10_f64.powf(value / self.factor)
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // Below 1 dB, plain decimal subdivisions.
+        let marks = if input.base_step_size < 1.0 {
+            log_grid_marks(&input, 10.0)
+        } else {
+            let index = (0..)
+                .find(|&index| step_sizes(index)[0] >= input.base_step_size)
+                .unwrap_or_default();
+            generate_marks(step_sizes(index), input.bounds)
+        };
+
+        marks
+            .into_iter()
+            .map(|mark| GridMark {
+                value: self.plot_to_data(mark.value),
+                step_size: mark.step_size,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amplitude_and_power() {
+        let amplitude = DecibelAxisTransform::new(1.0);
+        assert!((amplitude.data_to_plot(0.5) + 6.0206).abs() < 1e-4);
+        assert!((amplitude.plot_to_data(-20.0) - 0.1).abs() < 1e-12);
+
+        let power = DecibelAxisTransform::new_power(2.0);
+        assert!((power.data_to_plot(1.0) + 3.0103).abs() < 1e-4);
+
+        assert_eq!(amplitude.data_to_plot(0.0), MIN_DB);
+        assert_eq!(amplitude.data_to_plot(-1.0), MIN_DB);
+    }
+
+    #[test]
+    fn marks_depend_on_zoom() {
+        let db = DecibelAxisTransform::new(1.0);
+        let labels = |base_step_size| -> Vec<String> {
+            db.grid_marks(GridInput {
+                bounds: (-13.0, 1.0),
+                base_step_size,
+            })
+            .into_iter()
+            .filter(|mark| mark.step_size >= base_step_size)
+            .map(|mark| db.format(mark))
+            .collect()
+        };
+        assert_eq!(labels(2.5), vec!["-12 dB", "-9 dB", "-6 dB", "-3 dB", "0 dB"]);
+        assert_eq!(labels(8.0), vec!["-10 dB", "0 dB"]);
+    }
+}
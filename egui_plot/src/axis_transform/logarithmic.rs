@@ -0,0 +1,232 @@
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::generate_marks;
+use crate::grid::next_power;
+
+/// Multiples of each decade that get a minor grid mark, if they are smaller
+/// than the base.
+const MINOR_MULTIPLES: [f64; 2] = [2.0, 5.0];
+
+/// A logarithmic axis, e.g. for spectra or magnitudes spanning many orders of
+/// magnitude.
+///
+/// In plot space, each power of the base (a "decade" for base 10) has the
+/// same width. Major grid marks land on the powers of the base, with minor
+/// marks at 2× and 5× each power.
+///
+/// Only strictly positive data values can be shown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogAxisTransform {
+    base: f64,
+}
+
+impl Default for LogAxisTransform {
+    fn default() -> Self {
+        Self::new(10.0)
+    }
+}
+
+impl LogAxisTransform {
+    /// A logarithmic axis with the given base, e.g. `10.0` or `2.0`.
+    ///
+    /// Panics in debug builds if `base <= 1`.
+    pub fn new(base: f64) -> Self {
+        debug_assert!(base > 1.0, "The base of a logarithmic axis must be > 1, got {base}");
+        Self { base }
+    }
+
+    /// The base of the logarithm.
+    #[inline]
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
+    /// Distance in plot space between the two closest marks within a decade.
+    fn minor_step_size(&self) -> f64 {
+        let mut previous = 0.0;
+        let mut smallest = 1.0_f64;
+        for multiple in MINOR_MULTIPLES.iter().filter(|&&m| m < self.base) {
+            let position = self.data_to_plot(*multiple);
+            smallest = smallest.min(position - previous);
+            previous = position;
+        }
+        smallest.min(1.0 - previous)
+    }
+}
+
+impl AxisTransform for LogAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        if self.base == 10.0 {
+            // Exact for powers of ten, unlike `ln(x) / ln(10)`.
+            value.log10()
+        } else {
+            value.log(self.base)
+        }
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        self.base.powf(value)
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // Never put major marks closer than one power of the base. When zoomed
+        // far out, skip powers in steps of 10, 100, … instead.
+        let smallest_visible_unit = next_power(input.base_step_size, 10.0).max(1.0);
+        let step_sizes = [
+            smallest_visible_unit,
+            smallest_visible_unit * 10.0,
+            smallest_visible_unit * 100.0,
+        ];
+
+        let mut marks: Vec<GridMark> = generate_marks(step_sizes, input.bounds)
+            .into_iter()
+            .map(|mark| GridMark {
+                value: self.plot_to_data(mark.value),
+                step_size: mark.step_size,
+            })
+            .collect();
+
+        // Like the linear spacers, never put marks closer than the base step
+        // size, so the number of minor marks is bounded by the number of
+        // steps in the bounds, however far out the plot is zoomed.
+        let minor_step_size = self.minor_step_size();
+        if input.base_step_size.abs() <= minor_step_size {
+            for exponent in (min.floor() as i64)..=(max.floor() as i64) {
+                let power = self.plot_to_data(exponent as f64);
+                for multiple in MINOR_MULTIPLES.iter().filter(|&&m| m < self.base) {
+                    let position = exponent as f64 + self.data_to_plot(*multiple);
+                    if min <= position && position <= max {
+                        marks.push(GridMark {
+                            value: multiple * power,
+                            step_size: minor_step_size,
+                        });
+                    }
+                }
+            }
+            marks.sort_by(|a, b| a.value.total_cmp(&b.value));
+        }
+
+        marks
+    }
+
+    fn minor_grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // The closest ones are the last multiple and the next power. Like the
+        // labeled minor marks, only if they aren't closer than the base step.
+        let step_size = 1.0 - self.data_to_plot(self.base.ceil() - 1.0);
+        if input.base_step_size.abs() > step_size {
+            return Vec::new();
+        }
+
+        // All remaining integer multiples of each power, e.g. 3×, 4×, 6×, 7×, 8× and 9×.
+        let multiples: Vec<f64> = (2..)
+            .map(f64::from)
+            .take_while(|&multiple| multiple < self.base)
+            .filter(|multiple| !MINOR_MULTIPLES.contains(multiple))
+            .collect();
+
+        let mut marks = Vec::new();
+        for exponent in (min.floor() as i64)..=(max.floor() as i64) {
+            let power = self.plot_to_data(exponent as f64);
+            for multiple in &multiples {
+                let position = exponent as f64 + self.data_to_plot(*multiple);
+                if min <= position && position <= max {
+                    marks.push(GridMark {
+                        value: multiple * power,
+                        step_size,
+                    });
+                }
+            }
+        }
+        marks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let transform = LogAxisTransform::default();
+        assert_eq!(transform.data_to_plot(1000.0), 3.0);
+        assert!((transform.plot_to_data(-2.0) - 0.01).abs() < 1e-15);
+
+        let transform = LogAxisTransform::new(2.0);
+        assert!((transform.data_to_plot(8.0) - 3.0).abs() < 1e-12);
+        assert!((transform.plot_to_data(transform.data_to_plot(0.3)) - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn decade_and_minor_marks() {
+        let transform = LogAxisTransform::default();
+        let marks = transform.grid_marks(GridInput {
+            bounds: (0.0, 2.0), // 1..=100
+            base_step_size: 0.05,
+        });
+
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+        assert_eq!(values, vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0]);
+
+        let major = marks.iter().find(|mark| mark.value == 10.0).unwrap();
+        let minor = marks.iter().find(|mark| mark.value == 20.0).unwrap();
+        assert!(major.step_size > minor.step_size, "Major marks should be thicker");
+    }
+
+    #[test]
+    fn unlabeled_minor_marks() {
+        let transform = LogAxisTransform::default();
+        let marks = transform.minor_grid_marks(GridInput {
+            bounds: (0.0, 1.0), // 1..=10
+            base_step_size: 0.01,
+        });
+
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+        assert_eq!(values, vec![3.0, 4.0, 6.0, 7.0, 8.0, 9.0]);
+        assert!(marks.iter().all(|mark| mark.step_size < transform.minor_step_size()));
+    }
+
+    #[test]
+    fn minor_marks_are_limited_by_the_base_step_size() {
+        let transform = LogAxisTransform::default();
+        let input = |bounds, base_step_size| GridInput { bounds, base_step_size };
+
+        // A million decades at a step size too coarse for the minor marks.
+        let marks = transform.grid_marks(input((-5e5, 5e5), 0.5));
+        assert!(marks.iter().all(|mark| mark.step_size >= 1.0), "Expected only powers");
+        assert!(transform.minor_grid_marks(input((-5e5, 5e5), 0.5)).is_empty());
+
+        // Fine enough for the labeled minor marks, but not for the rest.
+        assert_eq!(transform.grid_marks(input((0.0, 3.0), 0.1)).len(), 9);
+        assert!(transform.minor_grid_marks(input((0.0, 3.0), 0.1)).is_empty());
+    }
+
+    #[test]
+    fn zoomed_out_skips_minor_marks() {
+        let transform = LogAxisTransform::default();
+        let marks = transform.grid_marks(GridInput {
+            bounds: (-30.0, 30.0),
+            base_step_size: 2.0,
+        });
+
+        assert!(!marks.is_empty(), "Expected some marks");
+        for mark in marks {
+            let exponent = transform.data_to_plot(mark.value);
+            assert!(
+                (exponent / 10.0 - (exponent / 10.0).round()).abs() < 1e-9,
+                "Expected only every tenth power, got {}",
+                mark.value
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::log_grid_marks;
+
+/// Probabilities closer than this to `0` or `1` are clamped.
+const CLAMP: f64 = 1e-12;
+
+/// Number of decades in each tail that get grid marks, down to [`CLAMP`].
+const MAX_DECADES: i32 = 12;
+
+/// Minor marks in the middle of the axis, besides `0.5`.
+const MIDDLE_MINOR: [f64; 3] = [0.2, 0.3, 0.4];
+
+/// Minor marks within each decade of the tails, e.g. `0.02` and `0.05`.
+const TAIL_MINOR: [f64; 2] = [2.0, 5.0];
+
+/// A probability ("logit") axis, e.g. for CDFs or calibration curves.
+///
+/// Maps a probability `p` to `log10(p / (1 - p))`, so `0.5` sits at zero and
+/// each step towards `0` or `1` (`0.1`, `0.01`, … and `0.9`, `0.99`, …) gets
+/// roughly the same width. Values at or beyond `0` and `1` are clamped to
+/// `1e-12` away from them, so they end up at the edge instead of at infinity.
+///
+/// Grid marks land on these standard levels, with minor marks at `0.2`–`0.8`
+/// and 2× and 5× each power in the tails. When zoomed in far enough that
+/// there are no standard levels left, the marks are spaced linearly.
+///
+/// Pair this with [`Self::format`] to label the ticks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LogitAxisTransform;
+
+impl LogitAxisTransform {
+    /// Format a grid mark as a probability, with as many decimals as needed,
+    /// e.g. `0.5`, `0.001` or `0.999`.
+    #[expect(
+        clippy::unused_self,
+        reason = "called on the transform, like the `AxisTransform` methods"
+    )]
+    pub fn format(&self, mark: GridMark) -> String {
+        let formatted = format!("{:.12}", mark.value);
+        formatted.trim_end_matches('0').trim_end_matches('.').to_owned()
+    }
+
+    /// Evenly spaced marks in data space, for when zoomed in between the
+    /// standard levels.
+    fn linear_marks(&self, input: &GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+
+        // How far the plot space moves per data unit, around the center of the view.
+        let center = self.plot_to_data(0.5 * (min + max));
+        let dplot_ddata = 1.0 / (std::f64::consts::LN_10 * center * (1.0 - center));
+
+        let data_input = GridInput {
+            bounds: (self.plot_to_data(min), self.plot_to_data(max)),
+            base_step_size: input.base_step_size / dplot_ddata,
+        };
+        log_grid_marks(&data_input, 10.0)
+            .into_iter()
+            .map(|mark| GridMark {
+                value: mark.value,
+                step_size: mark.step_size * dplot_ddata,
+            })
+            .collect()
+    }
+}
+
+impl AxisTransform for LogitAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        let p = value.clamp(CLAMP, 1.0 - CLAMP);
+        (p / (1.0 - p)).log10()
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        1.0 / (1.0 + 10_f64.powf(-value))
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // Every decade if there is room for it, otherwise every 2nd, 5th, 10th, …
+        let stride = (0..)
+            .flat_map(|exponent| [1, 2, 5].map(|m| 10_i32.saturating_pow(exponent).saturating_mul(m)))
+            .find(|&stride| f64::from(stride) >= input.base_step_size)
+            .unwrap_or(1);
+
+        let mut marks = Vec::new();
+        let mut push = |value: f64, step_size: f64| {
+            let position = self.data_to_plot(value);
+            if min <= position && position <= max {
+                marks.push(GridMark { value, step_size });
+            }
+        };
+
+        // Major marks: 0.5, and 0.1, 0.01, … and 0.9, 0.99, …
+        let major_step_size = f64::from(stride);
+        push(0.5, major_step_size);
+        for decade in (1..=MAX_DECADES).filter(|decade| decade % stride == 0) {
+            let power = 10_f64.powi(-decade);
+            push(power, major_step_size);
+            push(1.0 - power, major_step_size);
+        }
+
+        // Minor marks only make sense if every decade is shown.
+        if stride == 1 {
+            // The closest minor marks are 0.4 and 0.5.
+            let minor_step_size = self.data_to_plot(0.5) - self.data_to_plot(0.4);
+            for value in MIDDLE_MINOR {
+                push(value, minor_step_size);
+                push(1.0 - value, minor_step_size);
+            }
+            for decade in 2..=MAX_DECADES {
+                let power = 10_f64.powi(-decade);
+                for multiple in TAIL_MINOR {
+                    push(multiple * power, minor_step_size);
+                    push(1.0 - multiple * power, minor_step_size);
+                }
+            }
+        }
+
+        if marks.len() < 3 {
+            return self.linear_marks(&input);
+        }
+
+        marks.sort_by(|a, b| a.value.total_cmp(&b.value));
+        marks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symmetric_and_clamped() {
+        let transform = LogitAxisTransform;
+        assert_eq!(transform.data_to_plot(0.5), 0.0);
+        assert!((transform.data_to_plot(0.01) + transform.data_to_plot(0.99)).abs() < 1e-12);
+        assert!((transform.plot_to_data(transform.data_to_plot(0.123)) - 0.123).abs() < 1e-12);
+
+        assert!(transform.data_to_plot(0.0).is_finite());
+        assert!(transform.data_to_plot(1.5).is_finite());
+        assert_eq!(transform.data_to_plot(-1.0), transform.data_to_plot(0.0));
+    }
+
+    #[test]
+    fn standard_levels() {
+        let transform = LogitAxisTransform;
+        let marks = transform.grid_marks(GridInput {
+            bounds: (transform.data_to_plot(0.005), transform.data_to_plot(0.995)),
+            base_step_size: 0.5,
+        });
+        let major: Vec<String> = marks
+            .iter()
+            .filter(|mark| mark.step_size >= 1.0)
+            .map(|mark| transform.format(*mark))
+            .collect();
+        assert_eq!(major, vec!["0.01", "0.1", "0.5", "0.9", "0.99"]);
+
+        let labels: Vec<String> = marks.iter().map(|mark| transform.format(*mark)).collect();
+        for label in [
+            "0.01", "0.02", "0.05", "0.1", "0.2", "0.5", "0.8", "0.95", "0.98", "0.99",
+        ] {
+            assert!(labels.iter().any(|l| l == label), "Missing {label} in {labels:?}");
+        }
+    }
+
+    #[test]
+    fn linear_when_zoomed_in() {
+        let transform = LogitAxisTransform;
+        let marks = transform.grid_marks(GridInput {
+            bounds: (transform.data_to_plot(0.42), transform.data_to_plot(0.48)),
+            base_step_size: 0.001,
+        });
+        assert!(marks.len() >= 3, "Expected some marks, got {marks:?}");
+        assert!(marks.iter().any(|mark| (mark.value - 0.45).abs() < 1e-12));
+    }
+}
@@ -0,0 +1,253 @@
+//! Non-linear mappings between data values and the linear plot space.
+
+use std::sync::Arc;
+
+pub use crate::axis_transform::broken::BrokenAxisTransform;
+pub use crate::axis_transform::categorical::CategoricalAxisTransform;
+pub use crate::axis_transform::decibel::DecibelAxisTransform;
+pub use crate::axis_transform::logarithmic::LogAxisTransform;
+pub use crate::axis_transform::logit::LogitAxisTransform;
+pub use crate::axis_transform::power::PowerAxisTransform;
+pub use crate::axis_transform::power::SqrtAxisTransform;
+pub use crate::axis_transform::symlog::SymlogAxisTransform;
+pub use crate::axis_transform::time::TimeAxisTransform;
+pub use crate::axis_transform::time::TimeZone;
+pub use crate::axis_transform::time::UtcOffset;
+use crate::bounds::PlotBounds;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+
+mod broken;
+mod categorical;
+mod decibel;
+mod logarithmic;
+mod logit;
+mod power;
+mod symlog;
+mod time;
+
+/// Maps the values of one axis between "data space" and "plot space".
+///
+/// Data space is what the user passes in and reads back (e.g. `1000.0` Hz).
+/// Plot space is what gets laid out linearly on screen (e.g. `3.0` for a
+/// base-10 logarithmic axis).
+///
+/// The mapping must be increasing, and strictly so outside of [`Self::breaks`].
+///
+/// Attach a transform to a plot with [`crate::Plot::x_axis_transform`] and
+/// [`crate::Plot::y_axis_transform`].
+pub trait AxisTransform: Send + Sync {
+    /// Convert a data value to plot space.
+    ///
+    /// May return a non-finite value for data outside the domain of the
+    /// transform (e.g. negative values on a logarithmic axis).
+    fn data_to_plot(&self, value: f64) -> f64;
+
+    /// Convert a plot-space value back to data space.
+    fn plot_to_data(&self, value: f64) -> f64;
+
+    /// Generate the grid marks for the visible range.
+    ///
+    /// `input` is expressed in plot space. The returned [`GridMark::value`]s
+    /// are in data space, so that they can be passed as-is to an axis
+    /// formatter, while [`GridMark::step_size`] is in plot space, since it
+    /// determines how far apart the lines are on screen.
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark>;
+
+    /// Generate minor marks for the visible range, in addition to
+    /// [`Self::grid_marks`].
+    ///
+    /// Minor marks subdivide the major ones without being labeled, e.g. the
+    /// 3×, 4×, 6×, … marks of a logarithmic axis. The plot draws them as
+    /// short, faint ticks along its edges. Uses the same conventions as
+    /// [`Self::grid_marks`]. Empty by default.
+    fn minor_grid_marks(&self, _input: GridInput) -> Vec<GridMark> {
+        Vec::new()
+    }
+
+    /// Data values where the axis skips a range, e.g. for a
+    /// [`BrokenAxisTransform`].
+    ///
+    /// The plot draws a break indicator at each of them. Empty by default.
+    fn breaks(&self) -> Vec<f64> {
+        Vec::new()
+    }
+}
+
+/// The identity transform: data space and plot space are the same.
+///
+/// The grid is split recursively into powers of ten, like
+/// [`crate::log_grid_spacer`] with base `10`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinearAxisTransform;
+
+impl AxisTransform for LinearAxisTransform {
+    #[inline]
+    fn data_to_plot(&self, value: f64) -> f64 {
+        value
+    }
+
+    #[inline]
+    fn plot_to_data(&self, value: f64) -> f64 {
+        value
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        crate::grid::log_grid_marks(&input, 10.0)
+    }
+}
+
+/// Grid marks at round data values, for transforms where round plot-space
+/// values don't mean anything to the user (e.g. a square root axis).
+///
+/// The marks are multiples of powers of ten in data space. The step is chosen
+/// such that the marks are at least [`GridInput::base_step_size`] apart in
+/// plot space where they are closest, and each [`GridMark::step_size`] is that
+/// closest distance. This assumes the transform is steepest at the edges of
+/// the view or at zero.
+pub(crate) fn round_data_marks<T: AxisTransform + ?Sized>(transform: &T, input: &GridInput) -> Vec<GridMark> {
+    let (min, max) = input.bounds;
+    let (data_min, data_max) = (transform.plot_to_data(min), transform.plot_to_data(max));
+    if !data_min.is_finite() || !data_max.is_finite() || data_min >= data_max || input.base_step_size <= 0.0 {
+        return Vec::new();
+    }
+
+    // The smallest distance in plot space between two marks `step` apart in data space.
+    let closest = |step: f64| {
+        let mut closest =
+            (transform.data_to_plot(data_min + step) - min).min(max - transform.data_to_plot(data_max - step));
+        if data_min < 0.0 && 0.0 < data_max {
+            closest = closest.min(transform.data_to_plot(step) - transform.data_to_plot(0.0));
+        }
+        closest
+    };
+
+    // Start from the step a linear axis would use, then adjust by powers of ten.
+    let mut step = crate::grid::next_power((data_max - data_min) * input.base_step_size / (max - min), 10.0);
+    for _ in 0..30 {
+        if closest(step) < input.base_step_size {
+            step *= 10.0;
+        } else if closest(step / 10.0) >= input.base_step_size {
+            step /= 10.0;
+        } else {
+            break;
+        }
+    }
+
+    let step_sizes = [step, 10.0 * step, 100.0 * step];
+    let plot_step_sizes = step_sizes.map(closest);
+    crate::grid::generate_marks(step_sizes, (data_min, data_max))
+        .into_iter()
+        .map(|mark| GridMark {
+            value: mark.value,
+            step_size: step_sizes
+                .iter()
+                .zip(plot_step_sizes)
+                .find(|(data_step, _)| **data_step == mark.step_size)
+                .map_or(mark.step_size, |(_, plot_step)| plot_step),
+        })
+        .collect()
+}
+
+/// The transforms of the X and Y axes. `None` is the same as
+/// [`LinearAxisTransform`], but faster.
+pub(crate) type AxisTransforms = [Option<Arc<dyn AxisTransform>>; 2];
+
+/// Convert a data value to plot space.
+#[inline]
+pub(crate) fn data_to_plot(transform: Option<&Arc<dyn AxisTransform>>, value: f64) -> f64 {
+    transform.map_or(value, |t| t.data_to_plot(value))
+}
+
+/// Convert a plot-space value to data space.
+#[inline]
+pub(crate) fn plot_to_data(transform: Option<&Arc<dyn AxisTransform>>, value: f64) -> f64 {
+    transform.map_or(value, |t| t.plot_to_data(value))
+}
+
+/// Convert data-space bounds to plot space.
+pub(crate) fn bounds_to_plot(transforms: &AxisTransforms, bounds: &PlotBounds) -> PlotBounds {
+    if transforms.iter().all(Option::is_none) {
+        return *bounds;
+    }
+    PlotBounds::from_min_max(
+        [0, 1].map(|d| data_to_plot(transforms[d].as_ref(), bounds.min[d])),
+        [0, 1].map(|d| data_to_plot(transforms[d].as_ref(), bounds.max[d])),
+    )
+}
+
+/// Convert plot-space bounds to data space.
+pub(crate) fn bounds_to_data(transforms: &AxisTransforms, bounds: &PlotBounds) -> PlotBounds {
+    if transforms.iter().all(Option::is_none) {
+        return *bounds;
+    }
+    PlotBounds::from_min_max(
+        [0, 1].map(|d| plot_to_data(transforms[d].as_ref(), bounds.min[d])),
+        [0, 1].map(|d| plot_to_data(transforms[d].as_ref(), bounds.max[d])),
+    )
+}
+
+/// Apply `modify` to `bounds` in plot space.
+///
+/// Only axes whose plot-space range was changed are written back, so that
+/// untouched axes don't drift from repeated round trips.
+pub(crate) fn modify_in_plot_space(
+    transforms: &AxisTransforms,
+    bounds: &mut PlotBounds,
+    modify: impl FnOnce(&mut PlotBounds),
+) {
+    let old = bounds_to_plot(transforms, bounds);
+    let mut new = old;
+    modify(&mut new);
+    let data = bounds_to_data(transforms, &new);
+    if new.range_x() != old.range_x() {
+        bounds.set_x(&data);
+    }
+    if new.range_y() != old.range_y() {
+        bounds.set_y(&data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::Rect;
+    use emath::pos2;
+
+    use super::*;
+    use crate::axis::PlotTransform;
+
+    #[test]
+    fn plot_transform_is_linear_in_plot_space() {
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 100.0));
+        let bounds = PlotBounds::from_min_max([1.0, 0.0], [100.0, 1.0]);
+        let mut transform = PlotTransform::new_with_axis_transforms(
+            frame,
+            bounds,
+            false,
+            false,
+            [Some(Arc::new(LogAxisTransform::default())), None],
+        );
+
+        assert_eq!(transform.position_from_point_x(10.0), 100.0);
+        let value = transform.value_from_position(pos2(150.0, 50.0));
+        assert!((value.x - 10_f64.powf(1.5)).abs() < 1e-9);
+
+        // Panning by half the frame moves by one decade.
+        transform.translate_bounds((100.0, 0.0));
+        assert!((transform.bounds().min[0] - 10.0).abs() < 1e-9);
+        assert!((transform.bounds().max[0] - 1000.0).abs() < 1e-9);
+        assert_eq!(transform.bounds().range_y(), 0.0..=1.0);
+        assert!(
+            (transform.position_from_point_x(100.0) - 100.0).abs() < 1e-4,
+            "positions follow the panned bounds"
+        );
+
+        transform.set_bounds(PlotBounds::from_min_max([1.0, 0.0], [10_000.0, 1.0]));
+        assert_eq!(transform.position_from_point_x(100.0), 100.0);
+        transform.set_axis_transforms([None, None]);
+        assert!(
+            (transform.position_from_point_x(5_000.5) - 100.0).abs() < 1e-4,
+            "linear again without the transform"
+        );
+    }
+}
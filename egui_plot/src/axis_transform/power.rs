@@ -0,0 +1,109 @@
+use crate::axis_transform::AxisTransform;
+use crate::axis_transform::round_data_marks;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+
+/// A power axis, mapping `x` to `x^exponent`.
+///
+/// Negative values are mirrored, i.e. `-x` maps to `-(x^exponent)`, so the
+/// whole axis can be shown. An exponent below `1` spreads out small values,
+/// above `1` large ones.
+///
+/// Grid marks stay at round data values (multiples of powers of ten), not at
+/// round transformed values.
+///
+/// See also [`SqrtAxisTransform`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerAxisTransform {
+    exponent: f64,
+}
+
+impl PowerAxisTransform {
+    /// A power axis with the given exponent, e.g. `0.5` for a square root.
+    ///
+    /// Panics in debug builds if `exponent <= 0`.
+    pub fn new(exponent: f64) -> Self {
+        debug_assert!(
+            exponent > 0.0,
+            "The exponent of a power axis must be > 0, got {exponent}"
+        );
+        Self { exponent }
+    }
+
+    /// The exponent of the power.
+    #[inline]
+    pub fn exponent(&self) -> f64 {
+        self.exponent
+    }
+}
+
+impl AxisTransform for PowerAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        value.signum() * value.abs().powf(self.exponent)
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        value.signum() * value.abs().powf(self.exponent.recip())
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        round_data_marks(self, &input)
+    }
+}
+
+/// A square root axis, e.g. for counts and histograms.
+///
+/// The same as a [`PowerAxisTransform`] with exponent `0.5`, but exact for
+/// perfect squares.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SqrtAxisTransform;
+
+impl AxisTransform for SqrtAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        value.signum() * value.abs().sqrt()
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        value.signum() * value * value
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        round_data_marks(self, &input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let sqrt = SqrtAxisTransform;
+        assert_eq!(sqrt.data_to_plot(16.0), 4.0);
+        assert_eq!(sqrt.data_to_plot(-16.0), -4.0);
+        assert_eq!(sqrt.plot_to_data(-3.0), -9.0);
+
+        let cube = PowerAxisTransform::new(3.0);
+        for value in [-2.5, 0.0, 0.1, 7.0] {
+            assert!((cube.plot_to_data(cube.data_to_plot(value)) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn marks_at_round_data_values() {
+        let sqrt = SqrtAxisTransform;
+        let marks = sqrt.grid_marks(GridInput {
+            bounds: (0.0, 100.0), // 0..=10_000
+            base_step_size: 2.0,
+        });
+
+        assert!(!marks.is_empty(), "Expected some marks");
+        for mark in &marks {
+            assert_eq!(mark.value % 100.0, 0.0, "Not a round value: {}", mark.value);
+        }
+
+        // The closest marks are at the top end, where the root is flattest.
+        let finest = marks.iter().map(|mark| mark.step_size).fold(f64::INFINITY, f64::min);
+        assert!(finest >= 2.0, "Marks too close: {finest}");
+    }
+}
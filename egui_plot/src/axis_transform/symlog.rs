@@ -0,0 +1,165 @@
+use crate::axis_transform::AxisTransform;
+use crate::axis_transform::LogAxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::log_grid_marks;
+
+/// A symmetric logarithmic ("symlog") axis for signed data spanning many
+/// orders of magnitude, e.g. `±1e-6` to `±1e6`.
+///
+/// Values within `±linear_threshold` are mapped linearly, values beyond it
+/// logarithmically (base 10), on both sides of zero. In plot space the linear
+/// region spans `-1..=1`, and each decade beyond it has a width of `1`.
+///
+/// Grid marks are spaced linearly near zero and switch to decades (with 2×
+/// and 5× minor marks) in the logarithmic regions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymlogAxisTransform {
+    linear_threshold: f64,
+}
+
+impl Default for SymlogAxisTransform {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl SymlogAxisTransform {
+    /// A symlog axis that is linear within `±linear_threshold`.
+    ///
+    /// Panics in debug builds if `linear_threshold <= 0`.
+    pub fn new(linear_threshold: f64) -> Self {
+        debug_assert!(
+            linear_threshold > 0.0,
+            "The linear threshold of a symlog axis must be > 0, got {linear_threshold}"
+        );
+        Self { linear_threshold }
+    }
+
+    /// The data value at which the axis switches from linear to logarithmic.
+    #[inline]
+    pub fn linear_threshold(&self) -> f64 {
+        self.linear_threshold
+    }
+
+    /// Marks for the logarithmic region `1..=max` (in plot space) on the
+    /// positive side.
+    fn log_marks(&self, input: &GridInput, max: f64) -> Vec<GridMark> {
+        // The plot space of a base-10 log axis, shifted so that the threshold maps to 1.
+        let offset = self.linear_threshold.log10() - 1.0;
+        LogAxisTransform::default().grid_marks(GridInput {
+            bounds: (1.0 + offset, max + offset),
+            base_step_size: input.base_step_size,
+        })
+    }
+}
+
+impl AxisTransform for SymlogAxisTransform {
+    fn data_to_plot(&self, value: f64) -> f64 {
+        let t = self.linear_threshold;
+        if value.abs() <= t {
+            value / t
+        } else {
+            value.signum() * (1.0 + (value.abs() / t).log10())
+        }
+    }
+
+    fn plot_to_data(&self, value: f64) -> f64 {
+        let t = self.linear_threshold;
+        if value.abs() <= 1.0 {
+            value * t
+        } else {
+            value.signum() * t * 10_f64.powf(value.abs() - 1.0)
+        }
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+        let t = self.linear_threshold;
+
+        let mut marks = Vec::new();
+
+        // Linear region: ordinary power-of-ten marks in data space.
+        let (linear_min, linear_max) = (min.max(-1.0), max.min(1.0));
+        if linear_min <= linear_max {
+            let linear_input = GridInput {
+                bounds: (linear_min * t, linear_max * t),
+                base_step_size: input.base_step_size * t,
+            };
+            marks.extend(log_grid_marks(&linear_input, 10.0).into_iter().map(|mark| GridMark {
+                value: mark.value,
+                step_size: mark.step_size / t,
+            }));
+        }
+
+        // Logarithmic regions, mirrored around zero.
+        if max > 1.0 {
+            marks.extend(self.log_marks(&input, max));
+        }
+        if min < -1.0 {
+            marks.extend(self.log_marks(&input, -min).into_iter().map(|mark| GridMark {
+                value: -mark.value,
+                step_size: mark.step_size,
+            }));
+        }
+
+        // The threshold itself may have been generated by both regions.
+        marks.sort_by(|a, b| a.value.total_cmp(&b.value));
+        let mut deduplicated: Vec<GridMark> = Vec::with_capacity(marks.len());
+        for mark in marks {
+            if let Some(last) = deduplicated.last_mut() {
+                if (last.value - mark.value).abs() <= 1e-9 * mark.value.abs() {
+                    if last.step_size < mark.step_size {
+                        *last = mark;
+                    }
+                    continue;
+                }
+            }
+            deduplicated.push(mark);
+        }
+        deduplicated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let transform = SymlogAxisTransform::new(1e-6);
+        for value in [-1e6, -3.5, -1e-6, -1e-7, 0.0, 2e-7, 1e-6, 42.0, 1e6] {
+            let back = transform.plot_to_data(transform.data_to_plot(value));
+            assert!((back - value).abs() <= 1e-9 * value.abs(), "{value} -> {back}");
+        }
+        assert_eq!(transform.data_to_plot(1e-6), 1.0);
+        assert!((transform.data_to_plot(-1e6) + 13.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn linear_near_zero_and_log_beyond() {
+        let transform = SymlogAxisTransform::new(1.0);
+        let marks = transform.grid_marks(GridInput {
+            bounds: (-3.0, 3.0), // -100..=100
+            base_step_size: 0.05,
+        });
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+
+        // Log region: decades and minor marks, on both sides.
+        for value in [-50.0, -20.0, -10.0, -5.0, -2.0, 2.0, 5.0, 10.0, 20.0, 50.0] {
+            assert!(values.contains(&value), "Missing {value} in {values:?}");
+        }
+        // Linear region: evenly spaced.
+        for value in [-0.5, 0.0, 0.1, 0.5, 1.0] {
+            assert!(
+                values.iter().any(|v| (v - value).abs() < 1e-12),
+                "Missing {value} in {values:?}"
+            );
+        }
+        // No duplicates at the threshold.
+        assert_eq!(values.iter().filter(|&&v| v == 1.0).count(), 1);
+    }
+}
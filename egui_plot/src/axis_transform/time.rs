@@ -0,0 +1,465 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::axis_transform::AxisTransform;
+use crate::grid::GridInput;
+use crate::grid::GridMark;
+use crate::grid::log_grid_marks;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// Approximate length of a month, used as [`GridMark::step_size`].
+const MONTH: f64 = 30.0 * DAY;
+
+/// Approximate length of a year, used as [`GridMark::step_size`].
+const YEAR: f64 = 365.0 * DAY;
+
+/// A step between two grid marks on a time axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimeStep {
+    /// A fixed number of seconds, aligned to the unix epoch.
+    Seconds(f64),
+
+    /// Whole months, aligned to the start of the year.
+    Months(i64),
+
+    /// Whole years, aligned to year zero.
+    Years(i64),
+}
+
+impl TimeStep {
+    /// All steps from one second up to one year. Longer steps are generated
+    /// on demand by [`Self::nth`].
+    const TABLE: [Self; 20] = [
+        Self::Seconds(1.0),
+        Self::Seconds(2.0),
+        Self::Seconds(5.0),
+        Self::Seconds(10.0),
+        Self::Seconds(15.0),
+        Self::Seconds(30.0),
+        Self::Seconds(MINUTE),
+        Self::Seconds(2.0 * MINUTE),
+        Self::Seconds(5.0 * MINUTE),
+        Self::Seconds(10.0 * MINUTE),
+        Self::Seconds(15.0 * MINUTE),
+        Self::Seconds(30.0 * MINUTE),
+        Self::Seconds(HOUR),
+        Self::Seconds(3.0 * HOUR),
+        Self::Seconds(6.0 * HOUR),
+        Self::Seconds(12.0 * HOUR),
+        Self::Seconds(DAY),
+        Self::Seconds(2.0 * DAY),
+        Self::Months(1),
+        Self::Months(3),
+    ];
+
+    /// The `n`th step, ordered by length.
+    ///
+    /// After the table: 6 months, then 1, 2, 5, 10, 20, 50, … years.
+    fn nth(n: usize) -> Self {
+        if let Some(step) = Self::TABLE.get(n) {
+            return *step;
+        }
+        match n - Self::TABLE.len() {
+            0 => Self::Months(6),
+            n => {
+                let n = n - 1;
+                let decade = 10_i64.saturating_pow((n / 3) as u32);
+                Self::Years(decade.saturating_mul([1, 2, 5][n % 3]))
+            }
+        }
+    }
+
+    /// The (approximate) length of this step, in seconds.
+    fn approx_seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Months(months) => months as f64 * MONTH,
+            Self::Years(years) => years as f64 * YEAR,
+        }
+    }
+
+    /// All marks of this step within `min..=max`.
+    fn marks(self, (min, max): (f64, f64)) -> Vec<GridMark> {
+        let step_size = self.approx_seconds();
+        match self {
+            Self::Seconds(seconds) => {
+                let first = (min / seconds).ceil() as i64;
+                let last = (max / seconds).floor() as i64;
+                (first..=last)
+                    .map(|i| GridMark {
+                        value: i as f64 * seconds,
+                        step_size,
+                    })
+                    .collect()
+            }
+            Self::Months(months) => calendar_marks(months, step_size, (min, max)),
+            Self::Years(years) => calendar_marks(years.saturating_mul(12), step_size, (min, max)),
+        }
+    }
+}
+
+/// Marks on the first day of every `months`th month within `min..=max`.
+fn calendar_marks(months: i64, step_size: f64, (min, max): (f64, f64)) -> Vec<GridMark> {
+    let (year, month, _) = civil_from_days((min / DAY).floor() as i64);
+    let mut index = (year * 12 + month - 1).div_euclid(months) * months;
+    let mut marks = Vec::new();
+    loop {
+        let value = days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1) as f64 * DAY;
+        if value > max {
+            break;
+        }
+        if min <= value {
+            marks.push(GridMark { value, step_size });
+        }
+        index += months;
+    }
+    marks
+}
+
+/// A time zone for a [`TimeAxisTransform`].
+///
+/// This crate has no time zone database of its own. To show local time with
+/// daylight saving, implement this for a time zone of a crate that has one,
+/// or pass a closure, e.g. with a `chrono_tz::Tz`:
+/// ```ignore
+/// use chrono::Offset as _;
+/// use chrono::TimeZone as _;
+///
+/// let tz = chrono_tz::Europe::Berlin;
+/// let time = egui_plot::TimeAxisTransform::new(move |timestamp: f64| {
+///     let utc = chrono::DateTime::from_timestamp(timestamp.floor() as i64, 0).unwrap_or_default();
+///     tz.offset_from_utc_datetime(&utc.naive_utc()).fix().local_minus_utc() as f64
+/// });
+/// ```
+pub trait TimeZone: Send + Sync {
+    /// The offset of local time from UTC at the given unix timestamp, in
+    /// seconds, e.g. `3600.0` for UTC+1.
+    fn utc_offset(&self, timestamp: f64) -> f64;
+}
+
+impl<F: Fn(f64) -> f64 + Send + Sync> TimeZone for F {
+    #[inline]
+    fn utc_offset(&self, timestamp: f64) -> f64 {
+        self(timestamp)
+    }
+}
+
+/// A time zone with a fixed offset from UTC, without daylight saving.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UtcOffset {
+    seconds: f64,
+}
+
+impl UtcOffset {
+    /// An offset of the given number of seconds ahead of UTC.
+    #[inline]
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self { seconds }
+    }
+
+    /// An offset of the given number of hours ahead of UTC, e.g. `-5.0` or
+    /// `5.5`.
+    #[inline]
+    pub fn from_hours(hours: f64) -> Self {
+        Self::from_seconds(hours * HOUR)
+    }
+}
+
+impl TimeZone for UtcOffset {
+    #[inline]
+    fn utc_offset(&self, _timestamp: f64) -> f64 {
+        self.seconds
+    }
+}
+
+/// A time axis for unix timestamps, in seconds.
+///
+/// Data space and plot space are the same, but grid marks snap to natural
+/// time boundaries (seconds, minutes, hours, days, months and years) instead
+/// of powers of ten. Months and years follow the calendar, so their marks
+/// are not evenly spaced.
+///
+/// The boundaries are in UTC by default, or in local time with
+/// [`Self::new`]. Across a daylight saving transition, marks stay on local
+/// midnight, full hours, and so on.
+///
+/// Pair this with [`Self::format`] to label the ticks:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::Plot;
+/// use egui_plot::TimeAxisTransform;
+/// use egui_plot::UtcOffset;
+///
+/// let time = TimeAxisTransform::new(UtcOffset::from_hours(2.0));
+/// let formatter = time.clone();
+/// Plot::new("my_plot")
+///     .x_axis_transform(Box::new(time))
+///     .x_axis_formatter(move |mark, _range| formatter.format(mark))
+///     .show(ui, |_plot_ui| {});
+/// # });
+/// ```
+#[derive(Clone, Default)]
+pub struct TimeAxisTransform {
+    /// `None` for UTC.
+    time_zone: Option<Arc<dyn TimeZone>>,
+}
+
+impl Debug for TimeAxisTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimeAxisTransform")
+            .field("utc", &self.time_zone.is_none())
+            .finish()
+    }
+}
+
+impl TimeAxisTransform {
+    /// A time axis in the given time zone, e.g. a [`UtcOffset`].
+    pub fn new(time_zone: impl TimeZone + 'static) -> Self {
+        Self {
+            time_zone: Some(Arc::new(time_zone)),
+        }
+    }
+
+    /// The offset of local time from UTC at the given unix timestamp.
+    fn utc_offset(&self, timestamp: f64) -> f64 {
+        self.time_zone.as_ref().map_or(0.0, |tz| tz.utc_offset(timestamp))
+    }
+
+    /// Convert a local timestamp back to UTC.
+    ///
+    /// Local times that are skipped or repeated by a daylight saving
+    /// transition map to one of the candidates.
+    fn local_to_utc(&self, local: f64) -> f64 {
+        let guess = local - self.utc_offset(local);
+        local - self.utc_offset(guess)
+    }
+
+    /// Format a grid mark as a timestamp in the time zone of the axis.
+    ///
+    /// The precision follows [`GridMark::step_size`]: marks that are a year
+    /// apart print as `2024`, days as `2024-03-15`, minutes as `13:37`, and
+    /// so on.
+    ///
+    /// This also works for the hover readout, with a
+    /// [`crate::Plot::label_formatter`] and a `step_size` of the desired
+    /// precision, e.g. `1.0` for seconds.
+    pub fn format(&self, mark: GridMark) -> String {
+        let step = mark.step_size;
+        let seconds = mark.value + self.utc_offset(mark.value);
+        let days = (seconds / DAY).floor() as i64;
+        let (year, month, day) = civil_from_days(days);
+
+        if step >= YEAR {
+            return format!("{year}");
+        }
+        if step >= MONTH {
+            return format!("{year}-{month:02}");
+        }
+
+        let time_of_day = seconds - days as f64 * DAY;
+        if step >= DAY || time_of_day == 0.0 {
+            return format!("{year}-{month:02}-{day:02}");
+        }
+
+        let hours = (time_of_day / HOUR).floor();
+        let minutes = ((time_of_day - hours * HOUR) / MINUTE).floor();
+        let secs = time_of_day - hours * HOUR - minutes * MINUTE;
+        if step >= MINUTE {
+            format!("{hours:02}:{minutes:02}")
+        } else if step >= 1.0 {
+            format!("{hours:02}:{minutes:02}:{:02}", secs.floor())
+        } else {
+            let decimals = (-step.log10()).ceil().max(1.0) as usize;
+            format!("{hours:02}:{minutes:02}:{secs:0width$.decimals$}", width = decimals + 3)
+        }
+    }
+}
+
+impl AxisTransform for TimeAxisTransform {
+    #[inline]
+    fn data_to_plot(&self, value: f64) -> f64 {
+        value
+    }
+
+    #[inline]
+    fn plot_to_data(&self, value: f64) -> f64 {
+        value
+    }
+
+    fn grid_marks(&self, input: GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+        if self.time_zone.is_none() {
+            return local_grid_marks(&input);
+        }
+
+        // Place the marks on local boundaries, then move them back to UTC.
+        let local_input = GridInput {
+            bounds: (min + self.utc_offset(min), max + self.utc_offset(max)),
+            base_step_size: input.base_step_size,
+        };
+        let mut marks: Vec<GridMark> = local_grid_marks(&local_input)
+            .into_iter()
+            .map(|mark| GridMark {
+                value: self.local_to_utc(mark.value),
+                step_size: mark.step_size,
+            })
+            .filter(|mark| min <= mark.value && mark.value <= max)
+            .collect();
+        sort_and_dedup(&mut marks);
+        marks
+    }
+}
+
+/// Grid marks on the natural time boundaries within `input`, ignoring time
+/// zones.
+fn local_grid_marks(input: &GridInput) -> Vec<GridMark> {
+    // Below one second there are no natural boundaries left.
+    if input.base_step_size < 1.0 {
+        return log_grid_marks(input, 10.0);
+    }
+
+    let smallest = (0..)
+        .find(|&n| TimeStep::nth(n).approx_seconds() >= input.base_step_size)
+        .unwrap_or_default();
+
+    let mut marks: Vec<GridMark> = (smallest..smallest + 3)
+        .flat_map(|n| TimeStep::nth(n).marks(input.bounds))
+        .collect();
+    sort_and_dedup(&mut marks);
+    marks
+}
+
+/// Sort the marks, keeping only the coarsest where several coincide.
+fn sort_and_dedup(marks: &mut Vec<GridMark>) {
+    marks.sort_by(|a, b| a.value.total_cmp(&b.value).then(b.step_size.total_cmp(&a.step_size)));
+    marks.dedup_by(|next, kept| next.value == kept.value);
+}
+
+/// Days since the unix epoch to `(year, month, day)` in the proleptic
+/// Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html>.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// `(year, month, day)` in the proleptic Gregorian calendar to days since
+/// the unix epoch.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_round_trip() {
+        for days in [-800_000, -1, 0, 1, 59, 10_957, 19_782, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days, "{y}-{m}-{d}");
+        }
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn hourly_marks() {
+        let start = days_from_civil(2024, 3, 15) as f64 * DAY;
+        let marks = TimeAxisTransform::default().grid_marks(GridInput {
+            bounds: (start + 10.0 * MINUTE, start + 5.0 * HOUR),
+            base_step_size: 40.0 * MINUTE,
+        });
+
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+        let expected: Vec<f64> = (1..=5).map(|h| start + h as f64 * HOUR).collect();
+        assert_eq!(values, expected);
+        assert_eq!(marks[2].step_size, 3.0 * HOUR, "03:00 is also a 3-hour mark");
+        assert_eq!(TimeAxisTransform::default().format(marks[0]), "01:00");
+    }
+
+    #[test]
+    fn monthly_marks_follow_the_calendar() {
+        let start = days_from_civil(2023, 11, 20) as f64 * DAY;
+        let end = days_from_civil(2024, 3, 10) as f64 * DAY;
+        let marks = TimeAxisTransform::default().grid_marks(GridInput {
+            bounds: (start, end),
+            base_step_size: 20.0 * DAY,
+        });
+
+        let labels: Vec<String> = marks
+            .iter()
+            .map(|mark| TimeAxisTransform::default().format(*mark))
+            .collect();
+        assert_eq!(labels, vec!["2023-12", "2024-01", "2024-02", "2024-03"]);
+    }
+
+    #[test]
+    fn local_time() {
+        let time = TimeAxisTransform::new(UtcOffset::from_hours(-5.0));
+        let utc_midnight = days_from_civil(2024, 3, 15) as f64 * DAY;
+        let mark = GridMark {
+            value: utc_midnight,
+            step_size: HOUR,
+        };
+        assert_eq!(time.format(mark), "19:00");
+
+        let marks = time.grid_marks(GridInput {
+            bounds: (utc_midnight, utc_midnight + 3.0 * DAY),
+            base_step_size: DAY,
+        });
+        assert_eq!(marks[0].value, utc_midnight + 5.0 * HOUR, "local midnight");
+        assert_eq!(time.format(marks[0]), "2024-03-15");
+    }
+
+    #[test]
+    fn daylight_saving_transition() {
+        // 2024-03-31 01:00 UTC, when central Europe moves from UTC+1 to UTC+2.
+        let transition = days_from_civil(2024, 3, 31) as f64 * DAY + HOUR;
+        let time = TimeAxisTransform::new(move |timestamp: f64| if timestamp < transition { HOUR } else { 2.0 * HOUR });
+
+        let marks = time.grid_marks(GridInput {
+            bounds: (transition - 1.5 * DAY, transition + 0.5 * DAY),
+            base_step_size: 6.0 * HOUR,
+        });
+        let labels: Vec<String> = marks.iter().map(|mark| time.format(*mark)).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "18:00",
+                "2024-03-30",
+                "06:00",
+                "12:00",
+                "18:00",
+                "2024-03-31",
+                "06:00",
+                "12:00"
+            ]
+        );
+
+        // The night of the transition is an hour shorter.
+        assert_eq!(marks[2].value - marks[1].value, 6.0 * HOUR);
+        assert_eq!(marks[6].value - marks[5].value, 5.0 * HOUR);
+    }
+}
@@ -10,6 +10,7 @@ use emath::Vec2b;
 ///
 /// Uses f64 for improved accuracy to enable plotting
 /// large values (e.g. unix time on x axis).
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PlotPoint {
     /// This is often something monotonically increasing, such as time, but
@@ -302,6 +303,65 @@ impl PlotBounds {
     }
 }
 
+/// Limits on the plot bounds, see [`crate::Plot::bounds_limits`] and
+/// [`crate::Plot::span_limits`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct BoundsLimits {
+    /// The area the bounds have to stay within.
+    pub extent: PlotBounds,
+
+    /// The smallest width and height of the bounds.
+    pub min_span: [f64; 2],
+
+    /// The largest width and height of the bounds.
+    pub max_span: [f64; 2],
+}
+
+impl Default for BoundsLimits {
+    fn default() -> Self {
+        Self {
+            extent: PlotBounds::from_min_max([f64::NEG_INFINITY; 2], [f64::INFINITY; 2]),
+            min_span: [0.0; 2],
+            max_span: [f64::INFINITY; 2],
+        }
+    }
+}
+
+impl BoundsLimits {
+    /// Resize `bounds` around their center and move them as little as
+    /// needed to fit the limits. Bounds within the limits are left as they
+    /// are.
+    pub fn apply(&self, bounds: &mut PlotBounds) {
+        for d in 0..2 {
+            let (min, max) = (bounds.min[d], bounds.max[d]);
+            if !(min.is_finite() && max.is_finite()) {
+                continue;
+            }
+            let (lower, upper) = (self.extent.min[d], self.extent.max[d]);
+
+            // The extent wins over the smallest span.
+            let span = max - min;
+            let limited_span = span.max(self.min_span[d]).min(self.max_span[d]).min(upper - lower);
+            let (mut new_min, mut new_max) = if limited_span == span {
+                (min, max)
+            } else {
+                let center = 0.5 * (min + max);
+                (center - 0.5 * limited_span, center + 0.5 * limited_span)
+            };
+
+            if new_min < lower {
+                new_max += lower - new_min;
+                new_min = lower;
+            } else if new_max > upper {
+                new_min -= new_max - upper;
+                new_max = upper;
+            }
+            bounds.min[d] = new_min;
+            bounds.max[d] = new_max;
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct LinkedBounds {
     pub bounds: PlotBounds,
@@ -320,4 +380,38 @@ pub enum BoundsModification {
     Translate(Vec2),
     AutoBounds(Vec2b),
     Zoom(Vec2, PlotPoint),
+    UndoView,
+    RedoView,
+    GoLive,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_resize_and_move_bounds() {
+        let limits = BoundsLimits {
+            extent: PlotBounds::from_min_max([0.0, f64::NEG_INFINITY], [10.0, f64::INFINITY]),
+            min_span: [1.0, 1e-6],
+            max_span: [f64::INFINITY, 100.0],
+        };
+
+        let mut inside = PlotBounds::from_min_max([2.0, -3.0], [4.0, 5.0]);
+        limits.apply(&mut inside);
+        assert_eq!(inside, PlotBounds::from_min_max([2.0, -3.0], [4.0, 5.0]));
+
+        // Panned past the left edge, zoomed out too far in y.
+        let mut outside = PlotBounds::from_min_max([-3.0, -200.0], [2.0, 200.0]);
+        limits.apply(&mut outside);
+        assert_eq!(outside, PlotBounds::from_min_max([0.0, -50.0], [5.0, 50.0]));
+
+        // Zoomed in too far in x, zoomed out past the extent.
+        let mut tiny = PlotBounds::from_min_max([9.5, 0.0], [10.0, 1.0]);
+        limits.apply(&mut tiny);
+        assert_eq!(tiny.range_x(), 9.0..=10.0);
+        let mut huge = PlotBounds::from_min_max([-50.0, 0.0], [50.0, 1.0]);
+        limits.apply(&mut huge);
+        assert_eq!(huge.range_x(), 0.0..=10.0);
+    }
 }
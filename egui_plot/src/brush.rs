@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+
+use egui::Color32;
+use egui::Context;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+
+use crate::axis::PlotTransform;
+use crate::data::PointsView;
+
+/// A group of plots that share brushed points, see
+/// [`crate::Plot::brush_group`].
+///
+/// Selecting points with [`crate::Plot::box_select`] or
+/// [`crate::Plot::lasso_select`] in one plot of the group brushes their
+/// indices, and the points at those indices are highlighted in all items
+/// of all plots of the group, e.g. to follow the same samples through
+/// several views of one dataset. Selecting nothing clears the brush.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BrushGroup(Id);
+
+impl BrushGroup {
+    /// The brush group with the given id.
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self(id.into())
+    }
+
+    /// The brushed indices.
+    pub fn brushed(self, ctx: &Context) -> BTreeSet<usize> {
+        ctx.data(|data| data.get_temp(self.0)).unwrap_or_default()
+    }
+
+    /// Brush the given indices instead of the brushed ones, e.g. to reflect
+    /// a selection made elsewhere in the app.
+    pub fn set_brushed(self, ctx: &Context, indices: impl IntoIterator<Item = usize>) {
+        let indices: BTreeSet<usize> = indices.into_iter().collect();
+        ctx.data_mut(|data| data.insert_temp(self.0, indices));
+    }
+
+    /// Brush nothing.
+    pub fn clear(self, ctx: &Context) {
+        ctx.data_mut(|data| data.remove::<BTreeSet<usize>>(self.0));
+    }
+}
+
+/// Highlight the `points` at `indices` with a ring in `color`.
+pub(crate) fn mark_points(
+    points: PointsView<'_>,
+    indices: &BTreeSet<usize>,
+    color: Color32,
+    transform: &PlotTransform,
+    shapes: &mut Vec<Shape>,
+) {
+    for &index in indices.range(..points.len()) {
+        let point = points.get(index);
+        if !(point.x.is_finite() && point.y.is_finite()) {
+            continue;
+        }
+        let pos = transform.position_from_point(&point);
+        shapes.push(Shape::circle_filled(pos, 6.0, color.gamma_multiply(0.3)));
+        shapes.push(Shape::circle_stroke(pos, 6.0, Stroke::new(1.5, color)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brushes_are_shared_through_the_context() {
+        let ctx = Context::default();
+        let group = BrushGroup::new("brush");
+        assert!(group.brushed(&ctx).is_empty(), "nothing is brushed at first");
+
+        group.set_brushed(&ctx, [3, 1, 3]);
+        assert_eq!(BrushGroup::new("brush").brushed(&ctx), BTreeSet::from([1, 3]));
+        assert!(BrushGroup::new("other").brushed(&ctx).is_empty(), "groups are separate");
+
+        group.clear(&ctx);
+        assert!(group.brushed(&ctx).is_empty(), "the brush was cleared");
+    }
+}
@@ -0,0 +1,18 @@
+use egui::Id;
+use egui::Ui;
+
+use crate::bounds::PlotPoint;
+
+/// What was right-clicked to open the context menu of a plot, see
+/// [`crate::Plot::context_menu`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextMenuTarget {
+    /// The item under the pointer, or `None` for the plot background.
+    pub item: Option<Id>,
+
+    /// Where the plot was clicked, in plot coordinates.
+    pub value: PlotPoint,
+}
+
+pub(crate) type ContextMenuFn<'a> = Box<dyn Fn(&mut Ui, &ContextMenuTarget) + 'a>;
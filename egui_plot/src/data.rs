@@ -1,22 +1,33 @@
 use std::collections::Bound;
 use std::iter::FromIterator;
+use std::ops::Range;
 use std::ops::RangeBounds;
 use std::ops::RangeInclusive;
+use std::sync::Arc;
 
 use emath::lerp;
 
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
+use crate::parallel;
 
 /// Represents many [`PlotPoint`]s.
 ///
 /// These can be an owned `Vec`
 /// or generated on-the-fly by a function
-/// or borrowed from a slice.
+/// or borrowed from a slice
+/// or queried from a [`PlotDataSource`] for the visible range
+/// or stored as `f32`, owned or borrowed
+/// or shared with a [`crate::RetainedSeries`], with their bounds and
+/// generation, see [`crate::RetainedSeries::generation`].
 pub enum PlotPoints<'a> {
     Owned(Vec<PlotPoint>),
     Generator(ExplicitGenerator<'a>),
     Borrowed(&'a [PlotPoint]),
+    Source(SourceQuery<'a>),
+    OwnedF32(Vec<[f32; 2]>),
+    BorrowedF32(&'a [[f32; 2]]),
+    Shared(Arc<Vec<PlotPoint>>, PlotBounds, u64),
 }
 
 impl Default for PlotPoints<'_> {
@@ -45,6 +56,13 @@ impl<'a> From<&'a [PlotPoint]> for PlotPoints<'a> {
     }
 }
 
+impl<'a> From<&'a RollingPlotPoints> for PlotPoints<'a> {
+    #[inline]
+    fn from(points: &'a RollingPlotPoints) -> Self {
+        Self::Borrowed(points.points())
+    }
+}
+
 impl FromIterator<[f64; 2]> for PlotPoints<'_> {
     fn from_iter<T: IntoIterator<Item = [f64; 2]>>(iter: T) -> Self {
         Self::Owned(iter.into_iter().map(|point| point.into()).collect())
@@ -56,11 +74,40 @@ impl<'a> PlotPoints<'a> {
         Self::from_iter(points)
     }
 
+    /// The points as `f64`, empty for points stored as `f32`, which are
+    /// never converted as a whole, and before a generator or a data source
+    /// was queried. See [`crate::PlotGeometry`] for the points of an item.
     pub fn points(&self) -> &[PlotPoint] {
         match self {
             Self::Owned(points) => points.as_slice(),
-            Self::Generator(_) => &[],
+            Self::Generator(_) | Self::Source(_) | Self::OwnedF32(_) | Self::BorrowedF32(_) => &[],
             Self::Borrowed(points) => points,
+            Self::Shared(points, ..) => points.as_slice(),
+        }
+    }
+
+    /// Points stored as `f32`, which takes half the memory of [`PlotPoint`]s
+    /// for large series that don't need the precision, e.g. samples of a
+    /// sensor.
+    ///
+    /// The points are converted to `f64` one at a time while the plot is
+    /// drawn, by the items that support it, [`crate::Line`] and
+    /// [`crate::Points`]. The other items convert all of them each frame.
+    pub fn from_f32(points: Vec<[f32; 2]>) -> Self {
+        Self::OwnedF32(points)
+    }
+
+    /// Like [`Self::from_f32`], but borrowed from a slice.
+    pub fn from_f32_slice(points: &'a [[f32; 2]]) -> Self {
+        Self::BorrowedF32(points)
+    }
+
+    /// The points stored as `f32`, if they are.
+    fn f32_points(&self) -> Option<&[[f32; 2]]> {
+        match self {
+            Self::OwnedF32(points) => Some(points),
+            Self::BorrowedF32(points) => Some(points),
+            _ => None,
         }
     }
 
@@ -121,6 +168,16 @@ impl<'a> PlotPoints<'a> {
             .collect()
     }
 
+    /// Query the points in the visible x range from `source` each frame, at
+    /// most `max_points` of them, e.g. about twice the width of the plot in
+    /// pixels.
+    pub fn from_source(source: impl PlotDataSource + 'a, max_points: usize) -> Self {
+        Self::Source(SourceQuery {
+            source: Box::new(source),
+            max_points,
+        })
+    }
+
     /// From a series of y-values.
     /// The x-values will be the indices of these values
     pub fn from_ys_f32(ys: &[f32]) -> Self {
@@ -138,15 +195,58 @@ impl<'a> PlotPoints<'a> {
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Owned(points) => points.is_empty(),
-            Self::Generator(_) => false,
+            Self::Generator(_) | Self::Source(_) => false,
             Self::Borrowed(points) => points.is_empty(),
+            Self::OwnedF32(points) => points.is_empty(),
+            Self::BorrowedF32(points) => points.is_empty(),
+            Self::Shared(points, ..) => points.is_empty(),
         }
     }
 
+    /// The points, however they are stored, for reading them one at a time.
+    /// Empty before a generator or a data source was queried.
+    pub(crate) fn view(&self) -> PointsView<'_> {
+        match self.f32_points() {
+            Some(points) => PointsView::F32(points),
+            None => PointsView::F64(self.points()),
+        }
+    }
+
+    /// What tells these points apart from other versions of the series, for
+    /// the caches of large lines, without going through the points: shared
+    /// points by their generation, others by the `version` the caller gave
+    /// them. `None` if neither applies, and the caches have to be
+    /// built anew.
+    pub(crate) fn series_key(&self, version: Option<u64>) -> Option<SeriesKey> {
+        let (shared, version) = match self {
+            Self::Shared(_, _, generation) => (true, *generation),
+            _ => (false, version?),
+        };
+        Some(SeriesKey {
+            shared,
+            version,
+            len: self.view().len(),
+        })
+    }
+
     /// If initialized with a generator function, this will generate `n` evenly
-    /// spaced points in the given range.
+    /// spaced points in the given range. If initialized with a data source,
+    /// this will query its points in the given range. Points stored as `f32`
+    /// are converted to `f64`.
     pub fn generate_points(&mut self, x_range: RangeInclusive<f64>) {
-        if let Self::Generator(generator) = self {
+        if let Some(points) = self.f32_points() {
+            *self = Self::Owned(parallel::map(points, |&[x, y]| PlotPoint::new(x, y)));
+        } else {
+            self.query_points(x_range);
+        }
+    }
+
+    /// Like [`Self::generate_points`], but keeps points stored as `f32`, for
+    /// the items that read them through [`Self::view`].
+    pub(crate) fn query_points(&mut self, x_range: RangeInclusive<f64>) {
+        if let Self::Source(query) = self {
+            *self = Self::Owned(query.source.points_in(x_range, query.max_points));
+        } else if let Self::Generator(generator) = self {
             *self = Self::range_intersection(&x_range, &generator.x_range)
                 .map(|intersection| {
                     let increment = (intersection.end() - intersection.start()) / (generator.points - 1) as f64;
@@ -162,6 +262,25 @@ impl<'a> PlotPoints<'a> {
         }
     }
 
+    /// The bounds of the points, which are sorted by x: the x range is read
+    /// off the first and the last point, so only the y values are gone
+    /// through.
+    pub(crate) fn sorted_x_bounds(&self) -> PlotBounds {
+        if matches!(self, Self::Generator(_) | Self::Source(_) | Self::Shared(..)) {
+            return self.bounds();
+        }
+        let points = self.view();
+        let mut bounds = PlotBounds::NOTHING;
+        let mut xs = points.iter().map(|point| point.x).filter(|x| !x.is_nan());
+        for x in xs.next().into_iter().chain(xs.next_back()) {
+            bounds.extend_with_x(x);
+        }
+        for point in points.iter() {
+            bounds.extend_with_y(point.y);
+        }
+        bounds
+    }
+
     /// Returns the intersection of two ranges if they intersect.
     fn range_intersection(range1: &RangeInclusive<f64>, range2: &RangeInclusive<f64>) -> Option<RangeInclusive<f64>> {
         let start = range1.start().max(*range2.start());
@@ -179,6 +298,8 @@ impl<'a> PlotPoints<'a> {
                 bounds
             }
             Self::Generator(generator) => generator.estimate_bounds(),
+            Self::Source(query) => query.source.bounds(),
+            Self::Shared(_, bounds, _) => *bounds,
             Self::Borrowed(points) => {
                 let mut bounds = PlotBounds::NOTHING;
                 for point in *points {
@@ -186,10 +307,144 @@ impl<'a> PlotPoints<'a> {
                 }
                 bounds
             }
+            Self::OwnedF32(_) | Self::BorrowedF32(_) => {
+                let mut bounds = PlotBounds::NOTHING;
+                for &[x, y] in self.f32_points().unwrap_or_default() {
+                    bounds.extend_with(&PlotPoint::new(x, y));
+                }
+                bounds
+            }
+        }
+    }
+}
+
+/// Which version of a series some points are, to tell whether something
+/// cached for the series was computed from the same points. Within a
+/// version, points may only be appended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SeriesKey {
+    shared: bool,
+    version: u64,
+    len: usize,
+}
+
+impl SeriesKey {
+    /// The number of points.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether these are the points of `older`, possibly with more appended.
+    pub(crate) fn extends(&self, older: &Self) -> bool {
+        self.shared == older.shared && self.version == older.version && self.len >= older.len
+    }
+}
+
+/// The points of a [`PlotPoints`], either as they are or stored as `f32`,
+/// which are converted to `f64` one at a time when read, so that they are
+/// never copied as a whole.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum PointsView<'a> {
+    F64(&'a [PlotPoint]),
+    F32(&'a [[f32; 2]]),
+}
+
+impl<'a> From<&'a [PlotPoint]> for PointsView<'a> {
+    #[inline]
+    fn from(points: &'a [PlotPoint]) -> Self {
+        Self::F64(points)
+    }
+}
+
+impl<'a> From<&'a Vec<PlotPoint>> for PointsView<'a> {
+    #[inline]
+    fn from(points: &'a Vec<PlotPoint>) -> Self {
+        Self::F64(points)
+    }
+}
+
+impl<'a> PointsView<'a> {
+    #[inline]
+    pub(crate) fn len(self) -> usize {
+        match self {
+            Self::F64(points) => points.len(),
+            Self::F32(points) => points.len(),
+        }
+    }
+
+    /// The point at `index`, which must be in bounds.
+    #[inline]
+    pub(crate) fn get(self, index: usize) -> PlotPoint {
+        match self {
+            Self::F64(points) => points[index],
+            Self::F32(points) => {
+                let [x, y] = points[index];
+                PlotPoint::new(x, y)
+            }
+        }
+    }
+
+    /// The points in `range`, which must be in bounds.
+    #[inline]
+    pub(crate) fn slice(self, range: Range<usize>) -> Self {
+        match self {
+            Self::F64(points) => Self::F64(&points[range]),
+            Self::F32(points) => Self::F32(&points[range]),
+        }
+    }
+
+    pub(crate) fn iter(self) -> impl DoubleEndedIterator<Item = PlotPoint> + ExactSizeIterator + 'a {
+        (0..self.len()).map(move |index| self.get(index))
+    }
+
+    /// The number of points from the start for which `pred` holds, for
+    /// points partitioned by it, by binary search.
+    pub(crate) fn partition_point(self, mut pred: impl FnMut(PlotPoint) -> bool) -> usize {
+        match self {
+            Self::F64(points) => points.partition_point(|point| pred(*point)),
+            Self::F32(points) => points.partition_point(|&[x, y]| pred(PlotPoint::new(x, y))),
+        }
+    }
+
+    /// `f` applied to each point, in order, see [`parallel::map`].
+    pub(crate) fn map<T: Send>(self, f: impl Fn(PlotPoint) -> T + Sync + Send) -> Vec<T> {
+        match self {
+            Self::F64(points) => parallel::map(points, |point| f(*point)),
+            Self::F32(points) => parallel::map(points, |&[x, y]| f(PlotPoint::new(x, y))),
         }
     }
 }
 
+/// Provides the points of a series lazily, by x range, e.g. from a database
+/// or a file too large to keep in memory, see [`PlotPoints::from_source`].
+pub trait PlotDataSource {
+    /// The points with x in `x_range` in order of x, and the nearest point
+    /// on either side so that lines still leave the view.
+    ///
+    /// Return at most about `max_points` points, decimated e.g. by keeping
+    /// the lowest and the highest point of each group of points.
+    fn points_in(&self, x_range: RangeInclusive<f64>, max_points: usize) -> Vec<PlotPoint>;
+
+    /// The bounds of all the points, for the automatic bounds of the plot.
+    fn bounds(&self) -> PlotBounds;
+}
+
+impl<T: PlotDataSource + ?Sized> PlotDataSource for &T {
+    fn points_in(&self, x_range: RangeInclusive<f64>, max_points: usize) -> Vec<PlotPoint> {
+        (**self).points_in(x_range, max_points)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        (**self).bounds()
+    }
+}
+
+/// A [`PlotDataSource`] and how many points to query from it.
+pub struct SourceQuery<'a> {
+    source: Box<dyn PlotDataSource + 'a>,
+    max_points: usize,
+}
+
 /// Describes a function y = f(x) with an optional range for x and a number of
 /// points.
 pub struct ExplicitGenerator<'a> {
@@ -240,3 +495,183 @@ impl ExplicitGenerator<'_> {
         bounds
     }
 }
+
+/// The last points of a stream, e.g. of live telemetry, in a ring buffer of
+/// fixed capacity.
+///
+/// Pushing a point takes constant time and evicts the oldest point when the
+/// buffer is full. Keep it in your app state and plot it by reference, e.g.
+/// `Line::new("signal", &rolling)`, which borrows the points instead of
+/// copying them every frame.
+///
+/// To always have the points as one slice, each point is stored twice, so
+/// the buffer takes twice the memory of its capacity.
+#[derive(Clone, Debug)]
+pub struct RollingPlotPoints {
+    capacity: usize,
+
+    /// Ring of `capacity` points, followed by a copy of it, so that the
+    /// points from the oldest on are contiguous.
+    buffer: Vec<PlotPoint>,
+
+    /// Where the oldest point is in the ring.
+    start: usize,
+
+    len: usize,
+}
+
+impl RollingPlotPoints {
+    /// Room for the last `capacity` points, at least one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            buffer: vec![PlotPoint::new(0.0, 0.0); 2 * capacity],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Append a point, evicting the oldest one when full.
+    #[inline]
+    pub fn push(&mut self, point: impl Into<PlotPoint>) {
+        let point = point.into();
+        let slot = (self.start + self.len) % self.capacity;
+        self.buffer[slot] = point;
+        self.buffer[slot + self.capacity] = point;
+        if self.len < self.capacity {
+            self.len += 1;
+        } else {
+            self.start = (self.start + 1) % self.capacity;
+        }
+    }
+
+    /// Remove all points.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// The points, from the oldest to the newest.
+    #[inline]
+    pub fn points(&self) -> &[PlotPoint] {
+        &self.buffer[self.start..self.start + self.len]
+    }
+
+    /// The number of points kept.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no point was pushed since creation or the last clear.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of points kept at most.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<P: Into<PlotPoint>> Extend<P> for RollingPlotPoints {
+    fn extend<T: IntoIterator<Item = P>>(&mut self, iter: T) {
+        for point in iter {
+            self.push(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolling_points_keep_the_newest_in_order() {
+        let mut rolling = RollingPlotPoints::new(3);
+        assert!(rolling.is_empty(), "nothing pushed yet");
+
+        rolling.extend([[0.0, 0.0], [1.0, 1.0]]);
+        assert_eq!(rolling.points(), [PlotPoint::new(0.0, 0.0), PlotPoint::new(1.0, 1.0)]);
+
+        rolling.extend((2..7).map(|x| [x as f64, x as f64]));
+        let xs: Vec<f64> = rolling.points().iter().map(|point| point.x).collect();
+        assert_eq!(xs, [4.0, 5.0, 6.0]);
+        assert_eq!(PlotPoints::from(&rolling).points().len(), 3);
+
+        rolling.clear();
+        assert!(rolling.points().is_empty(), "cleared");
+    }
+
+    #[test]
+    fn f32_points_are_converted_when_drawn() {
+        let mut points = PlotPoints::from_f32(vec![[0.5, -1.0], [2.0, 4.0]]);
+        assert_eq!(points.bounds(), PlotBounds::from_min_max([0.5, -1.0], [2.0, 4.0]));
+        assert!(!points.is_empty(), "has points");
+
+        // Read one at a time, without converting them all.
+        points.query_points(f64::NEG_INFINITY..=f64::INFINITY);
+        assert!(points.points().is_empty(), "still stored as f32");
+        let view = points.view();
+        assert_eq!(view.get(1), PlotPoint::new(2.0, 4.0));
+        assert_eq!(points.sorted_x_bounds(), points.bounds());
+
+        points.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+        assert_eq!(points.points(), [PlotPoint::new(0.5, -1.0), PlotPoint::new(2.0, 4.0)]);
+    }
+
+    #[test]
+    fn sorted_x_bounds_match_the_bounds() {
+        let points = PlotPoints::new(vec![
+            [-1.0, 3.0],
+            [f64::NAN, 9.0],
+            [0.5, -2.0],
+            [4.0, 1.0],
+            [f64::NAN, 0.0],
+        ]);
+        assert_eq!(points.sorted_x_bounds(), points.bounds());
+        assert_eq!(
+            points.sorted_x_bounds(),
+            PlotBounds::from_min_max([-1.0, -2.0], [4.0, 9.0])
+        );
+    }
+
+    struct Squares;
+
+    impl PlotDataSource for Squares {
+        fn points_in(&self, x_range: RangeInclusive<f64>, max_points: usize) -> Vec<PlotPoint> {
+            let (start, end) = (x_range.start().floor() as i64, x_range.end().ceil() as i64);
+            (start..=end)
+                .take(max_points)
+                .map(|x| PlotPoint::new(x as f64, (x * x) as f64))
+                .collect()
+        }
+
+        fn bounds(&self) -> PlotBounds {
+            PlotBounds::from_min_max([-100.0, 0.0], [100.0, 10_000.0])
+        }
+    }
+
+    #[test]
+    fn sources_are_queried_for_the_visible_range() {
+        let mut points = PlotPoints::from_source(&Squares, 3);
+        assert_eq!(points.bounds(), Squares.bounds());
+        assert!(
+            points.points().is_empty(),
+            "nothing is queried before the range is known"
+        );
+
+        points.generate_points(1.5..=9.0);
+        assert_eq!(
+            points.points(),
+            [
+                PlotPoint::new(1.0, 1.0),
+                PlotPoint::new(2.0, 4.0),
+                PlotPoint::new(3.0, 9.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use std::ops::Range;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use egui::Context;
+use egui::Id;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotPoint;
+use crate::data::PointsView;
+use crate::data::SeriesKey;
+
+/// How [`crate::Line::downsample`] picks the points to draw.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DownsampleMethod {
+    /// Largest-Triangle-Three-Buckets: split the points in view into buckets
+    /// and keep from each the point that spans the largest triangle with its
+    /// neighbors, which keeps the shape of the line with few points.
+    Lttb {
+        /// How many points to draw at most.
+        target_points: usize,
+    },
+
+    /// Split the x axis into columns one pixel wide and keep the lowest and
+    /// the highest point of each, so the line becomes a vertical envelope
+    /// of the data that keeps every spike, e.g. for dense time series.
+    ///
+    /// The columns are computed over the whole series once per zoom level and
+    /// version of the series, see [`crate::Line::version`], so panning
+    /// doesn't go through all points again. Without a version, they are
+    /// computed each frame.
+    MinMax,
+}
+
+impl DownsampleMethod {
+    /// The indices of the points in the `visible` range of `points` to draw,
+    /// in order.
+    ///
+    /// `id` is the id of the item, to cache the columns of
+    /// [`Self::MinMax`] under for the version `key` of the series.
+    pub(crate) fn indices(
+        self,
+        ctx: &Context,
+        id: Id,
+        points: PointsView<'_>,
+        key: Option<SeriesKey>,
+        visible: Range<usize>,
+        transform: &PlotTransform,
+    ) -> Vec<usize> {
+        if visible.is_empty() {
+            return Vec::new();
+        }
+        match self {
+            Self::Lttb { target_points } => lttb(points.slice(visible.clone()), target_points)
+                .into_iter()
+                .map(|index| visible.start + index)
+                .collect(),
+            Self::MinMax => {
+                // Round the width a little, so that the rounding errors of
+                // panning don't throw away the cached columns.
+                let column_width = ((transform.dvalue_dpos()[0].abs().log2() * 16.0).round() / 16.0).exp2();
+                let columns = cached_min_max(ctx, id.with("min_max"), points, key, column_width);
+                let columns = &columns.indices;
+                let from = columns.partition_point(|&index| index <= visible.start);
+                let to = columns.partition_point(|&index| index < visible.end - 1);
+
+                // Keep the points on either side of the view, so the line
+                // still leaves it.
+                let mut indices = Vec::with_capacity(to.saturating_sub(from) + 2);
+                indices.push(visible.start);
+                indices.extend_from_slice(columns.get(from..to).unwrap_or_default());
+                if visible.end - 1 > visible.start {
+                    indices.push(visible.end - 1);
+                }
+                indices
+            }
+        }
+    }
+}
+
+/// The min/max columns of `points`, from the cache under `id` if they were
+/// computed from the same version of the series at the same zoom level, or
+/// extended from it if points were appended since, e.g. to a
+/// [`crate::RetainedSeries`]. Computed anew every time if the points have no
+/// `key`.
+fn cached_min_max(
+    ctx: &Context,
+    id: Id,
+    points: PointsView<'_>,
+    key: Option<SeriesKey>,
+    column_width: f64,
+) -> Arc<MinMaxColumns> {
+    let Some(key) = key else {
+        return Arc::new(MinMaxColumns::new(points, column_width));
+    };
+
+    // Taken out of the cache, so that it isn't copied to be extended.
+    let cached = ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<Option<(SeriesKey, Arc<MinMaxColumns>)>>(id)
+            .take()
+    });
+    let columns = match cached {
+        Some((cached_key, mut columns)) if key.extends(&cached_key) && columns.width == column_width => {
+            if key.len() > cached_key.len() {
+                Arc::make_mut(&mut columns).extend(points, cached_key.len());
+            }
+            columns
+        }
+        _ => Arc::new(MinMaxColumns::new(points, column_width)),
+    };
+    ctx.data_mut(|data| data.insert_temp(id, Some((key, columns.clone()))));
+    columns
+}
+
+/// The indices of the lowest and the highest point in each column of
+/// `width` along x, in order. The columns are aligned to multiples of their
+/// width, so that they stay the same while panning.
+#[derive(Clone)]
+struct MinMaxColumns {
+    width: f64,
+    indices: Vec<usize>,
+
+    /// The last column, which points appended later may still fall in: its
+    /// number, the indices of its lowest and highest point, and how many of
+    /// the indices are its.
+    last: Option<(f64, usize, usize, usize)>,
+}
+
+impl MinMaxColumns {
+    fn new(points: PointsView<'_>, width: f64) -> Self {
+        let mut columns = Self {
+            width,
+            indices: Vec::new(),
+            last: None,
+        };
+        columns.extend(points, 0);
+        columns
+    }
+
+    /// Update the columns computed from the first `old_len` of `points` for
+    /// the points appended since, going on from the last column.
+    fn extend(&mut self, points: PointsView<'_>, old_len: usize) {
+        if !(self.width > 0.0 && self.width.is_finite()) {
+            self.indices.extend(old_len..points.len());
+            return;
+        }
+
+        // The current column, and the indices of its lowest and highest point.
+        let mut column = self.last.take().map(|(number, lowest, highest, len)| {
+            self.indices.truncate(self.indices.len() - len);
+            (number, lowest, highest)
+        });
+        let mut push_column = |lowest: usize, highest: usize| {
+            self.indices.push(lowest.min(highest));
+            if lowest != highest {
+                self.indices.push(lowest.max(highest));
+            }
+        };
+        for index in old_len..points.len() {
+            let point = points.get(index);
+            if !(point.x.is_finite() && point.y.is_finite()) {
+                continue;
+            }
+            let number = (point.x / self.width).floor();
+            match &mut column {
+                Some((current, lowest, highest)) if *current == number => {
+                    if point.y < points.get(*lowest).y {
+                        *lowest = index;
+                    }
+                    if point.y > points.get(*highest).y {
+                        *highest = index;
+                    }
+                }
+                _ => {
+                    if let Some((_, lowest, highest)) = column.replace((number, index, index)) {
+                        push_column(lowest, highest);
+                    }
+                }
+            }
+        }
+        if let Some((number, lowest, highest)) = column {
+            push_column(lowest, highest);
+            self.last = Some((number, lowest, highest, if lowest == highest { 1 } else { 2 }));
+        }
+    }
+}
+
+/// Like [`visible_range`], but by binary search, for points that are known
+/// to be sorted by x.
+pub(crate) fn sorted_visible_range(points: PointsView<'_>, x_range: &RangeInclusive<f64>) -> Range<usize> {
+    let first = points.partition_point(|point| point.x < *x_range.start());
+    let end = points.partition_point(|point| point.x <= *x_range.end());
+    if first == points.len() {
+        0..0
+    } else {
+        first.saturating_sub(1)..(end.max(first) + 1).min(points.len())
+    }
+}
+
+/// The range of `points`, sorted by x, that is within `x_range`, with one
+/// more point on each side so that the line still leaves the view.
+pub(crate) fn visible_range(points: PointsView<'_>, x_range: &RangeInclusive<f64>) -> Range<usize> {
+    let first = points.iter().position(|point| point.x >= *x_range.start());
+    let last = points.iter().rposition(|point| point.x <= *x_range.end());
+    match (first, last) {
+        (Some(first), Some(last)) if first <= last => first.saturating_sub(1)..(last + 2).min(points.len()),
+        // No point is in view, but the line may still cross it.
+        (Some(first), _) => first.saturating_sub(1)..(first + 1).min(points.len()),
+        _ => 0..0,
+    }
+}
+
+/// The indices of at most `target` points picked by
+/// Largest-Triangle-Three-Buckets, always including the first and the last.
+fn lttb(points: PointsView<'_>, target: usize) -> Vec<usize> {
+    let n = points.len();
+    let target = target.max(3);
+    if n <= target {
+        return (0..n).collect();
+    }
+
+    // The first and last points are kept on their own, the rest is split
+    // into `target - 2` buckets.
+    let bucket_size = (n - 2) as f64 / (target - 2) as f64;
+    let bucket_start = |bucket: usize| ((bucket as f64 * bucket_size) as usize + 1).min(n - 1);
+
+    let mut kept = Vec::with_capacity(target);
+    kept.push(0);
+    let mut previous = points.get(0);
+    for bucket in 0..target - 2 {
+        let range = bucket_start(bucket)..bucket_start(bucket + 1);
+        // The next bucket, or the last point after the last bucket.
+        let next = if bucket + 1 == target - 2 {
+            points.slice(n - 1..n)
+        } else {
+            points.slice(bucket_start(bucket + 1)..bucket_start(bucket + 2))
+        };
+        let count = next.len() as f64;
+        let average = PlotPoint::new(
+            next.iter().map(|p| p.x).sum::<f64>() / count,
+            next.iter().map(|p| p.y).sum::<f64>() / count,
+        );
+
+        let area = |p: &PlotPoint| {
+            ((previous.x - average.x) * (p.y - previous.y) - (previous.x - p.x) * (average.y - previous.y)).abs()
+        };
+        let best = range
+            .max_by(|&a, &b| area(&points.get(a)).total_cmp(&area(&points.get(b))))
+            .unwrap_or_else(|| bucket_start(bucket));
+        kept.push(best);
+        previous = points.get(best);
+    }
+    kept.push(n - 1);
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::PlotPoints;
+
+    fn series(ys: &[f64]) -> Vec<PlotPoint> {
+        ys.iter()
+            .enumerate()
+            .map(|(x, y)| PlotPoint::new(x as f64, *y))
+            .collect()
+    }
+
+    #[test]
+    fn lttb_keeps_the_ends_and_spikes() {
+        let mut ys = vec![0.0; 100];
+        ys[37] = 10.0;
+        let points = series(&ys);
+
+        let kept = lttb(points[..].into(), 10);
+        assert_eq!(kept.len(), 10);
+        assert_eq!(kept.first(), Some(&0));
+        assert_eq!(kept.last(), Some(&99));
+        assert!(kept.contains(&37), "the spike should be kept: {kept:?}");
+        assert!(kept.windows(2).all(|w| w[0] < w[1]), "indices are in order");
+
+        let short = lttb(points[..5].into(), 10);
+        assert_eq!(short, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn min_max_keeps_the_envelope_of_each_column() {
+        let points = series(&[0.0, 3.0, -1.0, 1.0, 5.0, 2.0, 2.0, f64::NAN, 4.0]);
+        // Columns of two points: [0, 1], [2, 3], [4, 5], [6, 7], [8].
+        let columns = MinMaxColumns::new(points[..].into(), 2.0);
+        assert_eq!(columns.indices, [0, 1, 2, 3, 4, 5, 6, 8]);
+        // Columns of four points.
+        let columns = MinMaxColumns::new(points[..].into(), 4.0);
+        assert_eq!(columns.indices, [1, 2, 4, 5, 8]);
+
+        // Extending the columns gives the same as computing them anew.
+        for old_len in 0..points.len() {
+            let mut extended = MinMaxColumns::new(points[..old_len].into(), 4.0);
+            extended.extend(points[..].into(), old_len);
+            assert_eq!(extended.indices, columns.indices, "extended from {old_len} points");
+        }
+    }
+
+    #[test]
+    fn min_max_columns_are_cached_per_version() {
+        let ctx = Context::default();
+        let id = Id::new("line");
+        let mut points = PlotPoints::Owned(series(&[0.0, 3.0, -1.0, 1.0]));
+        let columns = cached_min_max(&ctx, id, points.view(), points.series_key(Some(0)), 2.0);
+        assert_eq!(columns.indices, [0, 1, 2, 3]);
+
+        let PlotPoints::Owned(owned) = &mut points else {
+            panic!("the points are owned");
+        };
+        owned[1].y = -3.0;
+        let kept = cached_min_max(&ctx, id, points.view(), points.series_key(Some(0)), 2.0);
+        assert!(Arc::ptr_eq(&kept, &columns), "columns are kept within a version");
+
+        let changed = cached_min_max(&ctx, id, points.view(), points.series_key(Some(1)), 2.0);
+        assert_eq!(changed.indices, [0, 1, 2, 3]);
+        assert!(
+            !Arc::ptr_eq(&changed, &columns),
+            "columns are computed for a new version"
+        );
+        let zoomed = cached_min_max(&ctx, id, points.view(), points.series_key(Some(1)), 4.0);
+        assert_eq!(zoomed.indices, [1, 3]);
+    }
+
+    #[test]
+    fn visible_range_keeps_a_point_on_each_side() {
+        let points = series(&[0.0; 10]);
+        assert_eq!(visible_range(points[..].into(), &(2.5..=5.5)), 2..7);
+        assert_eq!(visible_range(points[..].into(), &(-5.0..=20.0)), 0..10);
+        assert_eq!(visible_range(points[..].into(), &(4.2..=4.8)), 4..6);
+        assert_eq!(visible_range(points[..].into(), &(20.0..=30.0)), 0..0);
+
+        for range in [2.5..=5.5, -5.0..=20.0, 4.2..=4.8, 20.0..=30.0] {
+            assert_eq!(
+                sorted_visible_range(points[..].into(), &range),
+                visible_range(points[..].into(), &range),
+                "{range:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use egui::Id;
+
+use crate::bounds::PlotPoint;
+
+/// A data point being dragged, see [`crate::Points::draggable`].
+///
+/// The plot doesn't move the point itself: apply the new position to the
+/// data the item is built from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DraggedPoint {
+    /// The id of the item the point belongs to.
+    pub id: Id,
+
+    /// The index of the point in the item's data.
+    pub index: usize,
+
+    /// Where the point is dragged to, in the coordinates of the item's axes.
+    pub position: PlotPoint,
+}
+
+/// A line being dragged, see [`crate::HLine::draggable`] and
+/// [`crate::VLine::draggable`].
+///
+/// The plot doesn't move the line itself: build it at the new value next
+/// frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DraggedLine {
+    /// The id of the line.
+    pub id: Id,
+
+    /// The value the line is dragged to, snapped if the line snaps.
+    pub value: f64,
+}
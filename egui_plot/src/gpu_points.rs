@@ -0,0 +1,314 @@
+//! Drawing huge [`crate::Points`] items with OpenGL, see
+//! [`crate::Points::gpu`].
+//!
+//! The points are uploaded to a vertex buffer, relative to their first point
+//! and in `f32`, once for each version of the series, and drawn as instanced
+//! quads with the transform to the screen in uniforms, so panning and zooming
+//! don't upload them again.
+#![expect(unsafe_code, reason = "OpenGL is only reachable through unsafe calls")]
+
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+use egui::Color32;
+use egui::Id;
+use egui::PaintCallback;
+use egui::Shape;
+use egui::Ui;
+use egui::mutex::Mutex;
+use egui_glow::glow;
+use egui_glow::glow::HasContext as _;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotPoint;
+use crate::data::PointsView;
+use crate::data::SeriesKey;
+
+const VERTEX_SHADER: &str = r"
+in vec2 a_corner;
+in vec2 a_position;
+uniform vec2 u_scale;
+uniform vec2 u_offset;
+uniform vec2 u_radius;
+out vec2 v_corner;
+void main() {
+    v_corner = a_corner;
+    gl_Position = vec4(a_position * u_scale + u_offset + a_corner * u_radius, 0.0, 1.0);
+}
+";
+
+const FRAGMENT_SHADER: &str = r"
+precision mediump float;
+uniform vec4 u_color;
+uniform float u_radius_px;
+in vec2 v_corner;
+out vec4 out_color;
+void main() {
+    // Fade out over the outermost pixel, for a smooth edge.
+    float alpha = clamp((1.0 - length(v_corner)) * u_radius_px, 0.0, 1.0);
+    if (alpha <= 0.0) {
+        discard;
+    }
+    out_color = u_color * alpha;
+}
+";
+
+/// The points of a series relative to an origin, in `f32` for the GPU.
+#[derive(Clone)]
+struct Positions {
+    /// The points they were made from, if they can be told apart.
+    key: Option<SeriesKey>,
+    origin: PlotPoint,
+    values: Arc<Vec<[f32; 2]>>,
+}
+
+/// Everything but the points that is needed to draw them.
+#[derive(Clone, Copy)]
+struct Uniforms {
+    /// From the points relative to the origin to normalized device
+    /// coordinates.
+    scale: [f32; 2],
+    offset: [f32; 2],
+
+    /// The radius of the markers in normalized device coordinates, and in
+    /// physical pixels.
+    radius: [f32; 2],
+    radius_px: f32,
+
+    /// Premultiplied, in gamma space like the rest of egui.
+    color: [f32; 4],
+}
+
+/// The paint callback drawing `points` as filled circles of `radius`.
+///
+/// `id` is the id of the item, to keep its vertex buffer under, and `key`
+/// which version of the series the points are, to upload them only when
+/// they change.
+pub(crate) fn paint(
+    ui: &Ui,
+    id: Id,
+    points: PointsView<'_>,
+    key: Option<SeriesKey>,
+    transform: &PlotTransform,
+    color: Color32,
+    radius: f32,
+) -> Shape {
+    let positions = cached_positions(ui, id.with("gpu_positions"), points, key);
+    let frame = *transform.frame();
+    let (center, half) = (frame.center(), 0.5 * frame.size());
+    let origin = transform.position_from_point(&positions.origin);
+    let dpos_dvalue = transform.dpos_dvalue();
+    let uniforms = Uniforms {
+        scale: [
+            (dpos_dvalue[0] / half.x as f64) as f32,
+            (-dpos_dvalue[1] / half.y as f64) as f32,
+        ],
+        offset: [(origin.x - center.x) / half.x, (center.y - origin.y) / half.y],
+        radius: [radius / half.x, radius / half.y],
+        radius_px: radius * ui.pixels_per_point(),
+        color: color.to_normalized_gamma_f32(),
+    };
+
+    // One renderer for all plots, since it holds the shader program.
+    let renderer = ui.ctx().data_mut(|data| {
+        data.get_temp_mut_or_default::<Arc<Mutex<Renderer>>>(Id::new("egui_plot_gpu_points"))
+            .clone()
+    });
+    let pass = ui.ctx().cumulative_pass_nr();
+    let callback = egui_glow::CallbackFn::new(move |_info, painter| {
+        renderer.lock().paint(painter.gl(), pass, id, &positions, &uniforms);
+    });
+    Shape::Callback(PaintCallback {
+        rect: frame,
+        callback: Arc::new(callback),
+    })
+}
+
+/// The positions of `points`, from the cache under `id` if they were made
+/// from the same version of the series.
+fn cached_positions(ui: &Ui, id: Id, points: PointsView<'_>, key: Option<SeriesKey>) -> Positions {
+    if let Some(positions) = ui.ctx().data(|data| data.get_temp::<Positions>(id)) {
+        if key.is_some() && positions.key == key {
+            return positions;
+        }
+    }
+    let origin = points
+        .iter()
+        .find(|point| point.x.is_finite() && point.y.is_finite())
+        .unwrap_or_else(|| PlotPoint::new(0.0, 0.0));
+    let positions = Positions {
+        key,
+        origin,
+        values: Arc::new(points.map(|point| [(point.x - origin.x) as f32, (point.y - origin.y) as f32])),
+    };
+    ui.ctx().data_mut(|data| data.insert_temp(id, positions.clone()));
+    positions
+}
+
+/// The GL objects shared by all items, and the vertex buffers of the items.
+#[derive(Default)]
+struct Renderer {
+    /// Created on first use, or why they couldn't be.
+    objects: Option<Result<GlObjects, String>>,
+
+    buffers: ahash::HashMap<Id, VertexBuffer>,
+}
+
+struct GlObjects {
+    program: glow::Program,
+    vertex_array: glow::VertexArray,
+}
+
+struct VertexBuffer {
+    /// The points uploaded to the buffer, if they can be told apart.
+    key: Option<SeriesKey>,
+    buffer: glow::Buffer,
+    len: i32,
+
+    /// The last pass the buffer was drawn in.
+    pass: u64,
+}
+
+impl Renderer {
+    fn paint(&mut self, gl: &glow::Context, pass: u64, id: Id, positions: &Positions, uniforms: &Uniforms) {
+        let Ok(objects) = self.objects.get_or_insert_with(|| GlObjects::new(gl)) else {
+            return;
+        };
+
+        // Upload the points only if they changed.
+        let buffer = match self.buffers.entry(id) {
+            Entry::Occupied(entry) => {
+                let buffer = entry.into_mut();
+                if buffer.key.is_none() || buffer.key != positions.key {
+                    upload(gl, buffer.buffer, &positions.values);
+                    buffer.key = positions.key;
+                    buffer.len = positions.values.len() as i32;
+                }
+                buffer
+            }
+            Entry::Vacant(entry) => {
+                // SAFETY: called from the paint callback, with the context
+                // current on this thread.
+                let Ok(new_buffer) = (unsafe { gl.create_buffer() }) else {
+                    return;
+                };
+                upload(gl, new_buffer, &positions.values);
+                entry.insert(VertexBuffer {
+                    key: positions.key,
+                    buffer: new_buffer,
+                    len: positions.values.len() as i32,
+                    pass,
+                })
+            }
+        };
+        buffer.pass = pass;
+
+        // SAFETY: the program, vertex array and buffer were created with this
+        // context, and the instance count is the number of points uploaded to
+        // the buffer, so the draw reads only within it.
+        unsafe {
+            gl.use_program(Some(objects.program));
+            let location = |name| gl.get_uniform_location(objects.program, name);
+            gl.uniform_2_f32(location("u_scale").as_ref(), uniforms.scale[0], uniforms.scale[1]);
+            gl.uniform_2_f32(location("u_offset").as_ref(), uniforms.offset[0], uniforms.offset[1]);
+            gl.uniform_2_f32(location("u_radius").as_ref(), uniforms.radius[0], uniforms.radius[1]);
+            gl.uniform_1_f32(location("u_radius_px").as_ref(), uniforms.radius_px);
+            let [r, g, b, a] = uniforms.color;
+            gl.uniform_4_f32(location("u_color").as_ref(), r, g, b, a);
+
+            gl.bind_vertex_array(Some(objects.vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer.buffer));
+            gl.enable_vertex_attrib_array(1);
+            gl.vertex_attrib_pointer_f32(1, 2, glow::FLOAT, false, 0, 0);
+            gl.vertex_attrib_divisor(1, 1);
+
+            // Premultiplied alpha, like egui.
+            gl.enable(glow::BLEND);
+            gl.blend_func_separate(
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+                glow::ONE_MINUS_DST_ALPHA,
+                glow::ONE,
+            );
+            gl.draw_arrays_instanced(glow::TRIANGLE_STRIP, 0, 4, buffer.len);
+
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.use_program(None);
+        }
+
+        // Free the buffers of the items that are gone.
+        self.buffers.retain(|_, buffer| {
+            let drawn = pass.saturating_sub(buffer.pass) <= 1;
+            if !drawn {
+                // SAFETY: the buffer was created with this context, and is
+                // removed from the map so it isn't used again.
+                unsafe { gl.delete_buffer(buffer.buffer) };
+            }
+            drawn
+        });
+    }
+}
+
+impl GlObjects {
+    fn new(gl: &glow::Context) -> Result<Self, String> {
+        let version = egui_glow::ShaderVersion::get(gl);
+        if !version.is_new_shader_interface() {
+            return Err("instanced drawing needs GLSL 1.30 or GLSL ES 3.00".to_owned());
+        }
+
+        // SAFETY: called from the paint callback, with the context current
+        // on this thread, and only with objects created with it.
+        unsafe {
+            let program = gl.create_program()?;
+            let mut shaders = Vec::new();
+            for (kind, source) in [
+                (glow::VERTEX_SHADER, VERTEX_SHADER),
+                (glow::FRAGMENT_SHADER, FRAGMENT_SHADER),
+            ] {
+                let shader = gl.create_shader(kind)?;
+                gl.shader_source(shader, &format!("{}\n{source}", version.version_declaration()));
+                gl.compile_shader(shader);
+                if !gl.get_shader_compile_status(shader) {
+                    return Err(gl.get_shader_info_log(shader));
+                }
+                gl.attach_shader(program, shader);
+                shaders.push(shader);
+            }
+            gl.bind_attrib_location(program, 0, "a_corner");
+            gl.bind_attrib_location(program, 1, "a_position");
+            gl.link_program(program);
+            for shader in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+            if !gl.get_program_link_status(program) {
+                return Err(gl.get_program_info_log(program));
+            }
+
+            // The corners of the quad drawn for each point.
+            let corners = gl.create_buffer()?;
+            upload(gl, corners, &[[-1.0, -1.0], [1.0, -1.0], [-1.0, 1.0], [1.0, 1.0]]);
+            let vertex_array = gl.create_vertex_array()?;
+            gl.bind_vertex_array(Some(vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(corners));
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, 0, 0);
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            Ok(Self { program, vertex_array })
+        }
+    }
+}
+
+/// Fill `buffer` with `values`.
+fn upload(gl: &glow::Context, buffer: glow::Buffer, values: &[[f32; 2]]) {
+    // SAFETY: the buffer was created with this context, and the data is
+    // copied from a slice that outlives the call.
+    unsafe {
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+        gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, bytemuck::cast_slice(values), glow::STATIC_DRAW);
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+    }
+}
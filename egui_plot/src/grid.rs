@@ -38,32 +38,113 @@ pub struct GridMark {
     pub step_size: f64,
 }
 
+/// A tick at an explicit position, with an optional label.
+///
+/// See [`crate::Plot::x_ticks`] and [`crate::Plot::y_ticks`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    /// X or Y value in data space.
+    pub value: f64,
+
+    /// The text shown instead of the formatted value.
+    pub label: Option<String>,
+}
+
+impl Tick {
+    /// A tick at `value`, labeled by the axis formatter.
+    #[inline]
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Label the tick with `label` instead of the formatted value.
+    #[inline]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl From<f64> for Tick {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S: Into<String>> From<(f64, S)> for Tick {
+    #[inline]
+    fn from((value, label): (f64, S)) -> Self {
+        Self::new(value).label(label)
+    }
+}
+
 /// Recursively splits the grid into `base` subdivisions (e.g. 100, 10, 1).
 ///
 /// The logarithmic base, expressing how many times each grid unit is
 /// subdivided. 10 is a typical value, others are possible though.
 pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
     let log_base = log_base as f64;
-    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
-        // handle degenerate cases
-        if input.base_step_size.abs() < f64::EPSILON {
-            return Vec::new();
-        }
+    Box::new(move |input: GridInput| -> Vec<GridMark> { log_grid_marks(&input, log_base) })
+}
 
-        // The distance between two of the thinnest grid lines is "rounded" up
-        // to the next-bigger power of base
-        let smallest_visible_unit = next_power(input.base_step_size, log_base);
+/// The marks generated by [`log_grid_spacer`], for callers that don't need a
+/// boxed [`GridSpacer`].
+pub(crate) fn log_grid_marks(input: &GridInput, log_base: f64) -> Vec<GridMark> {
+    let mut marks = Vec::new();
+    log_grid_marks_into(&mut marks, input, log_base);
+    marks
+}
 
-        let step_sizes = [
-            smallest_visible_unit,
-            smallest_visible_unit * log_base,
-            smallest_visible_unit * log_base * log_base,
-        ];
+/// Like [`log_grid_marks`], but reusing the allocation of `out`.
+pub(crate) fn log_grid_marks_into(out: &mut Vec<GridMark>, input: &GridInput, log_base: f64) {
+    out.clear();
+    // handle degenerate cases
+    if input.base_step_size.abs() < f64::EPSILON {
+        return;
+    }
 
-        generate_marks(step_sizes, input.bounds)
-    };
+    // The distance between two of the thinnest grid lines is "rounded" up
+    // to the next-bigger power of base
+    let smallest_visible_unit = next_power(input.base_step_size, log_base);
+
+    let step_sizes = [
+        smallest_visible_unit,
+        smallest_visible_unit * log_base,
+        smallest_visible_unit * log_base * log_base,
+    ];
+
+    generate_marks_into(out, step_sizes, input.bounds);
+}
+
+/// Evenly spaced marks at a "nice" step (1, 2 or 5 times a power of ten),
+/// such that about `count` of them fit in the bounds.
+///
+/// All marks get the same [`GridMark::step_size`].
+pub(crate) fn nice_grid_marks(bounds: (f64, f64), count: usize) -> Vec<GridMark> {
+    let mut marks = Vec::new();
+    nice_grid_marks_into(&mut marks, bounds, count);
+    marks
+}
+
+/// Like [`nice_grid_marks`], but reusing the allocation of `out`.
+pub(crate) fn nice_grid_marks_into(out: &mut Vec<GridMark>, bounds: (f64, f64), count: usize) {
+    out.clear();
+    let (min, max) = bounds;
+    let target = (max - min) / count.max(1) as f64;
+    if !target.is_finite() || target <= 0.0 {
+        return;
+    }
 
-    Box::new(step_sizes)
+    // The nice step closest to the target, on a logarithmic scale.
+    let power = 10_f64.powf(target.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .map(|multiple| multiple * power)
+        .into_iter()
+        .min_by(|a, b| (a / target).ln().abs().total_cmp(&(b / target).ln().abs()))
+        .unwrap_or(power);
+
+    generate_marks_into(out, [step; 3], bounds);
 }
 
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
@@ -93,45 +174,65 @@ pub fn uniform_grid_spacer<'a>(spacer: impl Fn(GridInput) -> [f64; 3] + 'a) -> G
 /// assert_eq!(next_power(0.02, 10.0), 0.1);
 /// assert_eq!(next_power(0.2,  10.0), 1);
 /// ```
-fn next_power(value: f64, base: f64) -> f64 {
+pub(crate) fn next_power(value: f64, base: f64) -> f64 {
     debug_assert_ne!(value, 0.0, "Bad input"); // can be negative (typical for Y axis)
     base.powi(value.abs().log(base).ceil() as i32)
 }
 
 /// Fill in all values between [min, max] which are a multiple of `step_size`
-fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
-    let mut steps = vec![];
-    fill_marks_between(&mut steps, step_sizes[0], bounds);
-    fill_marks_between(&mut steps, step_sizes[1], bounds);
-    fill_marks_between(&mut steps, step_sizes[2], bounds);
+pub(crate) fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
+    let mut marks = Vec::new();
+    generate_marks_into(&mut marks, step_sizes, bounds);
+    marks
+}
 
-    // Remove duplicates:
-    // This can happen because we have overlapping steps, e.g.:
-    // step_size[0] =   10  =>  [-10, 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100,
-    // 110, 120] step_size[1] =  100  =>  [     0,
-    // 100          ] step_size[2] = 1000  =>  [     0
-    // ]
+/// Like [`generate_marks`], but reusing the allocation of `out`.
+///
+/// The multiples of the three step sizes are merged in order as they are
+/// generated, so no sorting is needed.
+pub(crate) fn generate_marks_into(out: &mut Vec<GridMark>, step_sizes: [f64; 3], (min, max): (f64, f64)) {
+    debug_assert!(min <= max, "Bad plot bounds: min: {min}, max: {max}");
+    out.clear();
 
-    steps.sort_by(|a, b| cmp_f64(a.value, b.value));
+    // For each step size, the multiples left to fill in.
+    let mut next = step_sizes.map(|step_size| (min / step_size).ceil() as i64);
+    let mut end = step_sizes.map(|step_size| (max / step_size).ceil() as i64);
+    for (step_size, end) in step_sizes.iter().zip(&mut end) {
+        if !(*step_size > 0.0 && step_size.is_finite()) {
+            *end = i64::MIN;
+        }
+    }
 
     let min_step = step_sizes.iter().fold(f64::INFINITY, |a, &b| a.min(b));
     let eps = 0.1 * min_step; // avoid putting two ticks too closely together
 
-    let mut deduplicated: Vec<GridMark> = Vec::with_capacity(steps.len());
-    for step in steps {
-        if let Some(last) = deduplicated.last_mut() {
-            if (last.value - step.value).abs() < eps {
+    // Take the smallest of the next multiples, the first step size on ties.
+    while let Some(i) = (0..3)
+        .filter(|&i| next[i] < end[i])
+        .min_by(|&a, &b| cmp_f64(next[a] as f64 * step_sizes[a], next[b] as f64 * step_sizes[b]))
+    {
+        let mark = GridMark {
+            value: next[i] as f64 * step_sizes[i],
+            step_size: step_sizes[i],
+        };
+        next[i] += 1;
+
+        // Remove duplicates:
+        // This can happen because we have overlapping steps, e.g.:
+        // step_size[0] =   10  =>  [-10, 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]
+        // step_size[1] =  100  =>  [     0,                                     100]
+        // step_size[2] = 1000  =>  [     0                                         ]
+        if let Some(last) = out.last_mut() {
+            if (last.value - mark.value).abs() < eps {
                 // Keep the one with the largest step size
-                if last.step_size < step.step_size {
-                    *last = step;
+                if last.step_size < mark.step_size {
+                    *last = mark;
                 }
                 continue;
             }
         }
-        deduplicated.push(step);
+        out.push(mark);
     }
-
-    deduplicated
 }
 
 #[test]
@@ -184,22 +285,51 @@ fn test_generate_marks() {
     }
 }
 
+#[test]
+fn test_nice_grid_marks() {
+    let values = |bounds, count| -> Vec<f64> {
+        nice_grid_marks(bounds, count)
+            .into_iter()
+            .map(|mark| mark.value)
+            .collect()
+    };
+    assert_eq!(values((0.0, 9.0), 5), vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+    assert_eq!(values((0.0, 9.0), 2), vec![0.0, 5.0]);
+    assert_eq!(values((-0.3, 0.35), 3), vec![-0.2, 0.0, 0.2]);
+}
+
+#[test]
+fn test_generate_marks_reuses_the_buffer() {
+    // The second call fills the buffer grown by the first one.
+    let mut marks = Vec::new();
+    generate_marks_into(&mut marks, [25.0, 100.0, 1.0], (-10.0, 120.0));
+    let capacity = marks.capacity();
+    generate_marks_into(&mut marks, [25.0, 100.0, 1.0], (-10.0, 120.0));
+    assert_eq!(marks.capacity(), capacity, "no reallocation");
+    assert!(marks.windows(2).all(|w| w[0].value < w[1].value), "marks are in order");
+    assert_eq!(
+        marks[10],
+        GridMark {
+            value: 0.0,
+            step_size: 100.0
+        }
+    );
+    assert_eq!(
+        marks[35],
+        GridMark {
+            value: 25.0,
+            step_size: 25.0
+        }
+    );
+    assert_eq!(marks.len(), 130);
+
+    generate_marks_into(&mut marks, [0.0, -1.0, f64::NAN], (-10.0, 120.0));
+    assert!(marks.is_empty(), "no marks for invalid step sizes");
+}
+
 fn cmp_f64(a: f64, b: f64) -> Ordering {
     match a.partial_cmp(&b) {
         Some(ord) => ord,
         None => a.is_nan().cmp(&b.is_nan()),
     }
 }
-
-/// Fill in all values between [min, max] which are a multiple of `step_size`
-fn fill_marks_between(out: &mut Vec<GridMark>, step_size: f64, (min, max): (f64, f64)) {
-    debug_assert!(min <= max, "Bad plot bounds: min: {min}, max: {max}");
-    let first = (min / step_size).ceil() as i64;
-    let last = (max / step_size).ceil() as i64;
-
-    let marks_iter = (first..last).map(|i| {
-        let value = (i as f64) * step_size;
-        GridMark { value, step_size }
-    });
-    out.extend(marks_iter);
-}
@@ -0,0 +1,152 @@
+use egui::Id;
+use egui::Response;
+use egui::Shape;
+use egui::Stroke;
+use emath::Pos2;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotPoint;
+use crate::cursor::Cursor;
+use crate::data::PointsView;
+use crate::items;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotItem;
+
+/// How the distance from the pointer to a data point is measured when
+/// snapping to it, see [`crate::Plot::hover_snap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SnapDistance {
+    /// The straight distance on screen.
+    #[default]
+    Euclidean,
+
+    /// Only the horizontal distance on screen, e.g. to read off the series
+    /// of a time plot at the time under the pointer.
+    XOnly,
+}
+
+impl SnapDistance {
+    /// The squared distance between two screen positions.
+    fn distance_sq(self, a: Pos2, b: Pos2) -> f32 {
+        match self {
+            Self::Euclidean => a.distance_sq(b),
+            Self::XOnly => (a.x - b.x).powi(2),
+        }
+    }
+}
+
+/// An element of a plot item under the pointer, e.g. a point or a bar, see
+/// [`crate::PlotResponse::hovered_element`] and
+/// [`crate::PlotResponse::clicked_item`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotItemHit {
+    /// The id of the item.
+    pub id: Id,
+
+    /// The name of the item.
+    pub name: String,
+
+    /// The index of the element in the item, e.g. of the point in a series
+    /// or of the bar in a bar chart.
+    pub index: usize,
+
+    /// The value of the point hit, in the coordinates of the item's axes.
+    /// For items other than series of points, this is where the pointer was.
+    pub value: PlotPoint,
+}
+
+/// The point nearest to `pointer`, ignoring points that aren't finite.
+pub(crate) fn nearest_point(
+    points: PointsView<'_>,
+    pointer: Pos2,
+    transform: &PlotTransform,
+    distance: SnapDistance,
+) -> Option<ClosestElem> {
+    points
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| value.x.is_finite() && value.y.is_finite())
+        .map(|(index, value)| ClosestElem {
+            index,
+            dist_sq: distance.distance_sq(pointer, transform.position_from_point(&value)),
+        })
+        .min_by(|a, b| a.dist_sq.total_cmp(&b.dist_sq))
+}
+
+/// The value of the series through `points` at `x`, interpolated between
+/// the first pair of neighboring points that spans `x`.
+pub(crate) fn y_at_x(points: PointsView<'_>, x: f64) -> Option<f64> {
+    points.iter().zip(points.iter().skip(1)).find_map(|(a, b)| {
+        if !(a.x.min(b.x)..=a.x.max(b.x)).contains(&x) {
+            return None;
+        }
+        let y = if a.x == b.x {
+            a.y
+        } else {
+            a.y + (x - a.x) / (b.x - a.x) * (b.y - a.y)
+        };
+        y.is_finite().then_some(y)
+    })
+}
+
+/// Highlight the point snapped to, put the rulers through it, and show
+/// `label` in a tooltip, e.g. the name of its series and its values.
+pub(crate) fn show_snapped_point(
+    plot_area_response: &Response,
+    item: &dyn PlotItem,
+    value: PlotPoint,
+    shapes: &mut Vec<Shape>,
+    cursors: &mut Vec<Cursor>,
+    plot: &PlotConfig<'_>,
+    label: String,
+) {
+    let pos = plot.transform.position_from_point(&value);
+    let color = item.color();
+    shapes.push(Shape::circle_filled(pos, 3.5, color));
+    shapes.push(Shape::circle_stroke(pos, 6.0, Stroke::new(1.5, color)));
+
+    items::rulers_and_tooltip_at_value(plot_area_response, value, "", plot, cursors, &None);
+    items::show_tooltip(plot_area_response, label);
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::Rect;
+    use emath::pos2;
+
+    use super::*;
+    use crate::bounds::PlotBounds;
+
+    #[test]
+    fn x_only_snapping_ignores_the_height() {
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]), false);
+        let points = [
+            PlotPoint::new(2.0, 5.0),
+            PlotPoint::new(f64::NAN, 0.0),
+            PlotPoint::new(4.0, 0.0),
+        ];
+
+        // The pointer is at (3.5, 5.0): nearest on screen is the first point,
+        // nearest in x the last.
+        let pointer = transform.position_from_point(&PlotPoint::new(3.5, 5.0));
+        let euclidean = nearest_point(points[..].into(), pointer, &transform, SnapDistance::Euclidean);
+        let x_only = nearest_point(points[..].into(), pointer, &transform, SnapDistance::XOnly);
+        assert_eq!(euclidean.map(|e| e.index), Some(0));
+        assert_eq!(x_only.map(|e| e.index), Some(2));
+    }
+
+    #[test]
+    fn series_are_interpolated_at_x() {
+        let points = [
+            PlotPoint::new(0.0, 0.0),
+            PlotPoint::new(2.0, 4.0),
+            PlotPoint::new(3.0, 1.0),
+        ];
+        assert_eq!(y_at_x(points[..].into(), 1.0), Some(2.0));
+        assert_eq!(y_at_x(points[..].into(), 2.5), Some(2.5));
+        assert_eq!(y_at_x(points[..].into(), 3.0), Some(1.0));
+        assert_eq!(y_at_x(points[..].into(), 4.0), None);
+    }
+}
@@ -0,0 +1,234 @@
+use egui::Modifiers;
+use egui::PointerButton;
+use emath::Vec2;
+
+/// Keyboard navigation of a plot, see [`crate::Plot::input_config`].
+///
+/// While the plot has keyboard focus, e.g. after clicking it, the arrow keys
+/// pan, `+` and `-` zoom in and out, `PageUp` and `PageDown` zoom in and out
+/// in bigger steps, and `Home` resets the view like a double-click.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlotInputConfig {
+    pub(crate) keyboard: bool,
+    pub(crate) pan_step: f32,
+    pub(crate) zoom_step: f32,
+    pub(crate) page_zoom_step: f32,
+}
+
+impl Default for PlotInputConfig {
+    fn default() -> Self {
+        Self {
+            keyboard: true,
+            pan_step: 0.1,
+            zoom_step: 1.25,
+            page_zoom_step: 2.0,
+        }
+    }
+}
+
+impl PlotInputConfig {
+    /// Whether the plot can be navigated with the keyboard. Default: `true`.
+    #[inline]
+    pub fn keyboard(mut self, on: bool) -> Self {
+        self.keyboard = on;
+        self
+    }
+
+    /// How far the arrow keys pan, as a fraction of the plot size.
+    /// Default: `0.1`.
+    #[inline]
+    pub fn pan_step(mut self, fraction: f32) -> Self {
+        self.pan_step = fraction;
+        self
+    }
+
+    /// By what factor `+` and `-` zoom in and out. Default: `1.25`.
+    #[inline]
+    pub fn zoom_step(mut self, factor: f32) -> Self {
+        self.zoom_step = factor;
+        self
+    }
+
+    /// By what factor `PageUp` and `PageDown` zoom in and out. Default: `2.0`.
+    #[inline]
+    pub fn page_zoom_step(mut self, factor: f32) -> Self {
+        self.page_zoom_step = factor;
+        self
+    }
+}
+
+/// What scrolling over a plot does, see
+/// [`PlotInteractionConfig::scroll_action`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollAction {
+    /// Pan the view. Zoom while holding ⌘/Ctrl.
+    #[default]
+    Pan,
+
+    /// Zoom around the pointer.
+    Zoom,
+}
+
+/// What stays in place when zooming along an axis by scrolling or
+/// pinching, see [`crate::Plot::zoom_anchor`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ZoomAnchor {
+    /// The value under the pointer.
+    #[default]
+    Pointer,
+
+    /// The value in the middle of the plot.
+    Center,
+
+    /// The given value, e.g. `0.0` for symmetric spectra. It stays where it
+    /// is on screen, even outside the plot.
+    Value(f64),
+}
+
+/// The pointer gestures of a plot, see [`crate::Plot::interaction_config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlotInteractionConfig {
+    pub(crate) pan_button: PointerButton,
+    pub(crate) box_zoom_button: PointerButton,
+    pub(crate) axis_lock_modifiers: Option<Modifiers>,
+    pub(crate) pan_only_modifiers: [Option<Modifiers>; 2],
+    pub(crate) snap_bypass_modifiers: Option<Modifiers>,
+    pub(crate) scroll_action: ScrollAction,
+    pub(crate) scroll_speed: f32,
+    pub(crate) zoom_speed: f32,
+}
+
+impl Default for PlotInteractionConfig {
+    fn default() -> Self {
+        Self {
+            pan_button: PointerButton::Primary,
+            box_zoom_button: PointerButton::Secondary,
+            axis_lock_modifiers: None,
+            pan_only_modifiers: [None, None],
+            snap_bypass_modifiers: Some(Modifiers::ALT),
+            scroll_action: ScrollAction::Pan,
+            scroll_speed: 1.0,
+            zoom_speed: 1.0,
+        }
+    }
+}
+
+impl PlotInteractionConfig {
+    /// The button to drag with to pan. Default: `PointerButton::Primary`.
+    #[inline]
+    pub fn pan_button(mut self, button: PointerButton) -> Self {
+        self.pan_button = button;
+        self
+    }
+
+    /// The button to drag with to zoom into a box. Default:
+    /// `PointerButton::Secondary`.
+    #[inline]
+    pub fn box_zoom_button(mut self, button: PointerButton) -> Self {
+        self.box_zoom_button = button;
+        self
+    }
+
+    /// While these modifiers are held, panning by dragging is locked to the
+    /// axis the drag has moved furthest along since it started.
+    ///
+    /// Default: `None`, panning is never locked.
+    #[inline]
+    pub fn axis_lock_modifiers(mut self, modifiers: impl Into<Option<Modifiers>>) -> Self {
+        self.axis_lock_modifiers = modifiers.into();
+        self
+    }
+
+    /// While these modifiers are held, panning by dragging moves only along
+    /// X, e.g. `Modifiers::SHIFT`. They can be pressed and released in the
+    /// middle of a drag, unlike [`crate::Plot::allow_drag`].
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn pan_x_only_modifiers(mut self, modifiers: impl Into<Option<Modifiers>>) -> Self {
+        self.pan_only_modifiers[0] = modifiers.into();
+        self
+    }
+
+    /// While these modifiers are held, panning by dragging moves only along
+    /// Y, e.g. `Modifiers::CTRL`, see [`Self::pan_x_only_modifiers`].
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn pan_y_only_modifiers(mut self, modifiers: impl Into<Option<Modifiers>>) -> Self {
+        self.pan_only_modifiers[1] = modifiers.into();
+        self
+    }
+
+    /// While these modifiers are held, dragged points and lines follow the
+    /// pointer without snapping, see [`crate::Plot::drag_snap`].
+    ///
+    /// Default: `Some(Modifiers::ALT)`.
+    #[inline]
+    pub fn snap_bypass_modifiers(mut self, modifiers: impl Into<Option<Modifiers>>) -> Self {
+        self.snap_bypass_modifiers = modifiers.into();
+        self
+    }
+
+    /// What scrolling does. Default: [`ScrollAction::Pan`].
+    #[inline]
+    pub fn scroll_action(mut self, action: ScrollAction) -> Self {
+        self.scroll_action = action;
+        self
+    }
+
+    /// How fast scrolling pans, relative to the distance scrolled.
+    /// Default: `1.0`.
+    #[inline]
+    pub fn scroll_speed(mut self, speed: f32) -> Self {
+        self.scroll_speed = speed;
+        self
+    }
+
+    /// How fast scrolling and pinching zoom, relative to the default zoom.
+    /// E.g. `2.0` zooms in twice as far for the same gesture. Default: `1.0`.
+    #[inline]
+    pub fn zoom_speed(mut self, speed: f32) -> Self {
+        self.zoom_speed = speed;
+        self
+    }
+}
+
+/// The zoom of a two-finger pinch, given how much the spread of the fingers
+/// changed along each axis and in distance.
+///
+/// A pinch whose spread changes `threshold` times as much along one axis as
+/// along the other zooms only that axis. Any other pinch zooms both axes
+/// alike, by the change of the distance between the fingers.
+pub(crate) fn pinch_zoom(zoom_delta_2d: Vec2, zoom_delta: f32, threshold: f32) -> Vec2 {
+    // Compare the changes on a log scale, so that pinching in and out count
+    // the same.
+    let x = zoom_delta_2d.x.ln().abs();
+    let y = zoom_delta_2d.y.ln().abs();
+    if x > threshold * y {
+        Vec2::new(zoom_delta_2d.x, 1.0)
+    } else if y > threshold * x {
+        Vec2::new(1.0, zoom_delta_2d.y)
+    } else {
+        Vec2::splat(zoom_delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::vec2;
+
+    use super::*;
+
+    #[test]
+    fn predominant_pinch_axis_is_zoomed_alone() {
+        assert_eq!(pinch_zoom(vec2(1.2, 1.05), 1.1, 2.0), vec2(1.2, 1.0));
+        assert_eq!(pinch_zoom(vec2(0.98, 0.8), 0.85, 2.0), vec2(1.0, 0.8));
+        assert_eq!(pinch_zoom(vec2(1.2, 1.15), 1.17, 2.0), Vec2::splat(1.17));
+        assert_eq!(pinch_zoom(Vec2::splat(1.0), 1.0, 2.0), Vec2::splat(1.0));
+    }
+}
@@ -0,0 +1,294 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use egui::WidgetText;
+use egui::epaint::TextShape;
+use emath::Pos2;
+use emath::Rect;
+use emath::Rot2;
+use emath::Vec2;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Padding between the text and its frame, in ui points.
+const PADDING: f32 = 3.0;
+
+impl Annotation {
+    /// Text pointing at `anchor`, with a leader line from the text to it.
+    pub fn new(name: impl Into<String>, anchor: impl Into<PlotPoint>, text: impl Into<WidgetText>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            text: text.into(),
+            anchor: anchor.into(),
+            offset: vec2(30.0, -30.0),
+            color: Color32::TRANSPARENT,
+            arrow: true,
+            frame: true,
+        }
+    }
+
+    /// Position of the center of the text relative to the anchor, in ui
+    /// points. Positive Y goes down. Default: `(30, -30)`, i.e. up and to the
+    /// right.
+    ///
+    /// The offset is flipped horizontally and/or vertically when the text
+    /// would otherwise stick out of the plot.
+    #[inline]
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Color of the text and the leader line. Default is
+    /// `Color32::TRANSPARENT` which means the text color of the ui.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// End the leader line with an arrow head at the anchor. Default: `true`.
+    #[inline]
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Draw the text on a framed background. Default: `true`.
+    #[inline]
+    pub fn frame(mut self, frame: bool) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+}
+
+/// A text box pointing at a position in the plot, with a leader line (and
+/// optionally an arrow) from the box to it.
+///
+/// Unlike [`crate::Text`], the text sits at a fixed screen offset from its
+/// anchor, so it keeps its distance at any zoom level.
+#[derive(Clone)]
+pub struct Annotation {
+    base: PlotItemBase,
+    pub(crate) text: WidgetText,
+    pub(crate) anchor: PlotPoint,
+    pub(crate) offset: Vec2,
+    pub(crate) color: Color32,
+    pub(crate) arrow: bool,
+    pub(crate) frame: bool,
+}
+
+impl PlotItem for Annotation {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
+        } else {
+            self.color
+        };
+        let galley =
+            self.text
+                .clone()
+                .into_galley(ui, Some(egui::TextWrapMode::Extend), f32::INFINITY, TextStyle::Small);
+
+        let anchor = transform.position_from_point(&self.anchor);
+        let box_size = galley.size() + Vec2::splat(2.0 * PADDING);
+        let text_box = place_box(anchor, self.offset, box_size, transform.frame());
+
+        let mut stroke = Stroke::new(1.0, color);
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+
+        // The leader line starts at the point of the box closest to the
+        // anchor, unless the anchor is inside the box.
+        let start = text_box.clamp(anchor);
+        let leader = anchor - start;
+        if leader.length() > 1.0 {
+            shapes.push(Shape::line_segment([start, anchor], stroke));
+            if self.arrow {
+                let dir = leader.normalized();
+                let tip_length = 6.0 * stroke.width.sqrt();
+                let rot = Rot2::from_angle(std::f32::consts::TAU / 12.0);
+                shapes.push(Shape::convex_polygon(
+                    vec![
+                        anchor,
+                        anchor - tip_length * (rot * dir),
+                        anchor - tip_length * (rot.inverse() * dir),
+                    ],
+                    color,
+                    Stroke::NONE,
+                ));
+            }
+        }
+
+        if self.frame {
+            shapes.push(Shape::rect_filled(text_box, 2.0, ui.visuals().extreme_bg_color));
+            shapes.push(Shape::rect_stroke(text_box, 2.0, stroke, egui::StrokeKind::Inside));
+        }
+        shapes.push(TextShape::new(text_box.min + Vec2::splat(PADDING), galley, color).into());
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.anchor);
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// The box of the given size centered at `anchor + offset`, with the offset
+/// flipped along each axis where that keeps the box inside `frame`.
+fn place_box(anchor: Pos2, offset: Vec2, size: Vec2, frame: &Rect) -> Rect {
+    let at = |offset: Vec2| Rect::from_center_size(anchor + offset, size);
+    let mut offset = offset;
+    let rect = at(offset);
+    if (rect.left() < frame.left() && offset.x < 0.0) || (rect.right() > frame.right() && offset.x > 0.0) {
+        offset.x = -offset.x;
+    }
+    if (rect.top() < frame.top() && offset.y < 0.0) || (rect.bottom() > frame.bottom() && offset.y > 0.0) {
+        offset.y = -offset.y;
+    }
+    at(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn box_flips_to_stay_inside() {
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let size = vec2(20.0, 10.0);
+
+        let inside = place_box(pos2(50.0, 50.0), vec2(20.0, -20.0), size, &frame);
+        assert_eq!(inside.center(), pos2(70.0, 30.0));
+
+        // Near the top right corner, the box goes down and to the left.
+        let flipped = place_box(pos2(90.0, 5.0), vec2(20.0, -20.0), size, &frame);
+        assert_eq!(flipped.center(), pos2(70.0, 25.0));
+    }
+}
@@ -5,8 +5,13 @@ use egui::Id;
 use egui::Shape;
 use egui::Stroke;
 use egui::Ui;
+use egui::epaint::QuadraticBezierShape;
+use emath::Pos2;
 use emath::Rot2;
+use emath::Vec2;
+use emath::vec2;
 
+use crate::aesthetics::ArrowHead;
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
 use crate::data::PlotPoints;
@@ -21,14 +26,43 @@ impl<'a> Arrows<'a> {
             origins: origins.into(),
             tips: tips.into(),
             tip_length: None,
+            head: ArrowHead::Open,
+            curvature: 0.0,
             color: Color32::TRANSPARENT,
+            colors: Vec::new(),
         }
     }
 
-    /// Set the length of the arrow tips
+    /// Set the length of the arrow tips, in ui points.
+    ///
+    /// Default: a quarter of the length of each arrow.
     #[inline]
     pub fn tip_length(mut self, tip_length: f32) -> Self {
-        self.tip_length = Some(tip_length);
+        self.tip_length = Some(TipLength::Points(tip_length));
+        self
+    }
+
+    /// Set the length of the arrow tips in plot units, measured along each
+    /// arrow, so that the tips scale with zoom.
+    #[inline]
+    pub fn tip_length_in_plot_units(mut self, tip_length: f64) -> Self {
+        self.tip_length = Some(TipLength::Plot(tip_length));
+        self
+    }
+
+    /// Set the kind of head drawn at the tips. Default: [`ArrowHead::Open`].
+    #[inline]
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Bend the arrows into arcs. The arc bulges out by `curvature` times the
+    /// length of the arrow, to the left of its direction for positive values.
+    /// Default: `0.0`, straight arrows.
+    #[inline]
+    pub fn curvature(mut self, curvature: f32) -> Self {
+        self.curvature = curvature;
         self
     }
 
@@ -39,6 +73,14 @@ impl<'a> Arrows<'a> {
         self
     }
 
+    /// Set a color for each arrow, in order. Arrows without a color of their
+    /// own, or with `Color32::TRANSPARENT`, use [`Self::color`].
+    #[inline]
+    pub fn colors(mut self, colors: impl IntoIterator<Item = Color32>) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
     /// Name of this plot item.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -71,6 +113,65 @@ impl<'a> Arrows<'a> {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -82,56 +183,97 @@ impl<'a> Arrows<'a> {
     }
 }
 
+/// The length of the arrow tips.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TipLength {
+    /// In ui points.
+    Points(f32),
+
+    /// In plot units, along the arrow.
+    Plot(f64),
+}
+
 /// A set of arrows.
 pub struct Arrows<'a> {
     base: PlotItemBase,
     pub(crate) origins: PlotPoints<'a>,
     pub(crate) tips: PlotPoints<'a>,
-    pub(crate) tip_length: Option<f32>,
+    pub(crate) tip_length: Option<TipLength>,
+    pub(crate) head: ArrowHead,
+    pub(crate) curvature: f32,
     pub(crate) color: Color32,
+    pub(crate) colors: Vec<Color32>,
+}
+
+impl Arrows<'_> {
+    /// The length of the tip, in ui points, of an arrow that is `length` ui
+    /// points and `plot_length` plot units long.
+    fn screen_tip_length(&self, length: f32, plot_length: f64) -> f32 {
+        match self.tip_length {
+            None => length / 4.0,
+            Some(TipLength::Points(tip_length)) => tip_length,
+            Some(TipLength::Plot(tip_length)) if plot_length > 0.0 => (tip_length / plot_length) as f32 * length,
+            Some(TipLength::Plot(_)) => 0.0,
+        }
+    }
+
+    fn arrow_color(&self, index: usize) -> Color32 {
+        match self.colors.get(index) {
+            Some(&color) if color != Color32::TRANSPARENT => color,
+            _ => self.color,
+        }
+    }
+
+    fn add_arrow(&self, origin: Pos2, tip: Pos2, tip_length: f32, stroke: Stroke, shapes: &mut Vec<Shape>) {
+        let vector = tip - origin;
+
+        // The direction the arrow arrives at the tip from.
+        let dir = if self.curvature == 0.0 {
+            shapes.push(Shape::line_segment([origin, tip], stroke));
+            vector.normalized()
+        } else {
+            let control = origin + 0.5 * vector + self.curvature * bend_offset(vector);
+            shapes.push(Shape::QuadraticBezier(QuadraticBezierShape::from_points_stroke(
+                [origin, control, tip],
+                false,
+                Color32::TRANSPARENT,
+                stroke,
+            )));
+            (tip - control).normalized()
+        };
+
+        let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+        let left = tip - tip_length * (rot.inverse() * dir);
+        let right = tip - tip_length * (rot * dir);
+        match self.head {
+            ArrowHead::Open => shapes.push(Shape::line(vec![left, tip, right], stroke)),
+            ArrowHead::Filled => shapes.push(Shape::convex_polygon(vec![left, tip, right], stroke.color, stroke)),
+            ArrowHead::None => {}
+        }
+    }
+}
+
+/// The offset of the control point of a curved arrow from the middle of
+/// `vector`, for a curvature of one: perpendicular to it, to its left on
+/// screen.
+fn bend_offset(vector: Vec2) -> Vec2 {
+    // The middle of a quadratic Bézier curve is halfway to its control point.
+    2.0 * vec2(vector.y, -vector.x)
 }
 
 impl PlotItem for Arrows<'_> {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let Self {
-            origins,
-            tips,
-            tip_length,
-            color,
-            base,
-            ..
-        } = self;
-        let stroke = Stroke::new(if base.highlight { 2.0 } else { 1.0 }, *color);
-        origins
-            .points()
-            .iter()
-            .zip(tips.points().iter())
-            .map(|(origin, tip)| {
-                (
-                    transform.position_from_point(origin),
-                    transform.position_from_point(tip),
-                )
-            })
-            .for_each(|(origin, tip)| {
-                let vector = tip - origin;
-                let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
-                let tip_length = if let Some(tip_length) = tip_length {
-                    *tip_length
-                } else {
-                    vector.length() / 4.0
-                };
-                let tip = origin + vector;
-                let dir = vector.normalized();
-                shapes.push(Shape::line_segment([origin, tip], stroke));
-                shapes.push(Shape::line(
-                    vec![
-                        tip - tip_length * (rot.inverse() * dir),
-                        tip,
-                        tip - tip_length * (rot * dir),
-                    ],
-                    stroke,
-                ));
-            });
+        let width = if self.base.highlight { 2.0 } else { 1.0 };
+        for (index, (origin, tip)) in self.origins.points().iter().zip(self.tips.points()).enumerate() {
+            let plot_length = (tip.x - origin.x).hypot(tip.y - origin.y);
+            let (origin, tip) = (
+                transform.position_from_point(origin),
+                transform.position_from_point(tip),
+            );
+            let tip_length = self.screen_tip_length((tip - origin).length(), plot_length);
+            let stroke = Stroke::new(width, self.arrow_color(index));
+            self.add_arrow(origin, tip, tip_length, stroke, shapes);
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
@@ -159,3 +301,25 @@ impl PlotItem for Arrows<'_> {
         &mut self.base
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tip_length_in_plot_units_scales_with_the_arrow() {
+        let arrows = Arrows::new("arrows", vec![[0.0, 0.0]], vec![[2.0, 0.0]]);
+        assert_eq!(arrows.screen_tip_length(100.0, 2.0), 25.0);
+
+        let arrows = arrows.tip_length_in_plot_units(0.5);
+        assert_eq!(arrows.screen_tip_length(100.0, 2.0), 25.0);
+        assert_eq!(arrows.screen_tip_length(200.0, 2.0), 50.0);
+        assert_eq!(arrows.screen_tip_length(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn positive_curvature_bends_to_the_left() {
+        // Pointing right on screen, left is up, towards negative y.
+        assert_eq!(bend_offset(vec2(1.0, 0.0)), vec2(0.0, -2.0));
+    }
+}
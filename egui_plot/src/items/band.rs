@@ -0,0 +1,309 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use egui::Color32;
+use egui::Id;
+use egui::Mesh;
+use egui::Pos2;
+use egui::Rgba;
+use egui::Shape;
+use egui::Ui;
+use egui::epaint::PathStroke;
+use emath::NumExt as _;
+
+use crate::aesthetics::LineStyle;
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::DEFAULT_FILL_ALPHA;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// A confidence band: a filled ribbon between a low and a high value at each
+/// x, e.g. for forecast uncertainty or ±σ regions.
+///
+/// Samples where either value is not finite leave a gap in the ribbon.
+pub struct Band {
+    base: PlotItemBase,
+
+    /// `(x, y_low, y_high)` triples, sorted by x.
+    pub(crate) samples: Vec<[f64; 3]>,
+
+    /// Color of the ribbon and its boundaries. `Color32::TRANSPARENT` means
+    /// that it will be picked automatically.
+    pub(crate) color: Color32,
+
+    pub(crate) fill_alpha: f32,
+
+    /// Width of the boundary lines, if they are drawn.
+    pub(crate) boundary_width: Option<f32>,
+
+    pub(crate) boundary_style: LineStyle,
+}
+
+impl Band {
+    /// A band through the given `[x, y_low, y_high]` samples, sorted by x.
+    pub fn new(name: impl Into<String>, samples: impl IntoIterator<Item = [f64; 3]>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            samples: samples.into_iter().collect(),
+            color: Color32::TRANSPARENT,
+            fill_alpha: 4.0 * DEFAULT_FILL_ALPHA,
+            boundary_width: None,
+            boundary_style: LineStyle::Solid,
+        }
+    }
+
+    /// A band around a central line, from `y - spread` to `y + spread`.
+    pub fn symmetric(
+        name: impl Into<String>,
+        xs: impl IntoIterator<Item = f64>,
+        ys: impl IntoIterator<Item = f64>,
+        spread: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        let samples = xs
+            .into_iter()
+            .zip(ys)
+            .zip(spread)
+            .map(|((x, y), spread)| [x, y - spread, y + spread]);
+        Self::new(name, samples)
+    }
+
+    /// Color of the band. Default is `Color32::TRANSPARENT` which means a
+    /// color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the ribbon's alpha channel. Default is `0.2`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Draw the low and high boundaries as lines of the given width, in the
+    /// band's color. Default: no boundaries.
+    #[inline]
+    pub fn boundaries(mut self, width: impl Into<f32>) -> Self {
+        self.boundary_width = Some(width.into());
+        self
+    }
+
+    /// Set the style of the boundary lines. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn boundary_style(mut self, style: LineStyle) -> Self {
+        self.boundary_style = style;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// Runs of consecutive samples with finite values, between the gaps.
+    fn runs(&self) -> impl Iterator<Item = &[[f64; 3]]> {
+        self.samples
+            .split(|sample| !sample.iter().all(|v| v.is_finite()))
+            .filter(|run| !run.is_empty())
+    }
+}
+
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut fill_alpha = self.fill_alpha;
+        if self.base.highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+        let fill_color: Color32 = Rgba::from(self.color).to_opaque().multiply(fill_alpha).into();
+
+        for run in self.runs() {
+            let low: Vec<Pos2> = run
+                .iter()
+                .map(|[x, y_low, _]| transform.position_from_point(&PlotPoint::new(*x, *y_low)))
+                .collect();
+            let high: Vec<Pos2> = run
+                .iter()
+                .map(|[x, _, y_high]| transform.position_from_point(&PlotPoint::new(*x, *y_high)))
+                .collect();
+
+            if run.len() >= 2 {
+                let mut mesh = Mesh::default();
+                mesh.reserve_vertices(2 * run.len());
+                mesh.reserve_triangles(2 * (run.len() - 1));
+                for (i, (&low, &high)) in low.iter().zip(&high).enumerate() {
+                    mesh.colored_vertex(low, fill_color);
+                    mesh.colored_vertex(high, fill_color);
+                    if i > 0 {
+                        let k = 2 * i as u32;
+                        mesh.add_triangle(k - 2, k - 1, k);
+                        mesh.add_triangle(k - 1, k, k + 1);
+                    }
+                }
+                shapes.push(Shape::Mesh(Arc::new(mesh)));
+            }
+
+            if let Some(width) = self.boundary_width {
+                for line in [low, high] {
+                    self.boundary_style.style_line(
+                        line,
+                        PathStroke::new(width, self.color),
+                        self.base.highlight,
+                        shapes,
+                    );
+                }
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for &[x, y_low, y_high] in self.runs().flatten() {
+            bounds.extend_with(&PlotPoint::new(x, y_low));
+            bounds.extend_with(&PlotPoint::new(x, y_high));
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_split_the_band() {
+        let band = Band::new(
+            "band",
+            [
+                [0.0, -1.0, 1.0],
+                [1.0, f64::NAN, 2.0],
+                [2.0, -2.0, 3.0],
+                [3.0, -1.0, 1.0],
+            ],
+        );
+        assert_eq!(band.runs().map(<[_]>::len).collect::<Vec<_>>(), [1, 2]);
+
+        let bounds = band.bounds();
+        assert_eq!(bounds.min(), [0.0, -2.0]);
+        assert_eq!(bounds.max(), [3.0, 3.0]);
+    }
+}
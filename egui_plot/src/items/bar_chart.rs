@@ -17,7 +17,9 @@ use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
 use crate::colors::highlighted_color;
 use crate::cursor::Cursor;
+use crate::items::BinRule;
 use crate::items::ClosestElem;
+use crate::items::Histogram;
 use crate::items::PlotConfig;
 use crate::items::PlotGeometry;
 use crate::items::PlotItem;
@@ -49,6 +51,14 @@ impl BarChart {
         }
     }
 
+    /// Create a histogram of the given samples, with one bar per bin.
+    ///
+    /// See [`Histogram`] for more control, e.g. to normalize the counts to a
+    /// density.
+    pub fn from_samples(name: impl Into<String>, samples: &[f64], bins: BinRule) -> Self {
+        Histogram::new(samples, bins).into_bar_chart(name)
+    }
+
     /// Set the default color. It is set on all elements that do not already
     /// have a specific color. This is the color that shows up in the
     /// legend. It can be overridden at the bar level (see [[`Bar`]]).
@@ -107,6 +117,9 @@ impl BarChart {
     /// Stacks the bars on top of another chart.
     /// Positive values are stacked on top of other positive values.
     /// Negative values are stacked below other negative values.
+    ///
+    /// To stack several segments per argument in one go, see
+    /// [`crate::StackedBars`].
     #[inline]
     pub fn stack_on(mut self, others: &[&Self]) -> Self {
         for (index, bar) in self.bars.iter_mut().enumerate() {
@@ -161,6 +174,56 @@ impl BarChart {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
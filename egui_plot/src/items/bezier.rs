@@ -0,0 +1,355 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use egui::epaint::PathStroke;
+use emath::Pos2;
+
+use crate::aesthetics::LineStyle;
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::data::PlotPoints;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Limit on the subdivisions of a single segment, i.e. at most `2^16` points.
+const MAX_DEPTH: u32 = 16;
+
+/// Subdivisions of each segment regardless of its flatness, so that S-shaped
+/// segments with a straight-looking midpoint are not mistaken for lines.
+const MIN_DEPTH: u32 = 3;
+
+impl<'a> CubicBezier<'a> {
+    /// A path of cubic Bézier segments, given by their control points:
+    /// `[start, control, control, end, control, control, end, …]`, i.e. `3n + 1`
+    /// points for `n` segments. Extra trailing points are ignored.
+    pub fn new(name: impl Into<String>, control_points: impl Into<PlotPoints<'a>>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            series: control_points.into(),
+            spline: None,
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            tolerance: 0.25,
+        }
+    }
+
+    /// A smooth curve passing through all the given knots: a cardinal spline,
+    /// which is a Catmull-Rom spline for the default tension of `0.0`.
+    pub fn spline(name: impl Into<String>, knots: impl Into<PlotPoints<'a>>) -> Self {
+        Self {
+            spline: Some(0.0),
+            ..Self::new(name, knots)
+        }
+    }
+
+    /// Tension of a [`Self::spline`], from `0.0` for a Catmull-Rom spline to
+    /// `1.0` for straight lines between the knots. Has no effect on explicit
+    /// control points.
+    #[inline]
+    pub fn tension(mut self, tension: f64) -> Self {
+        if self.spline.is_some() {
+            self.spline = Some(tension);
+        }
+        self
+    }
+
+    /// Maximum distance between the drawn polyline and the true curve, in ui
+    /// points. Default: `0.25`.
+    ///
+    /// The curve is subdivided on screen, so it stays smooth at any zoom
+    /// level.
+    #[inline]
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance.max(0.01);
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will
+    /// be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The control points of each segment, in plot coordinates.
+    fn segments(&self) -> Vec<[PlotPoint; 4]> {
+        let points = self.series.points();
+        match self.spline {
+            None => points.windows(4).step_by(3).map(|w| [w[0], w[1], w[2], w[3]]).collect(),
+            Some(tension) => {
+                let scale = (1.0 - tension) / 6.0;
+                let at = |i: usize| points[i.min(points.len() - 1)];
+                (0..points.len().saturating_sub(1))
+                    .map(|i| {
+                        let (p0, p1, p2, p3) = (at(i.saturating_sub(1)), at(i), at(i + 1), at(i + 2));
+                        [
+                            p1,
+                            PlotPoint::new(p1.x + scale * (p2.x - p0.x), p1.y + scale * (p2.y - p0.y)),
+                            PlotPoint::new(p2.x - scale * (p3.x - p1.x), p2.y - scale * (p3.y - p1.y)),
+                            p2,
+                        ]
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A smooth curve made of cubic Bézier segments, either from explicit control
+/// points ([`Self::new`]) or interpolating knots ([`Self::spline`]).
+///
+/// The curve is evaluated in plot coordinates and subdivided on screen until
+/// it is within [`Self::tolerance`] of a polyline, so there is no need to
+/// sample it beforehand.
+pub struct CubicBezier<'a> {
+    base: PlotItemBase,
+    pub(crate) series: PlotPoints<'a>,
+
+    /// The tension, if the series are knots of a spline.
+    spline: Option<f64>,
+
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    tolerance: f32,
+}
+
+impl PlotItem for CubicBezier<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let segments = self.segments();
+        let Some(first) = segments.first() else {
+            return;
+        };
+
+        let mut path = vec![transform.position_from_point(&first[0])];
+        for segment in &segments {
+            let eval = |t: f64| transform.position_from_point(&bezier(segment, t));
+            let end = transform.position_from_point(&segment[3]);
+            subdivide(
+                &eval,
+                (0.0, *path.last().unwrap_or(&end)),
+                (1.0, end),
+                0,
+                self.tolerance,
+                &mut path,
+            );
+        }
+
+        self.style.style_line(
+            path,
+            PathStroke::new(self.stroke.width, self.stroke.color),
+            self.base.highlight,
+            shapes,
+        );
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // The curve lies within the convex hull of its control points.
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.segments().iter().flatten() {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// The point at `t` of a cubic Bézier segment.
+fn bezier([p0, p1, p2, p3]: &[PlotPoint; 4], t: f64) -> PlotPoint {
+    let s = 1.0 - t;
+    let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+    PlotPoint::new(
+        a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+        a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+    )
+}
+
+/// Push the points of the curve `eval` after `start` up to `end`, splitting
+/// in halves until the midpoint is within `tolerance` of the chord.
+fn subdivide(
+    eval: &impl Fn(f64) -> Pos2,
+    start: (f64, Pos2),
+    end: (f64, Pos2),
+    depth: u32,
+    tolerance: f32,
+    path: &mut Vec<Pos2>,
+) {
+    let t = 0.5 * (start.0 + end.0);
+    let mid = eval(t);
+    let flat = mid.distance(start.1.lerp(end.1, 0.5)) <= tolerance;
+    if depth < MIN_DEPTH || (!flat && depth < MAX_DEPTH && mid.is_finite()) {
+        subdivide(eval, start, (t, mid), depth + 1, tolerance, path);
+        subdivide(eval, (t, mid), end, depth + 1, tolerance, path);
+    } else {
+        path.push(end.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spline_passes_through_knots() {
+        let knots = vec![[0.0, 0.0], [1.0, 2.0], [3.0, 1.0]];
+        let spline = CubicBezier::spline("spline", knots);
+        let segments = spline.segments();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(bezier(&segments[0], 0.0), PlotPoint::new(0.0, 0.0));
+        assert_eq!(bezier(&segments[0], 1.0), PlotPoint::new(1.0, 2.0));
+        assert_eq!(bezier(&segments[1], 1.0), PlotPoint::new(3.0, 1.0));
+
+        // Tangents are continuous at the inner knot.
+        let out_of_first = segments[0][3].y - segments[0][2].y;
+        let into_second = segments[1][1].y - segments[1][0].y;
+        assert!((out_of_first - into_second).abs() < 1e-12);
+    }
+}
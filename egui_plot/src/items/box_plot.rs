@@ -10,6 +10,7 @@ use egui::epaint::RectShape;
 use emath::NumExt as _;
 use emath::Pos2;
 
+use crate::aesthetics::FillRule;
 use crate::aesthetics::Orientation;
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
@@ -24,8 +25,12 @@ use crate::items::PlotItemBase;
 use crate::items::add_rulers_and_text;
 use crate::label::LabelFormatter;
 use crate::math::find_closest_rect;
+use crate::math::quantile;
 use crate::rect_elem::RectElement;
 
+/// Radius of the circles marking outliers, in ui points.
+const OUTLIER_RADIUS: f32 = 2.0;
+
 /// A diagram containing a series of [`BoxElem`] elements.
 pub struct BoxPlot {
     base: PlotItemBase,
@@ -127,6 +132,56 @@ impl BoxPlot {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -228,11 +283,31 @@ impl BoxSpread {
     }
 }
 
+/// How far the whiskers of a [`BoxElem`] computed by
+/// [`BoxElem::from_samples`] reach.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WhiskerRule {
+    /// The whiskers reach the furthest samples within the given multiple of
+    /// the interquartile range from the box. Samples beyond are outliers.
+    Iqr(f64),
+
+    /// The whiskers reach the minimum and the maximum, there are no outliers.
+    MinMax,
+}
+
+impl Default for WhiskerRule {
+    /// Tukey's rule, `1.5·IQR`.
+    fn default() -> Self {
+        Self::Iqr(1.5)
+    }
+}
+
 /// A box in a [`BoxPlot`] diagram.
 ///
-/// This is a low-level graphical element; it will not compute quartiles and
-/// whiskers, letting one use their preferred formula. Use
-/// [`Points`][`super::Points`] to draw the outliers.
+/// [`Self::new`] is a low-level constructor; it will not compute quartiles
+/// and whiskers, letting one use their preferred formula. Use
+/// [`Self::from_samples`] to compute them, along with the outliers and the
+/// notch, from raw samples.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BoxElem {
     /// Name of plot element in the diagram (annotated by default formatter).
@@ -258,6 +333,16 @@ pub struct BoxElem {
 
     /// Fill color
     pub fill: Color32,
+
+    /// Values beyond the whiskers, drawn as small circles.
+    pub outliers: Vec<f64>,
+
+    /// Lower and upper limits of the confidence interval of the median, drawn
+    /// as a notch in the box if [`Self::show_notch`] is set.
+    pub notch: Option<(f64, f64)>,
+
+    /// Whether to draw [`Self::notch`].
+    pub show_notch: bool,
 }
 
 impl BoxElem {
@@ -275,9 +360,49 @@ impl BoxElem {
             whisker_width: 0.15,
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             fill: Color32::TRANSPARENT,
+            outliers: Vec::new(),
+            notch: None,
+            show_notch: false,
         }
     }
 
+    /// Create a box from raw samples, with the whiskers reaching as far as
+    /// `rule` allows and the samples beyond them as outliers.
+    ///
+    /// The notch is computed as well, as `median ± 1.57·IQR/√n`, see
+    /// [`Self::show_notch`]. Non-finite samples are ignored.
+    pub fn from_samples(argument: f64, samples: &[f64], rule: WhiskerRule) -> Self {
+        let mut samples: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        samples.sort_by(f64::total_cmp);
+        if samples.is_empty() {
+            return Self::new(
+                argument,
+                BoxSpread::new(f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN),
+            );
+        }
+
+        let quartile1 = quantile(&samples, 0.25);
+        let median = quantile(&samples, 0.5);
+        let quartile3 = quantile(&samples, 0.75);
+        let iqr = quartile3 - quartile1;
+        let (low, high) = match rule {
+            WhiskerRule::Iqr(factor) => (quartile1 - factor * iqr, quartile3 + factor * iqr),
+            WhiskerRule::MinMax => (f64::NEG_INFINITY, f64::INFINITY),
+        };
+        let within = |s: &&f64| (low..=high).contains(*s);
+        let lower_whisker = samples.iter().find(within).copied().unwrap_or(quartile1);
+        let upper_whisker = samples.iter().rev().find(within).copied().unwrap_or(quartile3);
+
+        let half_notch = 1.57 * iqr / (samples.len() as f64).sqrt();
+        let mut elem = Self::new(
+            argument,
+            BoxSpread::new(lower_whisker, quartile1, median, quartile3, upper_whisker),
+        );
+        elem.outliers = samples.iter().copied().filter(|s| !(low..=high).contains(s)).collect();
+        elem.notch = Some((median - half_notch, median + half_notch));
+        elem
+    }
+
     /// Name of this box element.
     #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
     #[inline]
@@ -314,6 +439,30 @@ impl BoxElem {
         self
     }
 
+    /// Set the values beyond the whiskers, drawn as small circles.
+    #[inline]
+    pub fn outliers(mut self, outliers: impl IntoIterator<Item = f64>) -> Self {
+        self.outliers = outliers.into_iter().collect();
+        self
+    }
+
+    /// Set the limits of the confidence interval of the median, and draw it
+    /// as a notch.
+    #[inline]
+    pub fn notch(mut self, lower: f64, upper: f64) -> Self {
+        self.notch = Some((lower, upper));
+        self.show_notch = true;
+        self
+    }
+
+    /// Whether to draw the box with a notch around the median showing its
+    /// confidence interval. Needs a [`Self::notch`]. Default: `false`.
+    #[inline]
+    pub fn show_notch(mut self, show_notch: bool) -> Self {
+        self.show_notch = show_notch;
+        self
+    }
+
     /// Set orientation of the element as vertical. Argument axis is X.
     #[inline]
     pub fn vertical(mut self) -> Self {
@@ -335,28 +484,35 @@ impl BoxElem {
             (self.stroke, self.fill)
         };
 
-        let rect = transform.rect_from_values(
-            &self.point_at(self.argument - self.box_width / 2.0, self.spread.quartile1),
-            &self.point_at(self.argument + self.box_width / 2.0, self.spread.quartile3),
-        );
-        let rect = Shape::Rect(RectShape::new(
-            rect,
-            CornerRadius::ZERO,
-            fill,
-            stroke,
-            egui::StrokeKind::Inside,
-        ));
-        shapes.push(rect);
-
         let line_between = |v1, v2| {
             Shape::line_segment(
                 [transform.position_from_point(&v1), transform.position_from_point(&v2)],
                 stroke,
             )
         };
+
+        let median_width = if let Some((lower, upper)) = self.notch.filter(|_| self.show_notch) {
+            self.add_notched_box(lower, upper, transform, stroke, fill, shapes);
+            self.box_width / 2.0
+        } else {
+            let rect = transform.rect_from_values(
+                &self.point_at(self.argument - self.box_width / 2.0, self.spread.quartile1),
+                &self.point_at(self.argument + self.box_width / 2.0, self.spread.quartile3),
+            );
+            let rect = Shape::Rect(RectShape::new(
+                rect,
+                CornerRadius::ZERO,
+                fill,
+                stroke,
+                egui::StrokeKind::Inside,
+            ));
+            shapes.push(rect);
+            self.box_width
+        };
+
         let median = line_between(
-            self.point_at(self.argument - self.box_width / 2.0, self.spread.median),
-            self.point_at(self.argument + self.box_width / 2.0, self.spread.median),
+            self.point_at(self.argument - median_width / 2.0, self.spread.median),
+            self.point_at(self.argument + median_width / 2.0, self.spread.median),
         );
         shapes.push(median);
 
@@ -389,6 +545,45 @@ impl BoxElem {
                 shapes.push(low_whisker_end);
             }
         }
+
+        for &outlier in &self.outliers {
+            let center = transform.position_from_point(&self.point_at(self.argument, outlier));
+            shapes.push(Shape::circle_stroke(center, OUTLIER_RADIUS, stroke));
+        }
+    }
+
+    /// The box narrowed to half its width at the median, with the narrowing
+    /// spanning the confidence interval from `lower` to `upper`.
+    fn add_notched_box(
+        &self,
+        lower: f64,
+        upper: f64,
+        transform: &PlotTransform,
+        stroke: Stroke,
+        fill: Color32,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (q1, median, q3) = (self.spread.quartile1, self.spread.median, self.spread.quartile3);
+        let (lower, upper) = (lower.clamp(q1, median), upper.clamp(median, q3));
+        let half = self.box_width / 2.0;
+        let outline: Vec<Pos2> = [
+            (-half, q1),
+            (-half, lower),
+            (-half / 2.0, median),
+            (-half, upper),
+            (-half, q3),
+            (half, q3),
+            (half, upper),
+            (half / 2.0, median),
+            (half, lower),
+            (half, q1),
+        ]
+        .into_iter()
+        .map(|(offset, value)| transform.position_from_point(&self.point_at(self.argument + offset, value)))
+        .collect();
+
+        shapes.push(Shape::mesh(FillRule::NonZero.fill(&[outline.clone()], fill)));
+        shapes.push(Shape::closed_line(outline, stroke));
     }
 
     pub(in crate::items) fn add_rulers_and_text(
@@ -411,13 +606,13 @@ impl RectElement for BoxElem {
 
     fn bounds_min(&self) -> PlotPoint {
         let argument = self.argument - self.box_width.max(self.whisker_width) / 2.0;
-        let value = self.spread.lower_whisker;
+        let value = self.outliers.iter().fold(self.spread.lower_whisker, |a, &b| a.min(b));
         self.point_at(argument, value)
     }
 
     fn bounds_max(&self) -> PlotPoint {
         let argument = self.argument + self.box_width.max(self.whisker_width) / 2.0;
-        let value = self.spread.upper_whisker;
+        let value = self.outliers.iter().fold(self.spread.upper_whisker, |a, &b| a.max(b));
         self.point_at(argument, value)
     }
 
@@ -463,3 +658,29 @@ impl RectElement for BoxElem {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_splits_off_outliers() {
+        let samples = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 40.0, -30.0];
+        let elem = BoxElem::from_samples(0.0, &samples, WhiskerRule::default());
+        assert_eq!(elem.spread, BoxSpread::new(1.0, 2.5, 5.0, 7.5, 9.0));
+        assert_eq!(elem.outliers, [-30.0, 40.0]);
+        assert_eq!(elem.bounds_min().y, -30.0);
+        assert_eq!(elem.bounds_max().y, 40.0);
+
+        let Some((lower, upper)) = elem.notch else {
+            panic!("samples give a notch");
+        };
+        assert!(lower < 5.0 && upper > 5.0, "notch should surround the median");
+        assert!(!elem.show_notch, "notch should be hidden by default");
+
+        let elem = BoxElem::from_samples(0.0, &samples, WhiskerRule::MinMax);
+        assert_eq!(elem.spread.lower_whisker, -30.0);
+        assert_eq!(elem.spread.upper_whisker, 40.0);
+        assert!(elem.outliers.is_empty(), "min/max whiskers leave no outliers");
+    }
+}
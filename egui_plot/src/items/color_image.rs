@@ -0,0 +1,385 @@
+use std::hash::Hash as _;
+use std::hash::Hasher as _;
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::ColorImage;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextureHandle;
+use egui::TextureOptions;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::Vec2;
+use emath::pos2;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::BASE_COLORS;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::Heatmap;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+
+/// Resolution of the color palette.
+const PALETTE_RESOLUTION: usize = 256;
+
+impl ColorImageItem {
+    /// An image of scalar `values`, row by row from the top, `cols` values per
+    /// row. Position and size are in plot coordinates, like for
+    /// [`crate::PlotImage`].
+    pub fn new(
+        name: impl Into<String>,
+        values: impl Into<Vec<f64>>,
+        cols: usize,
+        center_position: PlotPoint,
+        size: impl Into<Vec2>,
+    ) -> Self {
+        let values = values.into();
+        let cols = cols.max(1);
+        Self {
+            base: PlotItemBase::new(name.into()),
+            rows: values.len() / cols,
+            values,
+            cols,
+            position: center_position,
+            size: size.into(),
+            range: None,
+            palette: Heatmap::linear_gradient_from_base_colors(&BASE_COLORS, PALETTE_RESOLUTION),
+            nan_color: Color32::TRANSPARENT,
+            texture_options: TextureOptions::NEAREST,
+        }
+    }
+
+    /// Values mapped to the ends of the palette; values outside are clamped.
+    /// Default: the range of the finite values.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the color palette by specifying base colors from low to high.
+    #[inline]
+    pub fn palette(mut self, base_colors: &[Color32]) -> Self {
+        self.palette = Heatmap::linear_gradient_from_base_colors(base_colors, PALETTE_RESOLUTION);
+        self
+    }
+
+    /// Color of non-finite values. Default: `Color32::TRANSPARENT`.
+    #[inline]
+    pub fn nan_color(mut self, color: impl Into<Color32>) -> Self {
+        self.nan_color = color.into();
+        self
+    }
+
+    /// How the texture is sampled. Default: [`TextureOptions::NEAREST`], which
+    /// shows each value as a sharp pixel.
+    #[inline]
+    pub fn texture_options(mut self, options: TextureOptions) -> Self {
+        self.texture_options = options;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The range mapped to the palette.
+    fn value_range(&self) -> (f64, f64) {
+        if let Some(range) = &self.range {
+            return (*range.start(), *range.end());
+        }
+        self.values
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            })
+    }
+
+    /// The colors of the pixels, row by row.
+    fn pixels(&self) -> Vec<Color32> {
+        let (min, max) = self.value_range();
+        let span = max - min;
+        let last = (self.palette.len() - 1) as f64;
+        self.values[..self.rows * self.cols]
+            .iter()
+            .map(|&value| {
+                if !value.is_finite() {
+                    self.nan_color
+                } else if span > 0.0 {
+                    self.palette[(((value - min) / span).clamp(0.0, 1.0) * last).round() as usize]
+                } else {
+                    self.palette[0]
+                }
+            })
+            .collect()
+    }
+
+    /// The texture of the image, only rebuilt when the values, range or
+    /// palette change.
+    fn texture(&self, ui: &Ui) -> TextureHandle {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.cols.hash(&mut hasher);
+        for value in &self.values {
+            value.to_bits().hash(&mut hasher);
+        }
+        self.range
+            .as_ref()
+            .map(|r| (r.start().to_bits(), r.end().to_bits()))
+            .hash(&mut hasher);
+        self.palette.hash(&mut hasher);
+        self.nan_color.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let id = self.base.id.with("color_image_texture");
+        let cached = ui.data(|d| d.get_temp::<(u64, TextureOptions, TextureHandle)>(id));
+        if let Some((cached_key, options, texture)) = cached
+            && cached_key == key
+            && options == self.texture_options
+        {
+            return texture;
+        }
+
+        let image = ColorImage::new([self.cols, self.rows], self.pixels());
+        let texture = ui
+            .ctx()
+            .load_texture(self.base.name.clone(), image, self.texture_options);
+        ui.data_mut(|d| d.insert_temp(id, (key, self.texture_options, texture.clone())));
+        texture
+    }
+
+    fn screen_rect(&self, transform: &PlotTransform) -> Rect {
+        let half = 0.5 * self.size;
+        let left_top = PlotPoint::new(self.position.x - half.x as f64, self.position.y + half.y as f64);
+        let right_bottom = PlotPoint::new(self.position.x + half.x as f64, self.position.y - half.y as f64);
+        Rect::from_two_pos(
+            transform.position_from_point(&left_top),
+            transform.position_from_point(&right_bottom),
+        )
+    }
+}
+
+/// An image of scalar values, colored through a palette.
+///
+/// The values are only converted to colors and uploaded when they (or the
+/// range or palette) change, not every frame, so this suits large scientific
+/// images such as depth maps or intensity frames. Hovering shows the value
+/// under the pointer.
+///
+/// See [`Heatmap`] for labeled tiles instead.
+pub struct ColorImageItem {
+    base: PlotItemBase,
+    pub(crate) values: Vec<f64>,
+    cols: usize,
+    rows: usize,
+    position: PlotPoint,
+    size: Vec2,
+
+    /// Values mapped to the palette. `None` means the range of the values.
+    range: Option<RangeInclusive<f64>>,
+
+    palette: Vec<Color32>,
+    nan_color: Color32,
+    texture_options: TextureOptions,
+}
+
+impl PlotItem for ColorImageItem {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.rows == 0 {
+            return;
+        }
+        let texture = self.texture(ui);
+        let rect = self.screen_rect(transform);
+        shapes.push(Shape::image(
+            texture.id(),
+            rect,
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            Color32::WHITE,
+        ));
+        if self.base.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+                egui::StrokeKind::Outside,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.palette[self.palette.len() / 2]
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let half = 0.5 * self.size;
+        PlotBounds::from_min_max(
+            [self.position.x - half.x as f64, self.position.y - half.y as f64],
+            [self.position.x + half.x as f64, self.position.y + half.y as f64],
+        )
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let rect = self.screen_rect(transform);
+        if self.rows == 0 || !rect.contains(point) {
+            return None;
+        }
+        let offset = (point - rect.min) / rect.size();
+        let col = ((offset.x * self.cols as f32) as usize).min(self.cols - 1);
+        let row = ((offset.y * self.rows as f32) as usize).min(self.rows - 1);
+        Some(ClosestElem {
+            index: row * self.cols + col,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        _shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        _plot: &PlotConfig<'_>,
+        _label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let (row, col) = (elem.index / self.cols, elem.index % self.cols);
+        show_tooltip(
+            plot_area_response,
+            format!("{}\n[{row}, {col}]: {}", self.name(), self.values[elem.index]),
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_map_to_the_palette() {
+        let image = ColorImageItem::new(
+            "image",
+            vec![0.0, 1.0, 2.0, f64::NAN],
+            2,
+            PlotPoint::new(0.0, 0.0),
+            [1.0, 1.0],
+        )
+        .palette(&[Color32::BLACK, Color32::WHITE])
+        .range(0.0..=1.0);
+        let pixels = image.pixels();
+        assert_eq!(pixels[0], Color32::BLACK);
+        assert_eq!(pixels[1], Color32::WHITE);
+        assert_eq!(pixels[2], Color32::WHITE);
+        assert_eq!(pixels[3], Color32::TRANSPARENT);
+    }
+}
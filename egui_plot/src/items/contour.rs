@@ -0,0 +1,469 @@
+use std::ops::RangeInclusive;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Id;
+use egui::Mesh;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use egui::epaint::Vertex;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::BASE_COLORS;
+use crate::items::Heatmap;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// A node of the grid, in plot coordinates, with its value.
+type Node = (PlotPoint, f64);
+
+/// Contour lines (iso-lines) of a 2D scalar field, optionally with filled
+/// bands between the levels.
+///
+/// The values are sampled on a regular grid of nodes, row by row, like for a
+/// [`Heatmap`]. Each grid cell is split into two triangles, within which the
+/// field is interpolated linearly.
+pub struct Contour {
+    base: PlotItemBase,
+
+    values: Vec<f64>,
+    cols: usize,
+    rows: usize,
+
+    /// Position of the first node.
+    pos: PlotPoint,
+
+    /// Distance from the first to the last node, in plot coordinates.
+    size: [f64; 2],
+
+    /// Sorted from low to high.
+    levels: Vec<f64>,
+
+    /// One per level. Empty for the default palette.
+    colors: Vec<Color32>,
+    width: f32,
+    filled: bool,
+    fill_alpha: f32,
+    show_labels: bool,
+    formatter: Box<dyn Fn(f64) -> String>,
+}
+
+impl Contour {
+    /// Contours of `values` at the given `levels`.
+    ///
+    /// - `values` are the samples of the field, row by row, starting at the
+    ///   bottom.
+    /// - `cols` is the number of columns (i.e. the length of each row).
+    /// - `values.len()` should be a multiple of `cols`, otherwise nothing is
+    ///   drawn.
+    ///
+    /// By default the grid spans `0..=cols - 1` and `0..=rows - 1`, see
+    /// [`Self::extent`].
+    pub fn new(
+        name: impl Into<String>,
+        values: impl Into<Vec<f64>>,
+        cols: usize,
+        levels: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        let values = values.into();
+        let rows = if cols == 0 || values.len() % cols != 0 {
+            0
+        } else {
+            values.len() / cols
+        };
+        let mut levels: Vec<f64> = levels.into_iter().filter(|level| level.is_finite()).collect();
+        levels.sort_by(f64::total_cmp);
+
+        Self {
+            base: PlotItemBase::new(name.into()),
+            values,
+            cols,
+            rows,
+            pos: PlotPoint::new(0.0, 0.0),
+            size: [cols.saturating_sub(1) as f64, rows.saturating_sub(1) as f64],
+            levels,
+            colors: Vec::new(),
+            width: 1.0,
+            filled: false,
+            fill_alpha: 0.5,
+            show_labels: false,
+            formatter: Box::new(|level| format!("{level}")),
+        }
+    }
+
+    /// Place the grid over the given ranges in plot coordinates: the first
+    /// node is at the start of both ranges, the last one at their end.
+    #[inline]
+    pub fn extent(mut self, x: RangeInclusive<f64>, y: RangeInclusive<f64>) -> Self {
+        self.pos = PlotPoint::new(*x.start(), *y.start());
+        self.size = [x.end() - x.start(), y.end() - y.start()];
+        self
+    }
+
+    /// Set the color of each level, from low to high.
+    ///
+    /// By default the colors go through the same palette as a [`Heatmap`].
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Stroke width of the contour lines. `0.0` for no lines. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Fill the bands between two consecutive levels with the color of the
+    /// lower one. Default: `false`.
+    #[inline]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Opacity of the filled bands, from `0.0` to `1.0`. Default: `0.5`.
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = fill_alpha.into();
+        self
+    }
+
+    /// Label each level once, along its contour. Default: `false`.
+    #[inline]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Format the level labels. Default: the plain value.
+    #[inline]
+    pub fn formatter(mut self, formatter: impl Fn(f64) -> String + 'static) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The color of each level.
+    fn level_colors(&self) -> Vec<Color32> {
+        if self.colors.is_empty() {
+            Heatmap::linear_gradient_from_base_colors(&BASE_COLORS, self.levels.len())
+        } else {
+            (0..self.levels.len())
+                .map(|i| self.colors[i.min(self.colors.len() - 1)])
+                .collect()
+        }
+    }
+
+    /// All triangles of the grid, skipping those with non-finite values.
+    fn triangles(&self) -> impl Iterator<Item = [Node; 3]> + '_ {
+        let node = |col: usize, row: usize| -> Node {
+            let fraction = |i: usize, n: usize| if n > 1 { i as f64 / (n - 1) as f64 } else { 0.0 };
+            (
+                PlotPoint::new(
+                    self.pos.x + self.size[0] * fraction(col, self.cols),
+                    self.pos.y + self.size[1] * fraction(row, self.rows),
+                ),
+                self.values[row * self.cols + col],
+            )
+        };
+        (0..self.rows.saturating_sub(1))
+            .flat_map(move |row| (0..self.cols.saturating_sub(1)).map(move |col| (col, row)))
+            .flat_map(move |(col, row)| {
+                let [a, b, c, d] = [
+                    node(col, row),
+                    node(col + 1, row),
+                    node(col + 1, row + 1),
+                    node(col, row + 1),
+                ];
+                [[a, b, c], [a, c, d]]
+            })
+            .filter(|triangle| triangle.iter().all(|(_, value)| value.is_finite()))
+    }
+
+    /// The segments of the contour at `level`, in plot coordinates.
+    fn iso_segments(&self, level: f64) -> Vec<[PlotPoint; 2]> {
+        self.triangles()
+            .filter_map(|triangle| {
+                let mut crossings = (0..3).filter_map(|i| {
+                    let (p, p_value) = triangle[i];
+                    let (q, q_value) = triangle[(i + 1) % 3];
+                    ((p_value >= level) != (q_value >= level))
+                        .then(|| lerp(p, q, (level - p_value) / (q_value - p_value)))
+                });
+                Some([crossings.next()?, crossings.next()?])
+            })
+            .collect()
+    }
+
+    /// The polygons where `low <= value <= high`, in plot coordinates.
+    fn band_polygons(&self, low: f64, high: f64) -> Vec<Vec<PlotPoint>> {
+        self.triangles()
+            .map(|triangle| clip(&clip(&triangle, low, true), high, false))
+            .filter(|polygon| polygon.len() >= 3)
+            .map(|polygon| polygon.into_iter().map(|(point, _)| point).collect())
+            .collect()
+    }
+}
+
+/// Linear interpolation between two points.
+fn lerp(p: PlotPoint, q: PlotPoint, t: f64) -> PlotPoint {
+    PlotPoint::new(p.x + t * (q.x - p.x), p.y + t * (q.y - p.y))
+}
+
+/// Clip a convex polygon to where the interpolated value is above (or below)
+/// `level`.
+fn clip(polygon: &[Node], level: f64, keep_above: bool) -> Vec<Node> {
+    let keep = |value: f64| if keep_above { value >= level } else { value <= level };
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+    for (i, &(p, p_value)) in polygon.iter().enumerate() {
+        let (q, q_value) = polygon[(i + 1) % polygon.len()];
+        if keep(p_value) {
+            clipped.push((p, p_value));
+        }
+        if keep(p_value) != keep(q_value) {
+            let t = (level - p_value) / (q_value - p_value);
+            clipped.push((lerp(p, q, t), level));
+        }
+    }
+    clipped
+}
+
+impl PlotItem for Contour {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let colors = self.level_colors();
+
+        if self.filled {
+            let mut mesh = Mesh::default();
+            for (i, pair) in self.levels.windows(2).enumerate() {
+                let color = colors[i].gamma_multiply(self.fill_alpha);
+                for polygon in self.band_polygons(pair[0], pair[1]) {
+                    let first = mesh.vertices.len() as u32;
+                    mesh.vertices.extend(polygon.iter().map(|point| Vertex {
+                        pos: transform.position_from_point(point),
+                        uv: egui::epaint::WHITE_UV,
+                        color,
+                    }));
+                    for k in 1..polygon.len() as u32 - 1 {
+                        mesh.add_triangle(first, first + k, first + k + 1);
+                    }
+                }
+            }
+            shapes.push(Shape::mesh(mesh));
+        }
+
+        let width = if self.base.highlight {
+            2.0 * self.width
+        } else {
+            self.width
+        };
+        let font_id = TextStyle::Small.resolve(ui.style());
+        for (&level, &color) in self.levels.iter().zip(&colors) {
+            let segments = self.iso_segments(level);
+            if width > 0.0 {
+                let stroke = Stroke::new(width, color);
+                shapes.extend(segments.iter().map(|segment| {
+                    Shape::line_segment(segment.map(|point| transform.position_from_point(&point)), stroke)
+                }));
+            }
+
+            if self.show_labels {
+                // Label the segment closest to the center of the contour.
+                let mut bounds = PlotBounds::NOTHING;
+                for point in segments.iter().flatten() {
+                    bounds.extend_with(point);
+                }
+                let center = bounds.center();
+                let distance_sq = |point: PlotPoint| (point.x - center.x).powi(2) + (point.y - center.y).powi(2);
+                let closest = segments
+                    .iter()
+                    .map(|[p, q]| lerp(*p, *q, 0.5))
+                    .min_by(|a, b| distance_sq(*a).total_cmp(&distance_sq(*b)));
+                if let Some(position) = closest {
+                    let position = transform.position_from_point(&position);
+                    let text = (self.formatter)(level);
+                    ui.fonts_mut(|f| {
+                        shapes.push(Shape::text(
+                            f,
+                            position,
+                            Align2::CENTER_CENTER,
+                            text,
+                            font_id.clone(),
+                            color,
+                        ));
+                    });
+                }
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        let colors = self.level_colors();
+        colors.get(colors.len() / 2).copied().unwrap_or(Color32::TRANSPARENT)
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if self.rows == 0 {
+            return PlotBounds::NOTHING;
+        }
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.pos);
+        bounds.extend_with(&PlotPoint::new(self.pos.x + self.size[0], self.pos.y + self.size[1]));
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `value = x + y` on a 3×3 grid.
+    fn diagonal() -> Contour {
+        let values: Vec<f64> = (0..3).flat_map(|y| (0..3).map(move |x| f64::from(x + y))).collect();
+        Contour::new("diagonal", values, 3, [1.5])
+    }
+
+    #[test]
+    fn iso_lines_follow_the_level() {
+        let segments = diagonal().iso_segments(1.5);
+        assert!(!segments.is_empty());
+        for point in segments.iter().flatten() {
+            assert!((point.x + point.y - 1.5).abs() < 1e-12, "{point:?}");
+        }
+    }
+
+    #[test]
+    fn bands_cover_the_area_between_levels() {
+        let contour = diagonal();
+        let area: f64 = contour
+            .band_polygons(1.0, 3.0)
+            .iter()
+            .map(|polygon| {
+                let signed: f64 = (0..polygon.len())
+                    .map(|i| {
+                        let (p, q) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                        p.x * q.y - q.x * p.y
+                    })
+                    .sum();
+                0.5 * signed.abs()
+            })
+            .sum();
+
+        // The square 0..=2 minus the corners below x + y = 1 and above x + y = 3.
+        assert!((area - 3.0).abs() < 1e-12, "{area}");
+    }
+}
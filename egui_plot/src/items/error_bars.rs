@@ -0,0 +1,321 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+use emath::Vec2;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::data::PlotPoints;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+impl<'a> ErrorBars<'a> {
+    /// Error bars around the given points. Add the errors with
+    /// [`Self::x_errors`], [`Self::y_errors`] and their asymmetric variants.
+    pub fn new(name: impl Into<String>, series: impl Into<PlotPoints<'a>>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            series: series.into(),
+            x_errors: Vec::new(),
+            y_errors: Vec::new(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            cap_width: 6.0,
+        }
+    }
+
+    /// Symmetric errors along X, one per point: the bar spans
+    /// `x - error..=x + error`.
+    #[inline]
+    pub fn x_errors(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.x_errors = errors.into_iter().map(|error| (error, error)).collect();
+        self
+    }
+
+    /// Asymmetric errors along X, one `(below, above)` pair per point: the
+    /// bar spans `x - below..=x + above`.
+    #[inline]
+    pub fn x_errors_asymmetric(mut self, errors: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.x_errors = errors.into_iter().collect();
+        self
+    }
+
+    /// Symmetric errors along Y, one per point: the bar spans
+    /// `y - error..=y + error`.
+    #[inline]
+    pub fn y_errors(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.y_errors = errors.into_iter().map(|error| (error, error)).collect();
+        self
+    }
+
+    /// Asymmetric errors along Y, one `(below, above)` pair per point: the
+    /// bar spans `y - below..=y + above`.
+    #[inline]
+    pub fn y_errors_asymmetric(mut self, errors: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.y_errors = errors.into_iter().collect();
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will
+    /// be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Width of the caps at the ends of the bars, in ui points. `0.0` for no
+    /// caps. Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: impl Into<f32>) -> Self {
+        self.cap_width = cap_width.into();
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    /// Give the error bars the same name as the [`crate::Points`] or
+    /// [`crate::Line`] they belong to, to share the legend entry.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The extent of the error bars of the point at `index`, as
+    /// `(x range, y range)`.
+    fn extent(&self, index: usize, point: &PlotPoint) -> (RangeInclusive<f64>, RangeInclusive<f64>) {
+        let range = |value: f64, error: Option<&(f64, f64)>| {
+            error.map_or(value..=value, |(below, above)| value - below..=value + above)
+        };
+        (
+            range(point.x, self.x_errors.get(index)),
+            range(point.y, self.y_errors.get(index)),
+        )
+    }
+}
+
+/// Error bars (whiskers with caps) around a set of points.
+///
+/// Draw these together with [`crate::Points`] or a [`crate::Line`] for the
+/// values themselves. Points without a matching error get no bar.
+pub struct ErrorBars<'a> {
+    base: PlotItemBase,
+
+    pub(crate) series: PlotPoints<'a>,
+
+    /// `(below, above)` per point.
+    pub(crate) x_errors: Vec<(f64, f64)>,
+    pub(crate) y_errors: Vec<(f64, f64)>,
+
+    pub(crate) stroke: Stroke,
+    pub(crate) cap_width: f32,
+}
+
+impl PlotItem for ErrorBars<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        let mut half_cap = 0.5 * self.cap_width;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+            half_cap *= 2f32.sqrt();
+        }
+
+        for (index, point) in self.series.points().iter().enumerate() {
+            let (x_range, y_range) = self.extent(index, point);
+            let position = |x: f64, y: f64| transform.position_from_point(&PlotPoint::new(x, y));
+
+            if index < self.x_errors.len() {
+                let left = position(*x_range.start(), point.y);
+                let right = position(*x_range.end(), point.y);
+                shapes.push(Shape::line_segment([left, right], stroke));
+                push_caps(shapes, [left, right], vec2(0.0, half_cap), stroke);
+            }
+            if index < self.y_errors.len() {
+                let bottom = position(point.x, *y_range.start());
+                let top = position(point.x, *y_range.end());
+                shapes.push(Shape::line_segment([bottom, top], stroke));
+                push_caps(shapes, [bottom, top], vec2(half_cap, 0.0), stroke);
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (index, point) in self.series.points().iter().enumerate() {
+            let (x_range, y_range) = self.extent(index, point);
+            bounds.extend_with(&PlotPoint::new(*x_range.start(), *y_range.start()));
+            bounds.extend_with(&PlotPoint::new(*x_range.end(), *y_range.end()));
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// Caps across both ends of a bar, reaching `half_cap` to each side.
+fn push_caps(shapes: &mut Vec<Shape>, ends: [Pos2; 2], half_cap: Vec2, stroke: Stroke) {
+    if half_cap == Vec2::ZERO {
+        return;
+    }
+    for end in ends {
+        shapes.push(Shape::line_segment([end - half_cap, end + half_cap], stroke));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_include_errors() {
+        let bars = ErrorBars::new("errors", vec![[0.0, 1.0], [2.0, 3.0]])
+            .y_errors([0.5, 1.0])
+            .x_errors_asymmetric([(0.25, 0.0)]);
+        let bounds = bars.bounds();
+        assert_eq!(bounds.min, [-0.25, 0.5]);
+        assert_eq!(bounds.max, [2.0, 4.0]);
+    }
+}
@@ -0,0 +1,379 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Id;
+use egui::Mesh;
+use egui::Shape;
+use egui::TextStyle;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::pos2;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::rulers_and_tooltip_at_value;
+use crate::label::LabelFormatter;
+
+/// Space between the lane labels and the left edge of the plot, in ui points.
+const LABEL_PADDING: f32 = 4.0;
+
+/// Short vertical ticks at given x positions on horizontal lanes, e.g. a
+/// spike raster or a timeline of log events.
+///
+/// Lane `i` is centered on `y = i`. Only the ticks in view are drawn, at
+/// most one per pixel column and lane, so lanes with hundreds of thousands
+/// of events stay cheap.
+pub struct Events {
+    base: PlotItemBase,
+
+    /// Label and sorted event positions of each lane.
+    pub(crate) lanes: Vec<(String, Vec<f64>)>,
+
+    /// Color of the ticks. `Color32::TRANSPARENT` means that it will be picked
+    /// automatically.
+    pub(crate) color: Color32,
+
+    /// Width of the ticks, in ui points.
+    pub(crate) width: f32,
+
+    /// Height of the ticks, in plot coordinates.
+    pub(crate) tick_height: f64,
+
+    pub(crate) show_labels: bool,
+}
+
+impl Events {
+    /// An item without lanes. Add them with [`Self::lane`].
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            lanes: Vec::new(),
+            color: Color32::TRANSPARENT,
+            width: 1.0,
+            tick_height: 0.8,
+            show_labels: true,
+        }
+    }
+
+    /// Add a lane with events at the given x positions, in any order.
+    /// Non-finite positions are ignored.
+    #[inline]
+    pub fn lane(mut self, label: impl Into<String>, xs: impl IntoIterator<Item = f64>) -> Self {
+        let mut xs: Vec<f64> = xs.into_iter().filter(|x| x.is_finite()).collect();
+        xs.sort_by(f64::total_cmp);
+        self.lanes.push((label.into(), xs));
+        self
+    }
+
+    /// Color of the ticks. Default is `Color32::TRANSPARENT` which means a
+    /// color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Width of the ticks, in ui points. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Height of the ticks, in plot coordinates, where lanes are one unit
+    /// apart. Default: `0.8`.
+    #[inline]
+    pub fn tick_height(mut self, height: f64) -> Self {
+        self.tick_height = height;
+        self
+    }
+
+    /// Whether to write the label of each lane at the left edge of the plot.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The screen rectangle of a tick.
+    fn tick_rect(&self, transform: &PlotTransform, lane: usize, x: f64, width: f32) -> Rect {
+        let y = lane as f64;
+        let top = transform.position_from_point(&PlotPoint::new(x, y + 0.5 * self.tick_height));
+        let bottom = transform.position_from_point(&PlotPoint::new(x, y - 0.5 * self.tick_height));
+        Rect::from_two_pos(top, bottom).expand2(vec2(0.5 * width, 0.0))
+    }
+
+    /// The lane and the position in it of the event at `index`, counting
+    /// through all lanes in order.
+    fn locate(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (lane, (_, xs)) in self.lanes.iter().enumerate() {
+            if index < xs.len() {
+                return Some((lane, index));
+            }
+            index -= xs.len();
+        }
+        None
+    }
+}
+
+/// The range of indices of the sorted `xs` within `range`.
+fn visible(xs: &[f64], range: &RangeInclusive<f64>) -> std::ops::Range<usize> {
+    let start = xs.partition_point(|x| x < range.start());
+    let end = xs.partition_point(|x| x <= range.end());
+    start..end.max(start)
+}
+
+impl PlotItem for Events {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut width = self.width;
+        if self.base.highlight {
+            width *= 2.0;
+        }
+        let range = transform.bounds().range_x();
+        let (min, max) = (range.start().min(*range.end()), range.start().max(*range.end()));
+
+        let mut mesh = Mesh::default();
+        for (lane, (_, xs)) in self.lanes.iter().enumerate() {
+            let mut last_column = None;
+            for &x in &xs[visible(xs, &(min..=max))] {
+                let rect = self.tick_rect(transform, lane, x, width);
+                let column = rect.center().x.round();
+                if last_column == Some(column) {
+                    continue;
+                }
+                last_column = Some(column);
+                mesh.add_colored_rect(rect, self.color);
+            }
+        }
+        shapes.push(Shape::Mesh(Arc::new(mesh)));
+
+        if self.show_labels {
+            let font_id = TextStyle::Small.resolve(ui.style());
+            let text_color = ui.visuals().text_color();
+            let left = transform.frame().left() + LABEL_PADDING;
+            ui.fonts_mut(|f| {
+                for (lane, (label, _)) in self.lanes.iter().enumerate() {
+                    let top = transform.position_from_point_y(lane as f64 + 0.5 * self.tick_height);
+                    shapes.push(Shape::text(
+                        f,
+                        pos2(left, top),
+                        Align2::LEFT_BOTTOM,
+                        label,
+                        font_id.clone(),
+                        text_color,
+                    ));
+                }
+            });
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (lane, (_, xs)) in self.lanes.iter().enumerate() {
+            let y = lane as f64;
+            if let (Some(&first), Some(&last)) = (xs.first(), xs.last()) {
+                bounds.extend_with(&PlotPoint::new(first, y - 0.5 * self.tick_height));
+                bounds.extend_with(&PlotPoint::new(last, y + 0.5 * self.tick_height));
+            }
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let x = transform.value_from_position(point).x;
+        let mut offset = 0;
+        let mut closest: Option<ClosestElem> = None;
+        for (lane, (_, xs)) in self.lanes.iter().enumerate() {
+            // Only the events on either side of the pointer can be closest.
+            let i = xs.partition_point(|&v| v < x);
+            for index in [i.checked_sub(1), Some(i)].into_iter().flatten() {
+                let Some(&event) = xs.get(index) else {
+                    continue;
+                };
+                let dist_sq = self.tick_rect(transform, lane, event, 0.0).distance_sq_to_pos(point);
+                if closest.as_ref().is_none_or(|c| dist_sq < c.dist_sq) {
+                    closest = Some(ClosestElem {
+                        index: offset + index,
+                        dist_sq,
+                    });
+                }
+            }
+            offset += xs.len();
+        }
+        closest
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let Some((lane, index)) = self.locate(elem.index) else {
+            return;
+        };
+        let (label, xs) = &self.lanes[lane];
+        let x = xs[index];
+
+        shapes.push(Shape::rect_filled(
+            self.tick_rect(plot.transform, lane, x, 2.0 * self.width),
+            0.0,
+            self.color,
+        ));
+        let value = PlotPoint::new(x, lane as f64);
+        rulers_and_tooltip_at_value(plot_area_response, value, label, plot, cursors, label_formatter);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes_are_sorted_and_indexed_in_order() {
+        let events = Events::new("spikes")
+            .lane("a", [3.0, 1.0, f64::NAN, 2.0])
+            .lane("b", [0.5]);
+        assert_eq!(events.lanes[0].1, [1.0, 2.0, 3.0]);
+        assert_eq!(events.locate(2), Some((0, 2)));
+        assert_eq!(events.locate(3), Some((1, 0)));
+        assert_eq!(events.locate(4), None);
+
+        assert_eq!(visible(&events.lanes[0].1, &(1.5..=3.0)), 1..3);
+    }
+}
@@ -23,6 +23,10 @@ use crate::items::PlotItemBase;
 /// Takes x-coordinates and corresponding `y_min` and `y_max` values, and fills
 /// the area between them. Useful for visualizing confidence intervals, ranges,
 /// and uncertainty bands.
+///
+/// The lines may cross: the fill is split at the crossings, and the parts
+/// where the "lower" line is above the "upper" one can get their own color
+/// with [`Self::fill_color_below`].
 pub struct FilledArea {
     base: PlotItemBase,
 
@@ -35,6 +39,9 @@ pub struct FilledArea {
     /// Fill color for the area
     fill_color: Color32,
 
+    /// Fill color where the upper line is below the lower one, if different.
+    fill_color_below: Option<Color32>,
+
     /// Optional stroke for the boundaries
     stroke: Option<Stroke>,
 }
@@ -73,10 +80,46 @@ impl FilledArea {
             lower_line,
             upper_line,
             fill_color: Color32::from_gray(128).linear_multiply(DEFAULT_FILL_ALPHA),
+            fill_color_below: None,
             stroke: None,
         }
     }
 
+    /// Create a new filled area between two series, which may have different
+    /// x-coordinates.
+    ///
+    /// Both series must be sorted by x. They are resampled by linear
+    /// interpolation onto the union of their x-coordinates, and the area is
+    /// only filled where both are defined. Function-generated
+    /// [`PlotPoints`] are not supported and produce an empty area.
+    pub fn between<'a>(
+        name: impl Into<String>,
+        lower: impl Into<PlotPoints<'a>>,
+        upper: impl Into<PlotPoints<'a>>,
+    ) -> Self {
+        let (lower, upper) = (lower.into(), upper.into());
+        let (lower, upper) = (lower.points(), upper.points());
+
+        let (Some(first), Some(last)) = (
+            lower.first().zip(upper.first()).map(|(a, b)| a.x.max(b.x)),
+            lower.last().zip(upper.last()).map(|(a, b)| a.x.min(b.x)),
+        ) else {
+            return Self::new(name, &[], &[], &[]);
+        };
+        let mut xs: Vec<f64> = lower
+            .iter()
+            .chain(upper)
+            .map(|point| point.x)
+            .filter(|x| (first..=last).contains(x))
+            .collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup();
+
+        let ys_min: Vec<f64> = xs.iter().map(|&x| interpolate(lower, x)).collect();
+        let ys_max: Vec<f64> = xs.iter().map(|&x| interpolate(upper, x)).collect();
+        Self::new(name, &xs, &ys_min, &ys_max)
+    }
+
     /// Set the fill color for the area.
     #[inline]
     pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
@@ -84,6 +127,15 @@ impl FilledArea {
         self
     }
 
+    /// Set the fill color where the upper line is below the lower one, e.g.
+    /// to shade gains and losses differently. Default: the same as
+    /// [`Self::fill_color`].
+    #[inline]
+    pub fn fill_color_below(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color_below = Some(color.into());
+        self
+    }
+
     /// Add a stroke around the boundaries of the filled area.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -115,6 +167,56 @@ impl FilledArea {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
     /// Sets the id of this plot item.
     #[inline]
     pub fn id(mut self, id: impl Into<Id>) -> Self {
@@ -130,50 +232,61 @@ impl PlotItem for FilledArea {
         }
 
         let n = self.lower_line.len();
+        let upper: Vec<Pos2> = self
+            .upper_line
+            .iter()
+            .map(|point| transform.position_from_point(point))
+            .collect();
+        let lower: Vec<Pos2> = self
+            .lower_line
+            .iter()
+            .map(|point| transform.position_from_point(point))
+            .collect();
 
         // Create a mesh for the filled area
         let mut mesh = Mesh::default();
         mesh.reserve_triangles((n - 1) * 2);
         mesh.reserve_vertices(n * 2);
 
-        // Add vertices for upper and lower lines
-        for point in &self.upper_line {
-            let pos = transform.position_from_point(point);
-            mesh.colored_vertex(pos, self.fill_color);
-        }
-        for point in &self.lower_line {
-            let pos = transform.position_from_point(point);
-            mesh.colored_vertex(pos, self.fill_color);
-        }
+        // Screen y grows downwards, so the upper line is above where its y is smaller.
+        let color = |upper: Pos2, lower: Pos2| {
+            if upper.y > lower.y {
+                self.fill_color_below.unwrap_or(self.fill_color)
+            } else {
+                self.fill_color
+            }
+        };
+        let mut add_triangle = |points: [Pos2; 3], color: Color32| {
+            let i = mesh.vertices.len() as u32;
+            for point in points {
+                mesh.colored_vertex(point, color);
+            }
+            mesh.add_triangle(i, i + 1, i + 2);
+        };
 
         // Create triangles connecting upper and lower lines
         for i in 0..(n - 1) {
-            // Each quad is split into two triangles
-            // Triangle 1: upper[i], lower[i], upper[i+1]
-            mesh.add_triangle(i as u32, (n + i) as u32, (i + 1) as u32);
-            // Triangle 2: lower[i], lower[i+1], upper[i+1]
-            mesh.add_triangle((n + i) as u32, (n + i + 1) as u32, (i + 1) as u32);
+            let (u0, l0, u1, l1) = (upper[i], lower[i], upper[i + 1], lower[i + 1]);
+            let (d0, d1) = (u0.y - l0.y, u1.y - l1.y);
+            if d0 * d1 < 0.0 {
+                // The lines cross: one triangle on each side of the crossing.
+                let crossing = u0.lerp(u1, d0 / (d0 - d1));
+                add_triangle([u0, l0, crossing], color(u0, l0));
+                add_triangle([crossing, u1, l1], color(u1, l1));
+            } else {
+                // Each quad is split into two triangles
+                let color = color(u0 + (u1 - u0) * 0.5, l0 + (l1 - l0) * 0.5);
+                add_triangle([u0, l0, u1], color);
+                add_triangle([l0, l1, u1], color);
+            }
         }
 
         shapes.push(Shape::Mesh(Arc::new(mesh)));
 
         // Draw optional stroke around boundaries
         if let Some(stroke) = self.stroke {
-            // Upper boundary line
-            let upper_points: Vec<Pos2> = self
-                .upper_line
-                .iter()
-                .map(|point| transform.position_from_point(point))
-                .collect();
-            shapes.push(Shape::line(upper_points, stroke));
-
-            // Lower boundary line
-            let lower_points: Vec<Pos2> = self
-                .lower_line
-                .iter()
-                .map(|point| transform.position_from_point(point))
-                .collect();
-            shapes.push(Shape::line(lower_points, stroke));
+            shapes.push(Shape::line(upper, stroke));
+            shapes.push(Shape::line(lower, stroke));
         }
     }
 
@@ -205,3 +318,26 @@ impl PlotItem for FilledArea {
         &mut self.base
     }
 }
+
+/// The y value of a line sorted by x at `x`, interpolated linearly.
+fn interpolate(line: &[PlotPoint], x: f64) -> f64 {
+    let i = line.partition_point(|point| point.x < x);
+    match (i.checked_sub(1).map(|i| line[i]), line.get(i)) {
+        (Some(a), Some(b)) if b.x > a.x => a.y + (b.y - a.y) * (x - a.x) / (b.x - a.x),
+        (_, Some(b)) => b.y,
+        (Some(a), None) => a.y,
+        (None, None) => f64::NAN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn between_resamples_onto_common_xs() {
+        let area = FilledArea::between("band", vec![[0.0, 0.0], [2.0, 2.0]], vec![[1.0, 3.0], [3.0, 3.0]]);
+        assert_eq!(area.lower_line, [PlotPoint::new(1.0, 1.0), PlotPoint::new(2.0, 2.0)]);
+        assert_eq!(area.upper_line, [PlotPoint::new(1.0, 3.0), PlotPoint::new(2.0, 3.0)]);
+    }
+}
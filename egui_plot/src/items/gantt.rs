@@ -0,0 +1,421 @@
+use std::ops::RangeInclusive;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::pos2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::highlighted_color;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+
+/// Space between the lane labels and the left edge of the plot, and between
+/// the bar labels and the start of their bars, in ui points.
+const LABEL_PADDING: f32 = 4.0;
+
+/// One bar of a [`Gantt`] chart: a span of x on a lane.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GanttBar {
+    /// The lane the bar is on.
+    pub lane: String,
+
+    /// Start of the bar on the X axis.
+    pub start: f64,
+
+    /// End of the bar on the X axis.
+    pub end: f64,
+
+    /// Text written in the bar, if it fits, and shown on hover.
+    pub label: String,
+
+    /// Fill color. `Color32::TRANSPARENT` means the color of the chart.
+    pub color: Color32,
+}
+
+impl GanttBar {
+    /// A bar from `start` to `end` on the given lane.
+    pub fn new(lane: impl Into<String>, start: f64, end: f64) -> Self {
+        Self {
+            lane: lane.into(),
+            start,
+            end,
+            label: String::new(),
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Text written in the bar, if it fits, and shown on hover.
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Fill color of this bar. Default: the color of the chart.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// A Gantt chart: horizontal bars on named lanes, e.g. for schedules or
+/// trace timelines.
+///
+/// The lanes are stacked from the top in the order they first appear among
+/// the bars, one unit apart on the Y axis.
+pub struct Gantt {
+    base: PlotItemBase,
+    pub(crate) bars: Vec<GanttBar>,
+
+    /// The lanes, from the top.
+    lanes: Vec<String>,
+
+    /// Default fill color. `Color32::TRANSPARENT` means that it will be
+    /// picked automatically.
+    pub(crate) color: Color32,
+
+    /// Height of the bars, in plot coordinates.
+    bar_height: f64,
+
+    corner_radius: f32,
+    show_lane_labels: bool,
+}
+
+impl Gantt {
+    /// A chart of the given bars.
+    pub fn new(name: impl Into<String>, bars: Vec<GanttBar>) -> Self {
+        let mut lanes: Vec<String> = Vec::new();
+        for bar in &bars {
+            if !lanes.contains(&bar.lane) {
+                lanes.push(bar.lane.clone());
+            }
+        }
+        Self {
+            base: PlotItemBase::new(name.into()),
+            bars,
+            lanes,
+            color: Color32::TRANSPARENT,
+            bar_height: 0.6,
+            corner_radius: 3.0,
+            show_lane_labels: true,
+        }
+    }
+
+    /// Fill color of the bars without a color of their own. Default is
+    /// `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Height of the bars, in plot coordinates, where lanes are one unit
+    /// apart. Default: `0.6`.
+    #[inline]
+    pub fn bar_height(mut self, height: f64) -> Self {
+        self.bar_height = height;
+        self
+    }
+
+    /// Rounding of the corners of the bars, in ui points. Default: `3.0`.
+    #[inline]
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Whether to write the name of each lane at the left edge of the plot.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_lane_labels(mut self, show: bool) -> Self {
+        self.show_lane_labels = show;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The Y value of the center of a lane, the first one being at the top.
+    fn lane_y(&self, lane: &str) -> f64 {
+        let index = self.lanes.iter().position(|l| l == lane).unwrap_or_default();
+        (self.lanes.len() - 1 - index) as f64
+    }
+
+    fn bar_rect(&self, bar: &GanttBar, transform: &PlotTransform) -> Rect {
+        let y = self.lane_y(&bar.lane);
+        let half = 0.5 * self.bar_height;
+        Rect::from_two_pos(
+            transform.position_from_point(&PlotPoint::new(bar.start, y + half)),
+            transform.position_from_point(&PlotPoint::new(bar.end, y - half)),
+        )
+    }
+
+    fn bar_color(&self, bar: &GanttBar) -> Color32 {
+        if bar.color == Color32::TRANSPARENT {
+            self.color
+        } else {
+            bar.color
+        }
+    }
+
+    fn add_bar_shapes(&self, bar: &GanttBar, transform: &PlotTransform, highlighted: bool, shapes: &mut Vec<Shape>) {
+        let fill = self.bar_color(bar);
+        let (stroke, fill) = if highlighted {
+            highlighted_color(Stroke::new(1.0, fill), fill)
+        } else {
+            (Stroke::NONE, fill)
+        };
+        shapes.push(Shape::rect_filled(
+            self.bar_rect(bar, transform),
+            self.corner_radius,
+            fill,
+        ));
+        if stroke != Stroke::NONE {
+            shapes.push(Shape::rect_stroke(
+                self.bar_rect(bar, transform),
+                self.corner_radius,
+                stroke,
+                egui::StrokeKind::Outside,
+            ));
+        }
+    }
+}
+
+impl PlotItem for Gantt {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for bar in &self.bars {
+            self.add_bar_shapes(bar, transform, self.base.highlight, shapes);
+        }
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.visuals().text_color();
+        ui.fonts_mut(|f| {
+            for bar in &self.bars {
+                if bar.label.is_empty() {
+                    continue;
+                }
+                let rect = self.bar_rect(bar, transform);
+                let galley = f.layout_no_wrap(bar.label.clone(), font_id.clone(), text_color);
+                if galley.size().x + 2.0 * LABEL_PADDING <= rect.width() {
+                    let pos = pos2(rect.left() + LABEL_PADDING, rect.center().y - 0.5 * galley.size().y);
+                    shapes.push(Shape::galley(pos, galley, text_color));
+                }
+            }
+
+            if self.show_lane_labels {
+                let left = transform.frame().left() + LABEL_PADDING;
+                for lane in &self.lanes {
+                    let top = transform.position_from_point_y(self.lane_y(lane) + 0.5 * self.bar_height);
+                    shapes.push(Shape::text(
+                        f,
+                        pos2(left, top),
+                        Align2::LEFT_BOTTOM,
+                        lane,
+                        font_id.clone(),
+                        text_color,
+                    ));
+                }
+            }
+        });
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let half = 0.5 * self.bar_height;
+        for bar in &self.bars {
+            let y = self.lane_y(&bar.lane);
+            bounds.extend_with(&PlotPoint::new(bar.start, y - half));
+            bounds.extend_with(&PlotPoint::new(bar.end, y + half));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.bars
+            .iter()
+            .enumerate()
+            .map(|(index, bar)| ClosestElem {
+                index,
+                dist_sq: self.bar_rect(bar, transform).distance_sq_to_pos(point),
+            })
+            .min_by(|a, b| a.dist_sq.total_cmp(&b.dist_sq))
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let bar = &self.bars[elem.index];
+        self.add_bar_shapes(bar, plot.transform, true, shapes);
+
+        let mut text = format!("{}\n", bar.lane);
+        if !bar.label.is_empty() {
+            text.push_str(&format!("{}\n", bar.label));
+        }
+        text.push_str(&format!("{} – {}", bar.start, bar.end));
+        show_tooltip(plot_area_response, text);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes_stack_from_the_top_in_order_of_appearance() {
+        let gantt = Gantt::new(
+            "schedule",
+            vec![
+                GanttBar::new("design", 0.0, 2.0),
+                GanttBar::new("build", 2.0, 5.0),
+                GanttBar::new("design", 5.0, 6.0),
+            ],
+        );
+        assert_eq!(gantt.lanes, ["design", "build"]);
+        assert_eq!(gantt.lane_y("design"), 1.0);
+        assert_eq!(gantt.lane_y("build"), 0.0);
+
+        let bounds = gantt.bounds();
+        assert_eq!(bounds.min(), [0.0, -0.3]);
+        assert_eq!(bounds.max(), [6.0, 1.3]);
+    }
+}
@@ -0,0 +1,123 @@
+use egui::Color32;
+
+use crate::items::Bar;
+use crate::items::BarChart;
+
+/// A grouped (clustered) bar chart: several series sharing the same
+/// categories, with their bars side by side within each category.
+///
+/// Each series becomes its own [`BarChart`], with its own color and legend
+/// entry.
+///
+/// Add it with [`crate::PlotUi::grouped_bars`].
+pub struct GroupedBars {
+    pub(crate) categories: Vec<f64>,
+    pub(crate) series: Vec<(String, Vec<f64>)>,
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) group_width: f64,
+    pub(crate) gap: f64,
+    pub(crate) horizontal: bool,
+}
+
+impl GroupedBars {
+    /// A group of bars at each of the given category positions (x positions,
+    /// or y positions when [`Self::horizontal`]). Add series with
+    /// [`Self::series`].
+    pub fn new(categories: impl Into<Vec<f64>>) -> Self {
+        Self {
+            categories: categories.into(),
+            series: Vec::new(),
+            colors: Vec::new(),
+            group_width: 0.8,
+            gap: 0.0,
+            horizontal: false,
+        }
+    }
+
+    /// Add a series, with one value per category.
+    ///
+    /// Missing values get no bar, extra ones are ignored.
+    #[inline]
+    pub fn series(mut self, name: impl Into<String>, values: impl Into<Vec<f64>>) -> Self {
+        self.series.push((name.into(), values.into()));
+        self
+    }
+
+    /// Set the color of each series. Series without a color get an
+    /// auto-assigned one.
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Width taken by each group of bars, in plot coordinates. Default: `0.8`,
+    /// which leaves some room between categories one unit apart.
+    #[inline]
+    pub fn group_width(mut self, width: f64) -> Self {
+        self.group_width = width;
+        self
+    }
+
+    /// Fraction of each bar's slot left empty between neighboring bars, from
+    /// `0.0` to `1.0`. Default: `0.0`.
+    #[inline]
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Lay the bars out horizontally: categories on the Y axis, values on the
+    /// X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Build one chart per series, with the colors filled in by `auto_color`
+    /// where missing.
+    pub(crate) fn into_charts(self, mut auto_color: impl FnMut() -> Color32) -> Vec<BarChart> {
+        let count = self.series.len();
+        let slot = self.group_width / count.max(1) as f64;
+        self.series
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, values))| {
+                let offset = (index as f64 - 0.5 * (count as f64 - 1.0)) * slot;
+                let bars = self
+                    .categories
+                    .iter()
+                    .zip(&values)
+                    .map(|(&category, &value)| {
+                        let bar = Bar::new(category + offset, value).width(slot * (1.0 - self.gap));
+                        if self.horizontal { bar.horizontal() } else { bar }
+                    })
+                    .collect();
+                let color = self
+                    .colors
+                    .get(index)
+                    .copied()
+                    .filter(|color| *color != Color32::TRANSPARENT)
+                    .unwrap_or_else(&mut auto_color);
+                BarChart::new(name, bars).color(color)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_sit_side_by_side() {
+        let charts = GroupedBars::new([0.0, 1.0])
+            .series("a", [1.0, 2.0])
+            .series("b", [3.0, 4.0])
+            .into_charts(|| Color32::RED);
+        assert_eq!(charts[0].bars[1].argument, 0.8);
+        assert_eq!(charts[1].bars[0].argument, 0.2);
+        assert_eq!(charts[1].bars[0].bar_width, 0.4);
+    }
+}
@@ -1,6 +1,10 @@
+use std::hash::Hash as _;
+use std::hash::Hasher as _;
 use std::ops::RangeInclusive;
 
 use egui::Color32;
+use egui::ColorImage;
+use egui::Id;
 use egui::Mesh;
 use egui::NumExt as _;
 use egui::Pos2;
@@ -8,11 +12,15 @@ use egui::Rect;
 use egui::Rgba;
 use egui::Shape;
 use egui::TextStyle;
+use egui::TextureHandle;
+use egui::TextureOptions;
 use egui::Ui;
 use egui::Vec2;
 use egui::WidgetText;
 use emath::Float as _;
+use emath::pos2;
 
+use crate::axis::Axis;
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
@@ -28,7 +36,7 @@ use crate::label::LabelFormatter;
 /// Default resolution for heatmap color palette
 pub const DEFAULT_RESOLUTION: usize = 128;
 
-/// A heatmap.
+/// A heatmap, drawn as one texture with a pixel per tile.
 pub struct Heatmap {
     base: PlotItemBase,
 
@@ -115,7 +123,14 @@ impl Heatmap {
     ///
     /// If parameters are invalid (e.g., `cols` is zero, `values` is empty, or
     /// `values.len()` is not divisible by `cols`), an empty heatmap is created.
-    pub fn new(values: Vec<f64>, cols: usize) -> Self {
+    ///
+    /// Hovering a tile shows its value in a tooltip, formatted with
+    /// [`Self::formatter`].
+    ///
+    /// A 2D array of the `ndarray` crate in standard layout is already row
+    /// by row, so pass the slice from `array.as_slice()` and `array.ncols()`.
+    pub fn new(values: impl Into<Vec<f64>>, cols: usize) -> Self {
+        let values = values.into();
         // Handle invalid parameters by creating an empty heatmap
         if cols == 0 || values.is_empty() || (values.len() % cols) != 0 {
             return Self::empty();
@@ -194,7 +209,7 @@ impl Heatmap {
 
     /// Interpolate linear gradient with given resolution from an arbitrary
     /// number of base colors.
-    fn linear_gradient_from_base_colors(base_colors: &[Color32], resolution: usize) -> Vec<Color32> {
+    pub(crate) fn linear_gradient_from_base_colors(base_colors: &[Color32], resolution: usize) -> Vec<Color32> {
         let mut interpolated = vec![Color32::TRANSPARENT; resolution];
         if base_colors.is_empty() || resolution == 0 {
             return interpolated;
@@ -302,6 +317,16 @@ impl Heatmap {
         self
     }
 
+    /// Stretch the heatmap over the given ranges in plot coordinates, e.g. the
+    /// extents of the grid the values were sampled on.
+    ///
+    /// This sets both [`Self::at`] and [`Self::size`].
+    #[inline]
+    pub fn extent(self, x: RangeInclusive<f64>, y: RangeInclusive<f64>) -> Self {
+        self.at(PlotPoint::new(*x.start(), *y.start()))
+            .size((x.end() - x.start()) as f32, (y.end() - y.start()) as f32)
+    }
+
     /// Highlight all plot elements.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
@@ -310,23 +335,140 @@ impl Heatmap {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
     fn push_shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let mut mesh = Mesh::default();
-        let mut labels: Vec<Shape> = Vec::new();
-        for i in 0..self.values.len() {
-            let (rect, color, text) = self.tile_view_info(ui, transform, i);
-            mesh.add_colored_rect(rect, color);
-            if self.show_labels {
-                labels.push(text);
+        if self.values.is_empty() {
+            return;
+        }
+        // A texture is stretched linearly between its corners, which doesn't
+        // follow a logarithmic axis, so then each tile is drawn on its own.
+        let max_side = ui.ctx().input(|i| i.max_texture_side);
+        let textured = self.cols <= max_side
+            && self.rows <= max_side
+            && transform.axis_transform(Axis::X).is_none()
+            && transform.axis_transform(Axis::Y).is_none();
+        if textured {
+            self.push_texture(ui, transform, shapes);
+        } else {
+            let mut mesh = Mesh::default();
+            for index in 0..self.values.len() {
+                mesh.add_colored_rect(self.tile_rect(transform, index), self.tile_color(index));
             }
+            shapes.push(Shape::mesh(mesh));
         }
-        shapes.push(Shape::mesh(mesh));
         if self.show_labels {
-            shapes.extend(labels);
+            shapes.extend((0..self.values.len()).filter_map(|index| self.tile_view_info(ui, transform, index).2));
         }
     }
 
-    fn tile_view_info(&self, ui: &Ui, transform: &PlotTransform, index: usize) -> (Rect, Color32, Shape) {
+    /// Draw all tiles as one image, with a texture of one pixel per tile
+    /// that is only uploaded again when the colors of the tiles change.
+    fn push_texture(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        // The first row of the texture is the top one, which is the last row
+        // of the heatmap.
+        let pixels: Vec<Color32> = (0..self.rows)
+            .rev()
+            .flat_map(|row| (row * self.cols..(row + 1) * self.cols).map(|index| self.tile_color(index)))
+            .collect();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.cols.hash(&mut hasher);
+        pixels.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let id = self.base.id.with("heatmap_texture");
+        let texture = match ui.data(|d| d.get_temp::<(u64, TextureHandle)>(id)) {
+            Some((cached_key, texture)) if cached_key == key => texture,
+            _ => {
+                let image = ColorImage::new([self.cols, self.rows], pixels);
+                let texture = ui.ctx().load_texture(self.name.clone(), image, TextureOptions::NEAREST);
+                ui.data_mut(|d| d.insert_temp(id, (key, texture.clone())));
+                texture
+            }
+        };
+
+        // The corners of the first and the last tile, which are swapped on
+        // screen along inverted axes, and the texture with them.
+        let first = transform.position_from_point(&self.pos);
+        let last = transform.position_from_point(&PlotPoint {
+            x: self.pos.x + self.tile_size.x as f64 * self.cols as f64,
+            y: self.pos.y + self.tile_size.y as f64 * self.rows as f64,
+        });
+        let (u_min, u_max) = if first.x <= last.x { (0.0, 1.0) } else { (1.0, 0.0) };
+        let (v_min, v_max) = if last.y <= first.y { (0.0, 1.0) } else { (1.0, 0.0) };
+        shapes.push(Shape::image(
+            texture.id(),
+            Rect::from_two_pos(first, last),
+            Rect::from_min_max(pos2(u_min, v_min), pos2(u_max, v_max)),
+            Color32::WHITE,
+        ));
+    }
+
+    /// The rect of a tile on screen.
+    fn tile_rect(&self, transform: &PlotTransform, index: usize) -> Rect {
+        let x = index % self.cols;
+        let y = index / self.cols;
+        transform.rect_from_values(
+            &PlotPoint {
+                x: self.pos.x + self.tile_size.x as f64 * x as f64,
+                y: self.pos.y + self.tile_size.y as f64 * y as f64,
+            },
+            &PlotPoint {
+                x: self.pos.x + self.tile_size.x as f64 * (x + 1) as f64,
+                y: self.pos.y + self.tile_size.y as f64 * (y + 1) as f64,
+            },
+        )
+    }
+
+    /// The color of a tile, from the custom mapping or the palette.
+    fn tile_color(&self, index: usize) -> Color32 {
         let v = self.values[index];
 
         // calculate color value
@@ -349,19 +491,18 @@ impl Heatmap {
             let fill = fill.to_opaque().multiply(fill_alpha);
             fill_color = fill.into();
         }
+        fill_color
+    }
+
+    /// The rect and color of a tile, and its label if [`Self::show_labels`].
+    fn tile_view_info(&self, ui: &Ui, transform: &PlotTransform, index: usize) -> (Rect, Color32, Option<Shape>) {
+        let v = self.values[index];
+        let fill_color = self.tile_color(index);
+        let tile_rect = self.tile_rect(transform, index);
+        if !self.show_labels {
+            return (tile_rect, fill_color, None);
+        }
 
-        let x = index % self.cols;
-        let y = index / self.cols;
-        let tile_rect = transform.rect_from_values(
-            &PlotPoint {
-                x: self.pos.x + self.tile_size.x as f64 * x as f64,
-                y: self.pos.y + self.tile_size.y as f64 * y as f64,
-            },
-            &PlotPoint {
-                x: self.pos.x + self.tile_size.x as f64 * (x + 1) as f64,
-                y: self.pos.y + self.tile_size.y as f64 * (y + 1) as f64,
-            },
-        );
         // Text
 
         let text: WidgetText = (self.formatter)(v).into();
@@ -386,7 +527,7 @@ impl Heatmap {
         let text_pos = tile_rect.center() - galley.size() / 2.0;
 
         let text = Shape::galley(text_pos, galley.clone(), Color32::WHITE);
-        (tile_rect, fill_color, text)
+        (tile_rect, fill_color, Some(text))
     }
 }
 
@@ -432,21 +573,7 @@ impl PlotItem for Heatmap {
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         (0..self.values.len())
             .map(|index| {
-                let x = index % self.cols;
-                let y = index / self.cols;
-
-                let tile_rect = transform.rect_from_values(
-                    &PlotPoint {
-                        x: self.pos.x + self.tile_size.x as f64 * x as f64,
-                        y: self.pos.y + self.tile_size.y as f64 * y as f64,
-                    },
-                    &PlotPoint {
-                        x: self.pos.x + self.tile_size.x as f64 * (x + 1) as f64,
-                        y: self.pos.y + self.tile_size.y as f64 * (y + 1) as f64,
-                    },
-                );
-
-                let dist_sq = tile_rect.distance_sq_to_pos(point);
+                let dist_sq = self.tile_rect(transform, index).distance_sq_to_pos(point);
 
                 ClosestElem { index, dist_sq }
             })
@@ -455,7 +582,7 @@ impl PlotItem for Heatmap {
 
     fn on_hover(
         &self,
-        _plot_area_response: &egui::Response,
+        plot_area_response: &egui::Response,
         elem: ClosestElem,
         shapes: &mut Vec<Shape>,
         _cursors: &mut Vec<Cursor>,
@@ -466,8 +593,17 @@ impl PlotItem for Heatmap {
         let mut mesh = Mesh::default();
         mesh.add_colored_rect(rect, color);
         shapes.push(Shape::mesh(mesh));
-        if self.show_labels {
-            shapes.push(text);
+        shapes.extend(text);
+
+        // Only when the pointer is on the tile, not just close to it.
+        if elem.dist_sq == 0.0 {
+            let value = (self.formatter)(self.values[elem.index]);
+            let text = if self.name.is_empty() {
+                value
+            } else {
+                format!("{}\n{value}", self.name)
+            };
+            super::show_tooltip(plot_area_response, text);
         }
     }
 
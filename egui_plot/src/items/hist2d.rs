@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Mesh;
+use egui::Shape;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::colors::BASE_COLORS;
+use crate::data::PlotPoints;
+use crate::items::Heatmap;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Resolution of the color palette.
+const PALETTE_RESOLUTION: usize = 128;
+
+impl<'a> Hist2d<'a> {
+    /// A 2D histogram of the given points.
+    pub fn new(name: impl Into<String>, series: impl Into<PlotPoints<'a>>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            series: series.into(),
+            bins: 64,
+            hexagonal: false,
+            palette: Heatmap::linear_gradient_from_base_colors(&BASE_COLORS, PALETTE_RESOLUTION),
+            log_scale: false,
+        }
+    }
+
+    /// Number of bins across the width of the plot. The bins are square (or
+    /// regular hexagons) on screen, so their count along the height follows
+    /// from the aspect ratio. Default: `64`.
+    #[inline]
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Bin into a hexagonal lattice instead of a square grid. Default:
+    /// `false`.
+    #[inline]
+    pub fn hexagonal(mut self, hexagonal: bool) -> Self {
+        self.hexagonal = hexagonal;
+        self
+    }
+
+    /// Set the color palette by specifying base colors from low to high
+    /// counts.
+    #[inline]
+    pub fn palette(mut self, base_colors: &[Color32]) -> Self {
+        self.palette = Heatmap::linear_gradient_from_base_colors(base_colors, PALETTE_RESOLUTION);
+        self
+    }
+
+    /// Map the logarithm of the counts to the palette, to make sparse bins
+    /// visible next to dense ones. Default: `false`.
+    #[inline]
+    pub fn log_scale(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// Count the visible points per bin, keyed by bin coordinates.
+    ///
+    /// Binning happens on screen, so the bins always cover the visible bounds
+    /// at the same resolution, whatever the zoom level.
+    fn count(&self, frame: &Rect, positions: impl Iterator<Item = Pos2>) -> BTreeMap<(i32, i32), u32> {
+        let size = self.bin_size(frame);
+        let mut counts = BTreeMap::new();
+        for pos in positions.filter(|pos| frame.contains(*pos)) {
+            let offset = pos - frame.min;
+            let key = if self.hexagonal {
+                hex_round(offset.x / size, offset.y / size)
+            } else {
+                ((offset.x / size) as i32, (offset.y / size) as i32)
+            };
+            *counts.entry(key).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Width of a square bin, or circumradius of a hexagonal one, in ui
+    /// points.
+    fn bin_size(&self, frame: &Rect) -> f32 {
+        let size = frame.width() / self.bins as f32;
+        if self.hexagonal { size / 3f32.sqrt() } else { size }
+    }
+}
+
+/// A 2D histogram: the points are counted in a grid (or hexagonal lattice)
+/// of bins, each colored by its count.
+///
+/// Suited to scatter data with too many points to draw them one by one.
+pub struct Hist2d<'a> {
+    base: PlotItemBase,
+
+    pub(crate) series: PlotPoints<'a>,
+
+    /// Number of bins across the width of the plot.
+    bins: usize,
+    hexagonal: bool,
+    palette: Vec<Color32>,
+    log_scale: bool,
+}
+
+impl PlotItem for Hist2d<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let frame = *transform.frame();
+        let counts = self.count(
+            &frame,
+            self.series
+                .points()
+                .iter()
+                .map(|point| transform.position_from_point(point)),
+        );
+        let Some(&max_count) = counts.values().max() else {
+            return;
+        };
+
+        let scale = |count: u32| {
+            if self.log_scale {
+                f64::from(count).ln_1p() / f64::from(max_count).ln_1p()
+            } else {
+                f64::from(count) / f64::from(max_count)
+            }
+        };
+        let size = self.bin_size(&frame);
+        let mut mesh = Mesh::default();
+        for (&(i, j), &count) in &counts {
+            let palette_index = (scale(count) * (self.palette.len() - 1) as f64).round() as usize;
+            let color = self.palette[palette_index];
+            if self.hexagonal {
+                let center = frame.min + hex_center(i, j) * size;
+                let first = mesh.vertices.len() as u32;
+                mesh.colored_vertex(center, color);
+                for corner in 0..6 {
+                    let angle = std::f32::consts::FRAC_PI_3 * (corner as f32 + 0.5);
+                    mesh.colored_vertex(center + size * vec2(angle.cos(), angle.sin()), color);
+                }
+                for corner in 0..6 {
+                    mesh.add_triangle(first, first + 1 + corner, first + 1 + (corner + 1) % 6);
+                }
+            } else {
+                let min = frame.min + vec2(i as f32, j as f32) * size;
+                mesh.add_colored_rect(Rect::from_min_size(min, vec2(size, size)), color);
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.palette[self.palette.len() / 2]
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.series.bounds()
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// Center of the pointy-top hexagon at axial coordinates `(q, r)`, for a unit
+/// circumradius.
+fn hex_center(q: i32, r: i32) -> emath::Vec2 {
+    let (q, r) = (q as f32, r as f32);
+    vec2(3f32.sqrt() * (q + 0.5 * r), 1.5 * r)
+}
+
+/// Axial coordinates of the pointy-top hexagon containing `(x, y)`, for a
+/// unit circumradius.
+fn hex_round(x: f32, y: f32) -> (i32, i32) {
+    let q = 3f32.sqrt() / 3.0 * x - y / 3.0;
+    let r = 2.0 / 3.0 * y;
+    let s = -q - r;
+
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    (rq as i32, rr as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn hex_round_finds_the_nearest_center() {
+        for (q, r) in [(0, 0), (1, 0), (0, 1), (-2, 3)] {
+            let center = hex_center(q, r);
+            assert_eq!(hex_round(center.x, center.y), (q, r));
+            assert_eq!(hex_round(center.x + 0.4, center.y - 0.4), (q, r));
+        }
+    }
+
+    #[test]
+    fn counts_per_square_bin() {
+        let frame = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+        let hist = Hist2d::new("hist", PlotPoints::default()).bins(10);
+        let counts = hist.count(
+            &frame,
+            [pos2(1.0, 1.0), pos2(5.0, 9.0), pos2(15.0, 1.0), pos2(200.0, 0.0)].into_iter(),
+        );
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&(0, 0)], 2);
+        assert_eq!(counts[&(1, 0)], 1);
+    }
+}
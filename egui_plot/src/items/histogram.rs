@@ -0,0 +1,145 @@
+use crate::items::Bar;
+use crate::items::BarChart;
+
+/// How to choose the bins of a [`Histogram`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinRule {
+    /// A fixed number of bins of equal width.
+    Count(usize),
+
+    /// Bins of a fixed width, starting at the smallest sample.
+    Width(f64),
+
+    /// `ceil(sqrt(n))` bins.
+    Sqrt,
+
+    /// `ceil(log2(n)) + 1` bins. Works well for normal-ish data and small
+    /// sample counts.
+    Sturges,
+
+    /// Bin width `3.49 σ n^(-1/3)`. Assumes normal-ish data.
+    Scott,
+
+    /// Bin width `2 IQR n^(-1/3)`. Robust to outliers.
+    FreedmanDiaconis,
+}
+
+/// Counts of samples in contiguous bins.
+///
+/// Turn it into bars with [`Self::into_bar_chart`], or use
+/// [`BarChart::from_samples`] directly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    /// `counts.len() + 1` increasing bin edges. The last bin includes its
+    /// upper edge.
+    pub edges: Vec<f64>,
+
+    /// The number of samples in each bin, or the density if normalized.
+    pub counts: Vec<f64>,
+}
+
+impl Histogram {
+    /// Bin the given samples. Non-finite samples are ignored.
+    pub fn new(samples: &[f64], rule: BinRule) -> Self {
+        let mut samples: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        samples.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (samples.first(), samples.last()) else {
+            return Self {
+                edges: Vec::new(),
+                counts: Vec::new(),
+            };
+        };
+
+        let n = samples.len() as f64;
+        let range = max - min;
+        let width_to_count = |width: f64| {
+            if width > 0.0 {
+                (range / width).ceil().max(1.0) as usize
+            } else {
+                1
+            }
+        };
+        let bins = match rule {
+            BinRule::Count(count) => count.max(1),
+            BinRule::Width(width) => width_to_count(width),
+            BinRule::Sqrt => n.sqrt().ceil() as usize,
+            BinRule::Sturges => n.log2().ceil() as usize + 1,
+            BinRule::Scott => {
+                let mean = samples.iter().sum::<f64>() / n;
+                let std_dev = (samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+                width_to_count(3.49 * std_dev * n.powf(-1.0 / 3.0))
+            }
+            BinRule::FreedmanDiaconis => {
+                let iqr = samples[(0.75 * (n - 1.0)) as usize] - samples[(0.25 * (n - 1.0)) as usize];
+                width_to_count(2.0 * iqr * n.powf(-1.0 / 3.0))
+            }
+        }
+        .min(MAX_BINS);
+
+        // A fixed width keeps its value, even if the last bin sticks out.
+        let width = match rule {
+            BinRule::Width(width) if width > 0.0 && bins < MAX_BINS => width,
+            _ if range > 0.0 => range / bins as f64,
+            _ => 1.0,
+        };
+        let start = if range > 0.0 { min } else { min - 0.5 * width };
+
+        let edges: Vec<f64> = (0..=bins).map(|i| start + width * i as f64).collect();
+        let mut counts = vec![0.0; bins];
+        for sample in samples {
+            let bin = (((sample - start) / width) as usize).min(bins - 1);
+            counts[bin] += 1.0;
+        }
+        Self { edges, counts }
+    }
+
+    /// Normalize the counts such that the total area of the bars is `1.0`,
+    /// i.e. they estimate a probability density.
+    #[inline]
+    pub fn density(mut self) -> Self {
+        let total: f64 = self.counts.iter().sum();
+        if total > 0.0 {
+            for (count, edges) in self.counts.iter_mut().zip(self.edges.windows(2)) {
+                *count /= total * (edges[1] - edges[0]);
+            }
+        }
+        self
+    }
+
+    /// One bar per bin, spanning the whole bin.
+    pub fn into_bar_chart(self, name: impl Into<String>) -> BarChart {
+        let bars = self
+            .counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&count, edges)| Bar::new(0.5 * (edges[0] + edges[1]), count).width(edges[1] - edges[0]))
+            .collect();
+        BarChart::new(name, bars)
+    }
+}
+
+/// Upper limit on the number of bins, against degenerate bin widths.
+const MAX_BINS: usize = 10_000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_and_density() {
+        let histogram = Histogram::new(&[0.0, 0.5, 1.0, 1.5, 2.0, 4.0], BinRule::Count(2));
+        assert_eq!(histogram.edges, [0.0, 2.0, 4.0]);
+        assert_eq!(histogram.counts, [4.0, 2.0]);
+
+        let density = histogram.density();
+        let area: f64 = density.counts.iter().map(|c| c * 2.0).sum();
+        assert!((area - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fixed_width_keeps_its_value() {
+        let histogram = Histogram::new(&[0.0, 1.0, 2.5], BinRule::Width(1.0));
+        assert_eq!(histogram.edges, [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(histogram.counts, [1.0, 1.0, 1.0]);
+    }
+}
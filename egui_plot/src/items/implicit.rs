@@ -0,0 +1,330 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+use emath::pos2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Number of times a grid cell crossed by the curve may be split in four.
+const MAX_DEPTH: u32 = 4;
+
+/// A corner of a grid cell: its screen position and the value there.
+type Corner = (Pos2, f64);
+
+/// The curve where `f(x, y) = 0`, e.g. a conic section or the boundary of a
+/// constraint.
+///
+/// The function is evaluated on a grid over the visible area every frame,
+/// and the curve traced with marching squares. Cells crossed by the curve
+/// are refined down to a fraction of a ui point, so the curve stays smooth
+/// at any zoom. Features smaller than [`Self::resolution`] may be missed.
+pub struct ImplicitCurve<'a> {
+    base: PlotItemBase,
+    function: Box<dyn Fn(f64, f64) -> f64 + 'a>,
+    pub(crate) stroke: Stroke,
+    resolution: f32,
+}
+
+impl<'a> ImplicitCurve<'a> {
+    /// The curve where `function(x, y)` is zero.
+    pub fn new(name: impl Into<String>, function: impl Fn(f64, f64) -> f64 + 'a) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            function: Box::new(function),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            resolution: 16.0,
+        }
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color
+    /// will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Size of the cells of the initial grid, in ui points. Smaller values
+    /// find smaller features of the curve, at the cost of more evaluations.
+    /// Default: `16.0`.
+    #[inline]
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = resolution.max(1.0);
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    fn corner(&self, pos: Pos2, transform: &PlotTransform) -> Corner {
+        let point = transform.value_from_position(pos);
+        (pos, (self.function)(point.x, point.y))
+    }
+
+    /// The segments of the curve over the visible area, on screen.
+    fn segments(&self, transform: &PlotTransform) -> Vec<[Pos2; 2]> {
+        let frame = transform.frame();
+        let cols = (frame.width() / self.resolution).ceil().max(1.0) as usize;
+        let rows = (frame.height() / self.resolution).ceil().max(1.0) as usize;
+        let position = |col: usize, row: usize| {
+            pos2(
+                frame.left() + frame.width() * col as f32 / cols as f32,
+                frame.top() + frame.height() * row as f32 / rows as f32,
+            )
+        };
+
+        let grid: Vec<Corner> = (0..=rows)
+            .flat_map(|row| (0..=cols).map(move |col| (col, row)))
+            .map(|(col, row)| self.corner(position(col, row), transform))
+            .collect();
+        let at = |col: usize, row: usize| grid[row * (cols + 1) + col];
+
+        let mut segments = Vec::new();
+        for row in 0..rows {
+            for col in 0..cols {
+                let cell = [at(col, row), at(col + 1, row), at(col + 1, row + 1), at(col, row + 1)];
+                self.trace(cell, transform, MAX_DEPTH, &mut segments);
+            }
+        }
+        segments
+    }
+
+    /// Trace the curve through a cell given by its corners in order around
+    /// it, splitting the cell while it is crossed.
+    fn trace(&self, cell: [Corner; 4], transform: &PlotTransform, depth: u32, segments: &mut Vec<[Pos2; 2]>) {
+        if !cell.iter().all(|(_, value)| value.is_finite()) {
+            return;
+        }
+        let crossed = cell.iter().any(|(_, value)| *value >= 0.0) && cell.iter().any(|(_, value)| *value < 0.0);
+        if !crossed {
+            return;
+        }
+        if depth == 0 {
+            let center = cell[0].0.lerp(cell[2].0, 0.5);
+            let (_, center_value) = self.corner(center, transform);
+            segments.extend(march(cell, center_value));
+            return;
+        }
+
+        let mid = |a: usize, b: usize| self.corner(cell[a].0.lerp(cell[b].0, 0.5), transform);
+        let [c0, c1, c2, c3] = cell;
+        let (m01, m12, m23, m30, center) = (mid(0, 1), mid(1, 2), mid(2, 3), mid(3, 0), mid(0, 2));
+        for quarter in [
+            [c0, m01, center, m30],
+            [m01, c1, m12, center],
+            [center, m12, c2, m23],
+            [m30, center, m23, c3],
+        ] {
+            self.trace(quarter, transform, depth - 1, segments);
+        }
+    }
+}
+
+/// The segments of the zero level through a cell, by marching squares.
+///
+/// `center_value` decides between the two ways of joining the crossings
+/// when the signs alternate around the cell.
+fn march(cell: [Corner; 4], center_value: f64) -> Vec<[Pos2; 2]> {
+    let crossing = |i: usize| -> Option<Pos2> {
+        let (p, p_value) = cell[i];
+        let (q, q_value) = cell[(i + 1) % 4];
+        ((p_value >= 0.0) != (q_value >= 0.0)).then(|| p.lerp(q, (p_value / (p_value - q_value)) as f32))
+    };
+    let crossings: Vec<Pos2> = (0..4).filter_map(crossing).collect();
+    match crossings.as_slice() {
+        [a, b] => vec![[*a, *b]],
+        [e0, e1, e2, e3] => {
+            if (center_value >= 0.0) == (cell[0].1 >= 0.0) {
+                // The center joins corners 0 and 2, cutting off corners 1 and 3.
+                vec![[*e0, *e1], [*e2, *e3]]
+            } else {
+                vec![[*e3, *e0], [*e1, *e2]]
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl PlotItem for ImplicitCurve<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+        shapes.extend(
+            self.segments(transform)
+                .into_iter()
+                .map(|segment| Shape::line_segment(segment, stroke)),
+        );
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        PlotBounds::NOTHING
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::Rect;
+
+    use super::*;
+
+    #[test]
+    fn segments_lie_on_the_curve() {
+        let circle = ImplicitCurve::new("circle", |x, y| x * x + y * y - 1.0);
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 200.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([-2.0, -2.0], [2.0, 2.0]), false);
+
+        let segments = circle.segments(&transform);
+        assert!(!segments.is_empty(), "the circle should be traced");
+        for pos in segments.iter().flatten() {
+            let radius = transform.value_from_position(*pos).to_vec2().length();
+            assert!((radius - 1.0).abs() < 0.01, "{pos:?} should be on the circle");
+        }
+    }
+
+    #[test]
+    fn saddles_are_split_by_the_center() {
+        let corner = |x: f32, y: f32, value: f64| (pos2(x, y), value);
+        let cell = [
+            corner(0.0, 0.0, 1.0),
+            corner(1.0, 0.0, -1.0),
+            corner(1.0, 1.0, 1.0),
+            corner(0.0, 1.0, -1.0),
+        ];
+        assert_eq!(march(cell, 1.0).len(), 2);
+        assert_ne!(march(cell, 1.0), march(cell, -1.0));
+    }
+}
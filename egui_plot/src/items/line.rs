@@ -10,6 +10,7 @@ use emath::Pos2;
 use emath::pos2;
 
 use crate::aesthetics::LineStyle;
+use crate::axis::Axis;
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
@@ -24,6 +25,9 @@ pub struct HLine {
     pub(crate) y: f64,
     pub(crate) stroke: Stroke,
     pub(crate) style: LineStyle,
+
+    /// The step the line snaps to when dragged, or `0.0` for none.
+    snap: f64,
 }
 
 impl HLine {
@@ -33,6 +37,7 @@ impl HLine {
             y: y.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             style: LineStyle::Solid,
+            snap: 0.0,
         }
     }
 
@@ -97,6 +102,73 @@ impl HLine {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the line with the pan button. While it is dragged,
+    /// [`crate::PlotResponse::dragged_line`] reports the new value, and the
+    /// plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Snap the dragged line to multiples of `step`. Default: `0.0`, which
+    /// means no snapping.
+    #[inline]
+    pub fn snap(mut self, step: f64) -> Self {
+        self.snap = step;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -150,6 +222,14 @@ impl PlotItem for HLine {
         bounds.max[1] = self.y;
         bounds
     }
+
+    fn drag_axis(&self) -> Option<(Axis, f64)> {
+        Some((Axis::Y, self.y))
+    }
+
+    fn snap_drag(&self, value: f64) -> f64 {
+        snap_to_step(value, self.snap)
+    }
 }
 
 /// A vertical line in a plot, filling the full width
@@ -159,6 +239,9 @@ pub struct VLine {
     pub(crate) x: f64,
     pub(crate) stroke: Stroke,
     pub(crate) style: LineStyle,
+
+    /// The step the line snaps to when dragged, or `0.0` for none.
+    snap: f64,
 }
 
 impl VLine {
@@ -168,6 +251,7 @@ impl VLine {
             x: x.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             style: LineStyle::Solid,
+            snap: 0.0,
         }
     }
 
@@ -232,6 +316,73 @@ impl VLine {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the line with the pan button. While it is dragged,
+    /// [`crate::PlotResponse::dragged_line`] reports the new value, and the
+    /// plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Snap the dragged line to multiples of `step`. Default: `0.0`, which
+    /// means no snapping.
+    #[inline]
+    pub fn snap(mut self, step: f64) -> Self {
+        self.snap = step;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -285,6 +436,24 @@ impl PlotItem for VLine {
         bounds.max[0] = self.x;
         bounds
     }
+
+    fn drag_axis(&self) -> Option<(Axis, f64)> {
+        Some((Axis::X, self.x))
+    }
+
+    fn snap_drag(&self, value: f64) -> f64 {
+        snap_to_step(value, self.snap)
+    }
+}
+
+/// The multiple of `step` nearest to `value`, or `value` if `step` isn't
+/// positive.
+fn snap_to_step(value: f64, step: f64) -> f64 {
+    if step > 0.0 {
+        (value / step).round() * step
+    } else {
+        value
+    }
 }
 
 pub fn vertical_line(pointer: Pos2, transform: &PlotTransform, line_color: Color32) -> Shape {
@@ -302,3 +471,17 @@ pub fn horizontal_line(pointer: Pos2, transform: &PlotTransform, line_color: Col
         (1.0, line_color),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dragged_lines_snap_to_their_step() {
+        let line = HLine::new("threshold", 0.0).draggable(true).snap(0.25);
+        assert_eq!(line.drag_axis(), Some((Axis::Y, 0.0)));
+        assert_eq!(line.snap_drag(0.6), 0.5);
+        assert_eq!(line.snap_drag(-0.13), -0.25);
+        assert_eq!(VLine::new("marker", 1.0).snap_drag(0.6), 0.6);
+    }
+}
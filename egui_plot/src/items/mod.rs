@@ -18,51 +18,112 @@ use egui::vec2;
 use emath::Float as _;
 
 use crate::aesthetics::Orientation;
+use crate::axis::Axis;
 use crate::axis::PlotTransform;
+use crate::axis::YAxis;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
 use crate::cursor::Cursor;
+use crate::data::PointsView;
+pub use crate::items::annotation::Annotation;
 pub use crate::items::arrows::Arrows;
+pub use crate::items::band::Band;
 pub use crate::items::bar_chart::Bar;
 pub use crate::items::bar_chart::BarChart;
+pub use crate::items::bezier::CubicBezier;
 pub use crate::items::box_plot::BoxElem;
 pub use crate::items::box_plot::BoxPlot;
 pub use crate::items::box_plot::BoxSpread;
+pub use crate::items::box_plot::WhiskerRule;
+pub use crate::items::color_image::ColorImageItem;
+pub use crate::items::contour::Contour;
+pub use crate::items::error_bars::ErrorBars;
+pub use crate::items::events::Events;
 pub use crate::items::filled_area::FilledArea;
+pub use crate::items::gantt::Gantt;
+pub use crate::items::gantt::GanttBar;
+pub use crate::items::grouped_bars::GroupedBars;
 pub use crate::items::heatmap::Heatmap;
+pub use crate::items::hist2d::Hist2d;
+pub use crate::items::histogram::BinRule;
+pub use crate::items::histogram::Histogram;
+pub use crate::items::implicit::ImplicitCurve;
 pub use crate::items::line::HLine;
 pub use crate::items::line::VLine;
 pub use crate::items::line::horizontal_line;
 pub use crate::items::line::vertical_line;
+pub use crate::items::parametric::ParametricLine;
+pub use crate::items::pie::PieChart;
 pub use crate::items::plot_image::PlotImage;
+pub use crate::items::points::PointStyle;
 pub use crate::items::points::Points;
 pub use crate::items::polygon::Polygon;
+pub use crate::items::quiver::Quiver;
+pub use crate::items::quiver::QuiverScale;
+pub use crate::items::radar::RadarChart;
 pub use crate::items::series::Line;
 pub use crate::items::span::Span;
+pub use crate::items::spectrogram::Spectrogram;
+pub use crate::items::spectrogram::SpectrogramBuffer;
+pub use crate::items::stacked::StackedBars;
+pub use crate::items::stacked::StackedLines;
+pub use crate::items::stem::Stem;
+pub use crate::items::streamlines::Streamlines;
 pub use crate::items::text::Text;
+pub use crate::items::violin::ViolinElem;
+pub use crate::items::violin::ViolinPlot;
+pub use crate::items::waterfall::Waterfall;
 use crate::label::LabelFormatter;
 use crate::rect_elem::RectElement;
 
+mod annotation;
 mod arrows;
+mod band;
 mod bar_chart;
+mod bezier;
 mod box_plot;
+mod color_image;
+mod contour;
+mod error_bars;
+mod events;
 mod filled_area;
+mod gantt;
+mod grouped_bars;
 mod heatmap;
+mod hist2d;
+mod histogram;
+mod implicit;
 mod line;
+mod parametric;
+mod pie;
 mod plot_image;
 mod points;
 mod polygon;
+mod quiver;
+mod radar;
 mod series;
 mod span;
+mod spectrogram;
+mod stacked;
+mod stem;
+mod streamlines;
 mod text;
+mod violin;
+mod waterfall;
 
 /// Base data shared by all plot items.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PlotItemBase {
     name: String,
     id: Id,
     highlight: bool,
     allow_hover: bool,
+    clickable: bool,
+    draggable: bool,
+    hover_radius: Option<f32>,
+    hover_priority: i32,
+    cache_key: Option<Id>,
+    y_axis: YAxis,
 }
 
 impl PlotItemBase {
@@ -74,6 +135,12 @@ impl PlotItemBase {
             id,
             highlight: false,
             allow_hover: true,
+            clickable: true,
+            draggable: false,
+            hover_radius: None,
+            hover_priority: 0,
+            cache_key: None,
+            y_axis: YAxis::Primary,
         }
     }
 }
@@ -128,12 +195,63 @@ pub trait PlotItem {
         self.base().allow_hover
     }
 
+    /// How near the pointer has to be to hover this item, in ui points, if
+    /// not the default of the plot.
+    fn hover_radius(&self) -> Option<f32> {
+        self.base().hover_radius
+    }
+
+    /// Items of higher priority are hovered over items of lower priority
+    /// when both are near the pointer.
+    fn hover_priority(&self) -> i32 {
+        self.base().hover_priority
+    }
+
+    /// The key the shapes of this item are cached under between frames, if
+    /// they are.
+    fn cache_key(&self) -> Option<Id> {
+        self.base().cache_key
+    }
+
+    /// Can the user click this item?
+    fn clickable(&self) -> bool {
+        self.base().clickable
+    }
+
+    /// Can the user drag the points of this item?
+    fn draggable(&self) -> bool {
+        self.base().draggable
+    }
+
+    /// For items dragged as a whole along one axis, like [`HLine`] and
+    /// [`VLine`]: that axis and the position of the item on it.
+    fn drag_axis(&self) -> Option<(Axis, f64)> {
+        None
+    }
+
+    /// The position along [`Self::drag_axis`] the item snaps to when dragged
+    /// to `value`.
+    fn snap_drag(&self, value: f64) -> f64 {
+        value
+    }
+
+    /// Which Y axis the plot item is drawn against.
+    fn y_axis(&self) -> YAxis {
+        self.base().y_axis
+    }
+
     /// Returns the geometry of the plot item.
     fn geometry(&self) -> PlotGeometry<'_>;
 
     /// Returns the bounds of the plot item.
     fn bounds(&self) -> PlotBounds;
 
+    /// Whether the points of [`Self::geometry`] are sorted by x, so that the
+    /// closest one can be found by binary search.
+    fn sorted_x(&self) -> bool {
+        false
+    }
+
     /// Returns a reference to the base data of the plot item.
     fn base(&self) -> &PlotItemBase;
 
@@ -150,19 +268,25 @@ pub trait PlotItem {
         match self.geometry() {
             PlotGeometry::None => None,
 
-            PlotGeometry::Points(points) => points
-                .iter()
-                .enumerate()
-                .map(|(index, value)| {
-                    let pos = transform.position_from_point(value);
-                    let dist_sq = point.distance_sq(pos);
-                    ClosestElem { index, dist_sq }
-                })
-                .min_by_key(|e| e.dist_sq.ord()),
-
             PlotGeometry::Rects => {
                 panic!("If the PlotItem is made of rects, it should implement find_closest()")
             }
+
+            geometry => {
+                let points = geometry.points()?;
+                if self.sorted_x() {
+                    return find_closest_sorted_x(points, point, transform);
+                }
+                points
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let pos = transform.position_from_point(&value);
+                        let dist_sq = point.distance_sq(pos);
+                        ClosestElem { index, dist_sq }
+                    })
+                    .min_by_key(|e| e.dist_sq.ord())
+            }
         }
     }
 
@@ -177,7 +301,8 @@ pub trait PlotItem {
         label_formatter: &Option<LabelFormatter<'_>>,
     ) {
         let points = match self.geometry() {
-            PlotGeometry::Points(points) => points,
+            PlotGeometry::Points(points) => PointsView::F64(points),
+            PlotGeometry::PointsF32(points) => PointsView::F32(points),
             PlotGeometry::None => {
                 panic!("If the PlotItem has no geometry, on_hover() must not be called")
             }
@@ -194,7 +319,7 @@ pub trait PlotItem {
 
         // this method is only called, if the value is in the result set of
         // find_closest()
-        let value = points[elem.index];
+        let value = points.get(elem.index);
         let pointer = plot.transform.position_from_point(&value);
         shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
 
@@ -292,7 +417,47 @@ pub(super) fn rulers_and_tooltip_at_value(
         return;
     };
 
-    let text = custom_label(name, &value);
+    show_tooltip(plot_area_response, custom_label(name, &value));
+}
+
+/// The point of `points`, which are sorted by x, closest to `pointer`.
+///
+/// The points are gone through outwards from the pointer's x, only as long
+/// as their distance along x is less than the closest distance so far.
+fn find_closest_sorted_x(points: PointsView<'_>, pointer: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+    let x = transform.value_from_position(pointer).x;
+    let start = points.partition_point(|point| point.x < x);
+
+    let mut closest: Option<ClosestElem> = None;
+    // Visit a point, returning whether points farther along x may still be
+    // closer.
+    let mut visit = |index: usize| {
+        let pos = transform.position_from_point(&points.get(index));
+        let dx = pos.x - pointer.x;
+        if closest.as_ref().is_some_and(|closest| dx * dx > closest.dist_sq) {
+            return false;
+        }
+        let dist_sq = pointer.distance_sq(pos);
+        if dist_sq.is_finite() && closest.as_ref().is_none_or(|closest| dist_sq < closest.dist_sq) {
+            closest = Some(ClosestElem { index, dist_sq });
+        }
+        true
+    };
+    for index in (0..start).rev() {
+        if !visit(index) {
+            break;
+        }
+    }
+    for index in start..points.len() {
+        if !visit(index) {
+            break;
+        }
+    }
+    closest
+}
+
+/// Show `text` in a tooltip next to the pointer, unless it is empty.
+pub(super) fn show_tooltip(plot_area_response: &egui::Response, text: String) {
     if text.is_empty() {
         return;
     }
@@ -324,12 +489,35 @@ pub enum PlotGeometry<'a> {
     /// Point values (X-Y graphs)
     Points(&'a [PlotPoint]),
 
+    /// Point values stored as `f32`, see [`crate::PlotPoints::from_f32`].
+    PointsF32(&'a [[f32; 2]]),
+
     /// Rectangles (examples: boxes or bars)
     // Has currently no data, as it would require copying rects or iterating a list of pointers.
     // Instead, geometry-based functions are directly implemented in the respective PlotItem impl.
     Rects,
 }
 
+impl<'a> PlotGeometry<'a> {
+    /// The geometry of `points`, however they are stored.
+    pub(crate) fn from_view(points: PointsView<'a>) -> Self {
+        match points {
+            PointsView::F64(points) => Self::Points(points),
+            PointsView::F32(points) => Self::PointsF32(points),
+        }
+    }
+
+    /// The points, however they are stored, if the geometry is made of
+    /// points.
+    pub(crate) fn points(&self) -> Option<PointsView<'a>> {
+        match *self {
+            Self::Points(points) => Some(PointsView::F64(points)),
+            Self::PointsF32(points) => Some(PointsView::F32(points)),
+            Self::None | Self::Rects => None,
+        }
+    }
+}
+
 /// Result of [`PlotItem::find_closest()`] search, identifies an element
 /// inside the item for immediate use
 pub struct ClosestElem {
@@ -340,3 +528,35 @@ pub struct ClosestElem {
     /// `PlotItems`, which might be nearer)
     pub dist_sq: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use emath::Float as _;
+    use emath::Rect;
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn closest_sorted_point_matches_a_full_scan() {
+        let points: Vec<PlotPoint> = (0..200)
+            .map(|i| PlotPoint::new(i as f64 * 0.5, (i as f64 * 0.3).sin()))
+            .collect();
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(400.0, 200.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, -1.0], [100.0, 1.0]), false);
+        for pointer in [
+            pos2(-10.0, 50.0),
+            pos2(123.4, 180.0),
+            pos2(250.0, 0.0),
+            pos2(500.0, 100.0),
+        ] {
+            let full_scan = points
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, value)| pointer.distance_sq(transform.position_from_point(value)).ord())
+                .map(|(index, _)| index);
+            let closest = find_closest_sorted_x(points[..].into(), pointer, &transform).map(|closest| closest.index);
+            assert_eq!(closest, full_scan, "same point as a full scan for {pointer:?}");
+        }
+    }
+}
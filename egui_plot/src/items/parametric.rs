@@ -0,0 +1,366 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use egui::epaint::PathStroke;
+use emath::Pos2;
+use emath::Rect;
+
+use crate::aesthetics::LineStyle;
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Number of evenly spaced parameter values the curve is first cut into,
+/// before refining.
+const INITIAL_SEGMENTS: usize = 64;
+
+/// Number of times each initial segment may be halved.
+const MAX_DEPTH: u32 = 10;
+
+/// Number of evenly spaced samples used for the bounds and for hovering.
+const BOUNDS_SAMPLES: usize = 256;
+
+/// A curve `(x, y) = f(t)` sampled adaptively on screen.
+///
+/// Unlike [`crate::PlotPoints::from_parametric_callback`], the number of
+/// samples is not fixed: segments are halved until they follow the curve to
+/// within [`Self::tolerance`] ui points at the current zoom, so circles,
+/// ellipses and Lissajous figures stay smooth however far one zooms in.
+///
+/// Parameter values where `f` is not finite leave a gap in the curve.
+pub struct ParametricLine<'a> {
+    base: PlotItemBase,
+    function: Box<dyn Fn(f64) -> (f64, f64) + 'a>,
+    t_range: RangeInclusive<f64>,
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    tolerance: f32,
+
+    /// Evenly spaced samples, for the bounds and for hovering.
+    samples: Vec<PlotPoint>,
+}
+
+impl<'a> ParametricLine<'a> {
+    /// A curve through `function(t)` for `t` in `t_range`.
+    pub fn new(
+        name: impl Into<String>,
+        function: impl Fn(f64) -> (f64, f64) + 'a,
+        t_range: impl Into<RangeInclusive<f64>>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            function: Box::new(function),
+            t_range: t_range.into(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            tolerance: 0.25,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color
+    /// will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// How far, in ui points, the drawn segments may stray from the curve.
+    /// Smaller values give smoother curves at the cost of more samples.
+    /// Default: `0.25`.
+    #[inline]
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The parameter value at `fraction` of the range.
+    fn t_at(&self, fraction: f64) -> f64 {
+        let (start, end) = (*self.t_range.start(), *self.t_range.end());
+        start + fraction * (end - start)
+    }
+
+    fn position(&self, t: f64, transform: &PlotTransform) -> Pos2 {
+        let (x, y) = (self.function)(t);
+        transform.position_from_point(&PlotPoint::new(x, y))
+    }
+
+    /// The curve on screen, as runs of positions between the gaps.
+    fn sample(&self, transform: &PlotTransform) -> Vec<Vec<Pos2>> {
+        let seeds: Vec<(f64, Pos2)> = (0..=INITIAL_SEGMENTS)
+            .map(|i| {
+                let t = self.t_at(i as f64 / INITIAL_SEGMENTS as f64);
+                (t, self.position(t, transform))
+            })
+            .collect();
+
+        let mut runs = vec![Vec::new()];
+        push_position(&mut runs, seeds[0].1);
+        for pair in seeds.windows(2) {
+            self.refine(pair[0], pair[1], transform, MAX_DEPTH, &mut runs);
+        }
+        runs.retain(|run| run.len() >= 2);
+        runs
+    }
+
+    /// Push the positions after `start` up to `end`, halving the segment
+    /// while its middle strays from the chord by more than the tolerance.
+    fn refine(
+        &self,
+        start: (f64, Pos2),
+        end: (f64, Pos2),
+        transform: &PlotTransform,
+        depth: u32,
+        runs: &mut Vec<Vec<Pos2>>,
+    ) {
+        let t = 0.5 * (start.0 + end.0);
+        let middle = (t, self.position(t, transform));
+        if depth > 0 && self.needs_refining(start.1, middle.1, end.1, transform.frame()) {
+            self.refine(start, middle, transform, depth - 1, runs);
+            self.refine(middle, end, transform, depth - 1, runs);
+        } else {
+            push_position(runs, middle.1);
+            push_position(runs, end.1);
+        }
+    }
+
+    fn needs_refining(&self, start: Pos2, middle: Pos2, end: Pos2, frame: &Rect) -> bool {
+        let finite = [start, middle, end].iter().filter(|p| p.is_finite()).count();
+        if finite == 0 {
+            return false;
+        }
+        if finite < 3 {
+            // Narrow down where the curve starts or stops being defined.
+            return true;
+        }
+
+        // Segments far outside the frame are not worth refining.
+        let extent = Rect::from_points(&[start, middle, end]);
+        if !extent.expand(extent.size().max_elem()).intersects(*frame) {
+            return false;
+        }
+
+        middle.distance(start.lerp(end, 0.5)) > self.tolerance
+    }
+}
+
+/// Append a position to the last run, or start a new run at a gap.
+fn push_position(runs: &mut Vec<Vec<Pos2>>, position: Pos2) {
+    if position.is_finite() {
+        if let Some(run) = runs.last_mut() {
+            run.push(position);
+        }
+    } else if runs.last().is_some_and(|run| !run.is_empty()) {
+        runs.push(Vec::new());
+    }
+}
+
+impl PlotItem for ParametricLine<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let stroke = PathStroke::new(self.stroke.width, self.stroke.color);
+        for run in self.sample(transform) {
+            self.style.style_line(run, stroke.clone(), self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        self.samples = (0..BOUNDS_SAMPLES)
+            .map(|i| {
+                let (x, y) = (self.function)(self.t_at(i as f64 / (BOUNDS_SAMPLES - 1) as f64));
+                PlotPoint::new(x, y)
+            })
+            .collect();
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.samples)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.samples {
+            if point.x.is_finite() && point.y.is_finite() {
+                bounds.extend_with(point);
+            }
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn zooming_in_adds_samples() {
+        let circle = ParametricLine::new("circle", |t: f64| (t.cos(), t.sin()), 0.0..=TAU);
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let count = |bounds| -> usize {
+            let transform = PlotTransform::new(frame, bounds, false);
+            circle.sample(&transform).iter().map(Vec::len).sum()
+        };
+
+        let whole = count(PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]));
+        let zoomed = count(PlotBounds::from_min_max([0.9, -0.1], [1.1, 0.1]));
+        assert!(zoomed > whole, "zooming in should refine the curve further");
+    }
+
+    #[test]
+    fn undefined_parameters_leave_gaps() {
+        let line = ParametricLine::new(
+            "line",
+            |t: f64| (t, if t.abs() < 0.25 { f64::NAN } else { t }),
+            -1.0..=1.0,
+        );
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]), false);
+        assert_eq!(line.sample(&transform).len(), 2);
+    }
+}
@@ -0,0 +1,318 @@
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Mesh;
+use egui::Pos2;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use emath::Vec2;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::highlighted_color;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+
+/// Segments per full turn used to approximate the arcs.
+const SEGMENTS_PER_TURN: f32 = 128.0;
+
+/// A pie (or donut) chart, drawn inside the plot at a given center and
+/// radius.
+///
+/// The chart ignores the axes apart from its position: it stays circular
+/// whatever the aspect ratio, with the radius measured along the X axis.
+/// This makes it usable as an inset next to other items.
+///
+/// Each slice becomes its own plot item, with its own legend entry. A slice
+/// moves out of the pie when hovered, either in the plot or in the legend.
+///
+/// Add it with [`crate::PlotUi::pie_chart`].
+pub struct PieChart {
+    pub(crate) center: PlotPoint,
+    pub(crate) radius: f64,
+    pub(crate) slices: Vec<(String, f64)>,
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) inner_radius: f32,
+    pub(crate) start_angle: f32,
+    pub(crate) explode: f32,
+    pub(crate) show_labels: bool,
+}
+
+impl PieChart {
+    /// A pie chart centered on `center`, with the given `radius` along the X
+    /// axis. Add slices with [`Self::slice`].
+    pub fn new(center: impl Into<PlotPoint>, radius: f64) -> Self {
+        Self {
+            center: center.into(),
+            radius,
+            slices: Vec::new(),
+            colors: Vec::new(),
+            inner_radius: 0.0,
+            start_angle: 90.0,
+            explode: 0.1,
+            show_labels: true,
+        }
+    }
+
+    /// Add a slice, proportional to `value`. Negative values are ignored.
+    ///
+    /// Slices go counter-clockwise from [`Self::start_angle`].
+    #[inline]
+    pub fn slice(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.slices.push((name.into(), value.max(0.0)));
+        self
+    }
+
+    /// Set the color of each slice. Slices without a color get an
+    /// auto-assigned one.
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Turn the pie into a donut, with a hole of the given fraction of the
+    /// radius. Default: `0.0`.
+    #[inline]
+    pub fn inner_radius(mut self, fraction: f32) -> Self {
+        self.inner_radius = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Angle of the start of the first slice, in degrees counter-clockwise
+    /// from the positive X axis. Default: `90.0`, i.e. straight up.
+    #[inline]
+    pub fn start_angle(mut self, degrees: f32) -> Self {
+        self.start_angle = degrees;
+        self
+    }
+
+    /// How far a hovered slice moves out, as a fraction of the radius.
+    /// Default: `0.1`.
+    #[inline]
+    pub fn explode(mut self, fraction: f32) -> Self {
+        self.explode = fraction;
+        self
+    }
+
+    /// Show the percentage of each slice on it. Default: `true`.
+    #[inline]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Build one item per slice, with the colors filled in by `auto_color`
+    /// where missing.
+    pub(crate) fn into_slices(self, mut auto_color: impl FnMut() -> Color32) -> Vec<PieSlice> {
+        let total: f64 = self.slices.iter().map(|(_, value)| value).sum();
+        let mut start = self.start_angle.to_radians();
+        self.slices
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, value))| {
+                let fraction = if total > 0.0 { value / total } else { 0.0 };
+                let end = start + TAU * fraction as f32;
+                let color = self
+                    .colors
+                    .get(index)
+                    .copied()
+                    .filter(|color| *color != Color32::TRANSPARENT)
+                    .unwrap_or_else(&mut auto_color);
+                let slice = PieSlice {
+                    base: PlotItemBase::new(name),
+                    center: self.center,
+                    radius: self.radius,
+                    inner_radius: self.inner_radius,
+                    angles: start..=end,
+                    value,
+                    fraction,
+                    color,
+                    explode: self.explode,
+                    show_label: self.show_labels,
+                };
+                start = end;
+                slice
+            })
+            .collect()
+    }
+}
+
+/// One slice of a [`PieChart`].
+pub(crate) struct PieSlice {
+    base: PlotItemBase,
+    center: PlotPoint,
+    radius: f64,
+    inner_radius: f32,
+
+    /// In radians, counter-clockwise from the positive X axis.
+    angles: RangeInclusive<f32>,
+
+    value: f64,
+    fraction: f64,
+    color: Color32,
+    explode: f32,
+    show_label: bool,
+}
+
+impl PieSlice {
+    /// Center and outer radius on screen.
+    fn screen_circle(&self, transform: &PlotTransform) -> (Pos2, f32) {
+        let center = transform.position_from_point(&self.center);
+        let radius = (transform.position_from_point_x(self.center.x + self.radius) - center.x).abs();
+        (center, radius)
+    }
+
+    /// Unit vector on screen at the given angle. Screen Y goes down.
+    fn direction(angle: f32) -> Vec2 {
+        vec2(angle.cos(), -angle.sin())
+    }
+
+    fn mid_angle(&self) -> f32 {
+        0.5 * (self.angles.start() + self.angles.end())
+    }
+
+    fn add_shapes(&self, ui: &Ui, transform: &PlotTransform, exploded: bool, shapes: &mut Vec<Shape>) {
+        let (mut center, radius) = self.screen_circle(transform);
+        if exploded {
+            center += self.explode * radius * Self::direction(self.mid_angle());
+        }
+        let inner = self.inner_radius * radius;
+
+        let (stroke, fill) = if exploded {
+            highlighted_color(Stroke::new(1.0, self.color), self.color.linear_multiply(0.6))
+        } else {
+            (Stroke::new(1.0, self.color), self.color.linear_multiply(0.6))
+        };
+
+        let (start, end) = (*self.angles.start(), *self.angles.end());
+        let segments = ((end - start) / TAU * SEGMENTS_PER_TURN).ceil().max(1.0) as usize;
+        let arc = |r: f32| -> Vec<Pos2> {
+            (0..=segments)
+                .map(|i| center + r * Self::direction(start + (end - start) * i as f32 / segments as f32))
+                .collect()
+        };
+        let outer_arc = arc(radius);
+        let inner_arc = if inner > 0.0 {
+            arc(inner)
+        } else {
+            vec![center; segments + 1]
+        };
+
+        let mut mesh = Mesh::default();
+        for (&o, &i) in outer_arc.iter().zip(&inner_arc) {
+            mesh.colored_vertex(o, fill);
+            mesh.colored_vertex(i, fill);
+        }
+        for k in 0..segments as u32 {
+            mesh.add_triangle(2 * k, 2 * k + 1, 2 * k + 2);
+            mesh.add_triangle(2 * k + 1, 2 * k + 2, 2 * k + 3);
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        let mut outline = outer_arc;
+        outline.extend(inner_arc.into_iter().rev());
+        shapes.push(Shape::closed_line(outline, stroke));
+
+        if self.show_label && self.fraction > 0.0 {
+            let position = center + 0.5 * (radius + inner.max(0.3 * radius)) * Self::direction(self.mid_angle());
+            let text = format!("{:.0}%", 100.0 * self.fraction);
+            let font_id = TextStyle::Small.resolve(ui.style());
+            let color = ui.visuals().strong_text_color();
+            ui.fonts_mut(|f| {
+                shapes.push(Shape::text(f, position, Align2::CENTER_CENTER, text, font_id, color));
+            });
+        }
+    }
+}
+
+impl PlotItem for PieSlice {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.add_shapes(ui, transform, self.base.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.center);
+        bounds.extend_with(&PlotPoint::new(self.center.x - self.radius, self.center.y));
+        bounds.extend_with(&PlotPoint::new(self.center.x + self.radius, self.center.y));
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let (center, radius) = self.screen_circle(transform);
+        let offset = point - center;
+        let distance = offset.length();
+        if distance > radius || distance < self.inner_radius * radius {
+            return None;
+        }
+
+        // Compare angles counter-clockwise from the start of the slice.
+        let angle = (-offset.y).atan2(offset.x);
+        let from_start = (angle - self.angles.start()).rem_euclid(TAU);
+        (from_start <= self.angles.end() - self.angles.start()).then_some(ClosestElem { index: 0, dist_sq: 0.0 })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        self.add_shapes(plot.ui, plot.transform, true, shapes);
+        show_tooltip(
+            plot_area_response,
+            format!("{}\n{} ({:.1}%)", self.name(), self.value, 100.0 * self.fraction),
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices_split_the_turn() {
+        let slices = PieChart::new([0.0, 0.0], 1.0)
+            .start_angle(0.0)
+            .slice("a", 1.0)
+            .slice("b", 3.0)
+            .into_slices(|| Color32::RED);
+        assert_eq!(slices[0].angles, 0.0..=TAU / 4.0);
+        assert_eq!(slices[1].angles, TAU / 4.0..=TAU);
+        assert_eq!(slices[1].fraction, 0.75);
+    }
+}
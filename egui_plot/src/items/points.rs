@@ -10,11 +10,16 @@ use emath::Pos2;
 use emath::pos2;
 use emath::vec2;
 
+use crate::aesthetics::FillRule;
 use crate::aesthetics::MarkerShape;
+#[cfg(feature = "glow")]
+use crate::axis::Axis;
 use crate::axis::PlotTransform;
+use crate::axis::YAxis;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
 use crate::data::PlotPoints;
+use crate::downsample;
 use crate::items::PlotGeometry;
 use crate::items::PlotItem;
 use crate::items::PlotItemBase;
@@ -29,6 +34,15 @@ impl<'a> Points<'a> {
             filled: true,
             radius: 1.0,
             stems: None,
+            colors: Vec::new(),
+            radii: Vec::new(),
+            shapes: Vec::new(),
+            style_fn: None,
+            sorted_x: false,
+            #[cfg(feature = "glow")]
+            gpu: false,
+            #[cfg(feature = "glow")]
+            version: None,
         }
     }
 
@@ -68,6 +82,93 @@ impl<'a> Points<'a> {
         self
     }
 
+    /// Set the color of each point, in the order of the series. Points without
+    /// a color here use [`Self::color`].
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Set the radius of each point, in the order of the series, e.g. for a
+    /// bubble chart. Points without a radius here use [`Self::radius`].
+    #[inline]
+    pub fn radii(mut self, radii: impl Into<Vec<f32>>) -> Self {
+        self.radii = radii.into();
+        self
+    }
+
+    /// Set the marker shape of each point, in the order of the series. Points
+    /// without a shape here use [`Self::shape`].
+    #[inline]
+    pub fn shapes(mut self, shapes: impl Into<Vec<MarkerShape>>) -> Self {
+        self.shapes = shapes.into();
+        self
+    }
+
+    /// Style each point with a function of its index and position. What it
+    /// leaves unset falls back to [`Self::colors`], [`Self::radii`] and
+    /// [`Self::shapes`], then to the settings of the whole item.
+    ///
+    /// ```
+    /// # use egui::Color32;
+    /// # use egui_plot::{PointStyle, Points};
+    /// let points = Points::new("classes", vec![[0.0, 1.0], [1.0, -1.0]]).style_fn(|_, point| PointStyle {
+    ///     color: Some(if point.y > 0.0 { Color32::RED } else { Color32::BLUE }),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    #[inline]
+    pub fn style_fn(mut self, style_fn: impl Fn(usize, &PlotPoint) -> PointStyle + 'a) -> Self {
+        self.style_fn = Some(Box::new(style_fn));
+        self
+    }
+
+    /// Promise that the points are sorted by x, so that only the points in
+    /// view are drawn, found by binary search, e.g. to pan quickly over a
+    /// long recording. Markers larger than [`Self::radius`] and
+    /// [`Self::radii`] may be cut off at the edges of the plot. The point
+    /// closest to the pointer is then also found by binary search, and the x
+    /// range of the bounds read off the first and the last point.
+    ///
+    /// If the points aren't sorted, some of the points in view may not be
+    /// drawn or hovered. Default: `false`.
+    #[inline]
+    pub fn sorted_x(mut self, sorted: bool) -> Self {
+        self.sorted_x = sorted;
+        self
+    }
+
+    /// Draw the markers on the GPU with OpenGL, for scatter plots of millions
+    /// of points. The points are uploaded once for each [`Self::version`] and
+    /// drawn as instanced quads, so panning and zooming costs next to nothing
+    /// on the CPU.
+    ///
+    /// This needs `eframe` to render with `glow`. Only filled circles with a
+    /// single color and radius are drawn this way: with other shapes,
+    /// per-point styles, stems or an axis transform, the markers are drawn
+    /// as usual. Default: `false`.
+    #[cfg(feature = "glow")]
+    #[inline]
+    pub fn gpu(mut self, gpu: bool) -> Self {
+        self.gpu = gpu;
+        self
+    }
+
+    /// Tell that the points are the same as the last frame they were drawn
+    /// with the same `version`, so that [`Self::gpu`] doesn't upload them
+    /// again. Change the version whenever the points change.
+    ///
+    /// Not needed for the markers of a [`crate::RetainedSeries`], which keep
+    /// track of their points themselves. Default: no version, and the points
+    /// are uploaded every frame.
+    #[cfg(feature = "glow")]
+    #[inline]
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
     /// Name of this plot item.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -100,6 +201,74 @@ impl<'a> Points<'a> {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the points with the pan button. While a point is
+    /// dragged, [`crate::PlotResponse::dragged_point`] reports where to, and
+    /// the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -109,6 +278,53 @@ impl<'a> Points<'a> {
         self.base_mut().id = id.into();
         self
     }
+
+    /// The shape, color and radius of the point at `index`.
+    fn style_at(&self, index: usize, point: &PlotPoint) -> (MarkerShape, Color32, f32) {
+        let style = self
+            .style_fn
+            .as_ref()
+            .map(|style_fn| style_fn(index, point))
+            .unwrap_or_default();
+        (
+            style
+                .shape
+                .or_else(|| self.shapes.get(index).copied())
+                .unwrap_or(self.shape),
+            style
+                .color
+                .or_else(|| self.colors.get(index).copied())
+                .unwrap_or(self.color),
+            style
+                .radius
+                .or_else(|| self.radii.get(index).copied())
+                .unwrap_or(self.radius),
+        )
+    }
+
+    /// Whether the markers can be drawn on the GPU, see [`Self::gpu`].
+    #[cfg(feature = "glow")]
+    fn gpu_drawable(&self, transform: &PlotTransform) -> bool {
+        self.gpu
+            && self.filled
+            && self.shape == MarkerShape::Circle
+            && self.stems.is_none()
+            && self.colors.is_empty()
+            && self.radii.is_empty()
+            && self.shapes.is_empty()
+            && self.style_fn.is_none()
+            && transform.axis_transform(Axis::X).is_none()
+            && transform.axis_transform(Axis::Y).is_none()
+    }
+}
+
+/// The style of a single point of [`Points`], as returned by
+/// [`Points::style_fn`]. Unset fields fall back to the item's settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointStyle {
+    pub color: Option<Color32>,
+    pub radius: Option<f32>,
+    pub shape: Option<MarkerShape>,
 }
 
 /// A set of points.
@@ -130,125 +346,97 @@ pub struct Points<'a> {
     pub(crate) radius: f32,
 
     pub(crate) stems: Option<f32>,
-}
 
-impl PlotItem for Points<'_> {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
-
-        let Self {
-            base,
-            series,
-            shape,
-            color,
-            filled,
-            radius,
-            stems,
-            ..
-        } = self;
+    /// Per-point overrides, by index in the series.
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) radii: Vec<f32>,
+    pub(crate) shapes: Vec<MarkerShape>,
+
+    pub(crate) style_fn: Option<Box<dyn Fn(usize, &PlotPoint) -> PointStyle + 'a>>,
 
-        let mut radius = *radius;
+    /// Whether the points are sorted by x, so the ones in view can be found
+    /// by binary search.
+    pub(crate) sorted_x: bool,
 
-        let stroke_size = radius / 5.0;
+    /// Whether to draw the markers on the GPU, see [`Self::gpu`].
+    #[cfg(feature = "glow")]
+    pub(crate) gpu: bool,
+
+    /// Which version of the series the points are, see [`Self::version`].
+    #[cfg(feature = "glow")]
+    pub(crate) version: Option<u64>,
+}
+
+impl PlotItem for Points<'_> {
+    #[cfg_attr(
+        not(feature = "glow"),
+        expect(unused_variables, reason = "only used to draw on the GPU")
+    )]
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        #[cfg(feature = "glow")]
+        if self.gpu_drawable(transform) {
+            let radius = if self.base.highlight {
+                self.radius * 2f32.sqrt()
+            } else {
+                self.radius
+            };
+            shapes.push(crate::gpu_points::paint(
+                ui,
+                self.base.id,
+                self.series.view(),
+                self.series.series_key(self.version),
+                transform,
+                self.color,
+                radius,
+            ));
+            return;
+        }
 
-        let default_stroke = Stroke::new(stroke_size, *color);
-        let mut stem_stroke = default_stroke;
-        let (fill, stroke) = if *filled {
-            (*color, Stroke::NONE)
+        let y_reference = self
+            .stems
+            .map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+
+        let points = self.series.view();
+        let visible = if self.sorted_x {
+            // Keep the markers that reach into view from outside it.
+            let radius = self.radii.iter().copied().fold(self.radius, f32::max);
+            let margin = 2.0 * radius as f64 * transform.dvalue_dpos()[0].abs();
+            let x_range = transform.bounds().range_x();
+            downsample::sorted_visible_range(points, &(x_range.start() - margin..=x_range.end() + margin))
         } else {
-            (Color32::TRANSPARENT, default_stroke)
+            0..points.len()
         };
 
-        if base.highlight {
-            radius *= 2f32.sqrt();
-            stem_stroke.width *= 2.0;
-        }
+        for (index, value) in points.iter().enumerate().take(visible.end).skip(visible.start) {
+            let (shape, color, mut radius) = self.style_at(index, &value);
+            let center = transform.position_from_point(&value);
 
-        let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+            let stroke_size = radius / 5.0;
 
-        series
-            .points()
-            .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+            let default_stroke = Stroke::new(stroke_size, color);
+            let mut stem_stroke = default_stroke;
+            let (fill, stroke) = if self.filled {
+                (color, Stroke::NONE)
+            } else {
+                (Color32::TRANSPARENT, default_stroke)
+            };
 
-                if let Some(y) = y_reference {
-                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
-                    shapes.push(stem);
-                }
+            if self.base.highlight {
+                radius *= 2f32.sqrt();
+                stem_stroke.width *= 2.0;
+            }
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [tf(-frac_1_sqrt_2, -frac_1_sqrt_2), tf(frac_1_sqrt_2, frac_1_sqrt_2)];
-                        let diagonal2 = [tf(frac_1_sqrt_2, -frac_1_sqrt_2), tf(-frac_1_sqrt_2, frac_1_sqrt_2)];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points = vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![tf(0.0, 1.0), tf(-0.5 * sqrt_3, -0.5), tf(0.5 * sqrt_3, -0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points = vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![tf(1.0, 0.0), tf(-0.5, 0.5 * sqrt_3), tf(-0.5, -0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                }
-            });
+            if let Some(y) = y_reference {
+                let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
+                shapes.push(stem);
+            }
+
+            push_marker(shapes, shape, center, radius, fill, stroke, default_stroke);
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
-        self.series.generate_points(x_range);
+        self.series.query_points(x_range);
     }
 
     fn color(&self) -> Color32 {
@@ -256,11 +444,19 @@ impl PlotItem for Points<'_> {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.series.points())
+        PlotGeometry::from_view(self.series.view())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        if self.sorted_x {
+            self.series.sorted_x_bounds()
+        } else {
+            self.series.bounds()
+        }
+    }
+
+    fn sorted_x(&self) -> bool {
+        self.sorted_x
     }
 
     fn base(&self) -> &PlotItemBase {
@@ -271,3 +467,151 @@ impl PlotItem for Points<'_> {
         &mut self.base
     }
 }
+
+/// Push the shapes of a marker centered at `center`.
+///
+/// `fill` and `stroke` are used for the outlined shapes, `line_stroke` for
+/// the ones made of lines only (e.g. [`MarkerShape::Cross`]).
+pub(crate) fn push_marker(
+    shapes: &mut Vec<Shape>,
+    shape: MarkerShape,
+    center: Pos2,
+    radius: f32,
+    fill: Color32,
+    stroke: Stroke,
+    line_stroke: Stroke,
+) {
+    let sqrt_3 = 3_f32.sqrt();
+    let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+    let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+    let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+
+    match shape {
+        MarkerShape::Circle => {
+            shapes.push(Shape::Circle(CircleShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            }));
+        }
+        MarkerShape::Diamond => {
+            let points = vec![
+                tf(0.0, 1.0),  // bottom
+                tf(-1.0, 0.0), // left
+                tf(0.0, -1.0), // top
+                tf(1.0, 0.0),  // right
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Square => {
+            let points = vec![
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Cross => {
+            let diagonal1 = [tf(-frac_1_sqrt_2, -frac_1_sqrt_2), tf(frac_1_sqrt_2, frac_1_sqrt_2)];
+            let diagonal2 = [tf(frac_1_sqrt_2, -frac_1_sqrt_2), tf(-frac_1_sqrt_2, frac_1_sqrt_2)];
+            shapes.push(Shape::line_segment(diagonal1, line_stroke));
+            shapes.push(Shape::line_segment(diagonal2, line_stroke));
+        }
+        MarkerShape::Plus => {
+            let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            shapes.push(Shape::line_segment(horizontal, line_stroke));
+            shapes.push(Shape::line_segment(vertical, line_stroke));
+        }
+        MarkerShape::Up => {
+            let points = vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Down => {
+            let points = vec![tf(0.0, 1.0), tf(-0.5 * sqrt_3, -0.5), tf(0.5 * sqrt_3, -0.5)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Left => {
+            let points = vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Right => {
+            let points = vec![tf(1.0, 0.0), tf(-0.5, 0.5 * sqrt_3), tf(-0.5, -0.5 * sqrt_3)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Asterisk => {
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
+            let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
+            shapes.push(Shape::line_segment(vertical, line_stroke));
+            shapes.push(Shape::line_segment(diagonal1, line_stroke));
+            shapes.push(Shape::line_segment(diagonal2, line_stroke));
+        }
+        MarkerShape::Custom(path) => {
+            let to_screen = |ring: &[[f32; 2]]| ring.iter().map(|&[x, y]| tf(x, -y)).collect::<Vec<_>>();
+            for &outline in path.outlines {
+                let outline = to_screen(outline);
+                if fill != Color32::TRANSPARENT {
+                    shapes.push(Shape::mesh(FillRule::NonZero.fill(&[outline.clone()], fill)));
+                }
+                if stroke != Stroke::NONE {
+                    shapes.push(Shape::closed_line(outline, stroke));
+                }
+            }
+            for &line in path.lines {
+                shapes.push(Shape::line(to_screen(line), line_stroke));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_point_style_falls_back_to_the_item() {
+        let points = Points::new("points", vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])
+            .color(Color32::RED)
+            .radius(2.0)
+            .colors([Color32::BLUE])
+            .radii([5.0, 6.0])
+            .style_fn(|index, _| PointStyle {
+                shape: (index == 1).then_some(MarkerShape::Square),
+                ..Default::default()
+            });
+        let series = points.series.points();
+        assert_eq!(
+            points.style_at(0, &series[0]),
+            (MarkerShape::Circle, Color32::BLUE, 5.0)
+        );
+        assert_eq!(points.style_at(1, &series[1]), (MarkerShape::Square, Color32::RED, 6.0));
+        assert_eq!(points.style_at(2, &series[2]), (MarkerShape::Circle, Color32::RED, 2.0));
+    }
+
+    #[test]
+    fn custom_marker_is_scaled_and_flipped() {
+        let shape = MarkerShape::Custom(crate::MarkerPath {
+            outlines: &[&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]],
+            lines: &[&[[0.0, 0.0], [0.0, -1.0]]],
+        });
+        let stroke = Stroke::new(1.0, Color32::RED);
+        let mut shapes = Vec::new();
+        push_marker(
+            &mut shapes,
+            shape,
+            pos2(10.0, 10.0),
+            2.0,
+            Color32::RED,
+            Stroke::NONE,
+            stroke,
+        );
+        assert_eq!(shapes.len(), 2);
+        let Shape::Path(line) = &shapes[1] else {
+            panic!("expected a path");
+        };
+        assert_eq!(line.points, [pos2(10.0, 10.0), pos2(10.0, 12.0)]);
+    }
+}
@@ -7,6 +7,7 @@ use egui::Stroke;
 use egui::Ui;
 use egui::epaint::PathStroke;
 
+use crate::aesthetics::FillRule;
 use crate::aesthetics::LineStyle;
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
@@ -16,10 +17,12 @@ use crate::items::PlotGeometry;
 use crate::items::PlotItem;
 use crate::items::PlotItemBase;
 
-/// A convex polygon.
+/// A polygon, convex unless it has holes or a [`FillRule`].
 pub struct Polygon<'a> {
     base: PlotItemBase,
     pub(crate) series: PlotPoints<'a>,
+    pub(crate) holes: Vec<PlotPoints<'a>>,
+    pub(crate) fill_rule: Option<FillRule>,
     pub(crate) stroke: Stroke,
     pub(crate) fill_color: Option<Color32>,
     pub(crate) style: LineStyle,
@@ -30,6 +33,8 @@ impl<'a> Polygon<'a> {
         Self {
             base: PlotItemBase::new(name.into()),
             series: series.into(),
+            holes: Vec::new(),
+            fill_rule: None,
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             fill_color: None,
             style: LineStyle::Solid,
@@ -57,6 +62,25 @@ impl<'a> Polygon<'a> {
         self
     }
 
+    /// Cut a hole in the polygon, given by its boundary. Can be called
+    /// several times.
+    ///
+    /// Polygons with holes are filled according to [`Self::fill_rule`].
+    #[inline]
+    pub fn hole(mut self, hole: impl Into<PlotPoints<'a>>) -> Self {
+        self.holes.push(hole.into());
+        self
+    }
+
+    /// Fill according to the given rule, which also allows non-convex and
+    /// self-intersecting outlines. Default: convex fill without holes,
+    /// [`FillRule::EvenOdd`] with holes.
+    #[inline]
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
     /// Set the outline's style. Default is `LineStyle::Solid`.
     #[inline]
     pub fn style(mut self, style: LineStyle) -> Self {
@@ -96,6 +120,65 @@ impl<'a> Polygon<'a> {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -112,36 +195,45 @@ impl PlotItem for Polygon<'_> {
         let Self {
             base,
             series,
+            holes,
+            fill_rule,
             stroke,
             fill_color,
             style,
-            ..
         } = self;
 
-        let mut values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
+        let rings: Vec<Vec<_>> = std::iter::once(series)
+            .chain(holes)
+            .map(|ring| ring.points().iter().map(|v| transform.position_from_point(v)).collect())
             .collect();
 
         let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
 
-        let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
-        shapes.push(shape);
-
-        if let Some(first) = values_tf.first() {
-            values_tf.push(*first); // close the polygon
+        let fill_rule = fill_rule.or_else(|| (!holes.is_empty()).then_some(FillRule::EvenOdd));
+        if let Some(fill_rule) = fill_rule {
+            shapes.push(Shape::mesh(fill_rule.fill(&rings, fill_color)));
+        } else {
+            shapes.push(Shape::convex_polygon(rings[0].clone(), fill_color, Stroke::NONE));
         }
 
-        style.style_line(
-            values_tf,
-            PathStroke::new(stroke.width, stroke.color),
-            base.highlight,
-            shapes,
-        );
+        for mut ring in rings {
+            if let Some(first) = ring.first() {
+                ring.push(*first); // close the polygon
+            }
+
+            style.style_line(
+                ring,
+                PathStroke::new(stroke.width, stroke.color),
+                base.highlight,
+                shapes,
+            );
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        for hole in &mut self.holes {
+            hole.generate_points(x_range.clone());
+        }
         self.series.generate_points(x_range);
     }
 
@@ -0,0 +1,346 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rot2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::BASE_COLORS;
+use crate::data::PlotPoints;
+use crate::items::Heatmap;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Resolution of the color palette for [`Quiver::color_by_magnitude`].
+const PALETTE_RESOLUTION: usize = 128;
+
+/// How the arrows of a [`Quiver`] are scaled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuiverScale {
+    /// The arrow for `(u, v)` goes from `(x, y)` to `(x + k u, y + k v)` in
+    /// plot coordinates, so it zooms with the plot.
+    Data(f64),
+
+    /// The arrow for `(u, v)` is `k |(u, v)|` ui points long, whatever the
+    /// zoom level. Its direction is still that of `(u, v)` in plot
+    /// coordinates.
+    Screen(f32),
+}
+
+impl<'a> Quiver<'a> {
+    /// Arrows at the given positions, with one `[u, v]` vector per position.
+    pub fn new(
+        name: impl Into<String>,
+        positions: impl Into<PlotPoints<'a>>,
+        vectors: impl IntoIterator<Item = [f64; 2]>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            positions: positions.into(),
+            vectors: vectors.into_iter().collect(),
+            scale: QuiverScale::Data(1.0),
+            tip_length: None,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            palette: None,
+        }
+    }
+
+    /// Set how the arrows are scaled. Default: `QuiverScale::Data(1.0)`.
+    #[inline]
+    pub fn scale(mut self, scale: QuiverScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the length of the arrow tips, in ui points. Default: a quarter of
+    /// each arrow's length.
+    #[inline]
+    pub fn tip_length(mut self, tip_length: f32) -> Self {
+        self.tip_length = Some(tip_length);
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will
+    /// be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Color each arrow by its magnitude instead, through a palette given by
+    /// its base colors from low to high. An empty slice selects the same
+    /// palette as a [`Heatmap`].
+    #[inline]
+    pub fn color_by_magnitude(mut self, base_colors: &[Color32]) -> Self {
+        let base_colors = if base_colors.is_empty() {
+            &BASE_COLORS
+        } else {
+            base_colors
+        };
+        self.palette = Some(Heatmap::linear_gradient_from_base_colors(
+            base_colors,
+            PALETTE_RESOLUTION,
+        ));
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The screen positions of the origin and tip of each arrow.
+    fn arrows<'t>(&'t self, transform: &'t PlotTransform) -> impl Iterator<Item = (Pos2, Pos2)> + 't {
+        self.positions
+            .points()
+            .iter()
+            .zip(&self.vectors)
+            .map(move |(position, [u, v])| {
+                let origin = transform.position_from_point(position);
+                let tip = match self.scale {
+                    QuiverScale::Data(k) => {
+                        transform.position_from_point(&PlotPoint::new(position.x + k * u, position.y + k * v))
+                    }
+                    QuiverScale::Screen(k) => {
+                        let towards = transform.position_from_point(&PlotPoint::new(position.x + u, position.y + v));
+                        origin + (towards - origin).normalized() * k * u.hypot(*v) as f32
+                    }
+                };
+                (origin, tip)
+            })
+    }
+}
+
+/// A vector field: an arrow at each position, given by its `(u, v)`
+/// components.
+///
+/// See also [`crate::Arrows`] to draw arrows between given points.
+pub struct Quiver<'a> {
+    base: PlotItemBase,
+    pub(crate) positions: PlotPoints<'a>,
+    pub(crate) vectors: Vec<[f64; 2]>,
+    pub(crate) scale: QuiverScale,
+    pub(crate) tip_length: Option<f32>,
+    pub(crate) stroke: Stroke,
+
+    /// Colors by magnitude, if any.
+    pub(crate) palette: Option<Vec<Color32>>,
+}
+
+impl PlotItem for Quiver<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+
+        let magnitudes: Vec<f64> = self.vectors.iter().map(|[u, v]| u.hypot(*v)).collect();
+        let max_magnitude = magnitudes.iter().copied().fold(0.0, f64::max);
+
+        let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+        for ((origin, tip), magnitude) in self.arrows(transform).zip(magnitudes) {
+            let vector = tip - origin;
+            if !vector.is_finite() || vector.length() == 0.0 {
+                continue;
+            }
+            let mut stroke = stroke;
+            if let Some(palette) = &self.palette
+                && max_magnitude > 0.0
+            {
+                let index = (magnitude / max_magnitude * (palette.len() - 1) as f64).round() as usize;
+                stroke.color = palette[index];
+            }
+
+            let tip_length = self.tip_length.unwrap_or_else(|| vector.length() / 4.0);
+            let dir = vector.normalized();
+            shapes.push(Shape::line_segment([origin, tip], stroke));
+            shapes.push(Shape::line(
+                vec![
+                    tip - tip_length * (rot.inverse() * dir),
+                    tip,
+                    tip - tip_length * (rot * dir),
+                ],
+                stroke,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.positions.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        match &self.palette {
+            Some(palette) => palette[palette.len() / 2],
+            None => self.stroke.color,
+        }
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.positions.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.positions.bounds();
+        if let QuiverScale::Data(k) = self.scale {
+            for (position, [u, v]) in self.positions.points().iter().zip(&self.vectors) {
+                bounds.extend_with(&PlotPoint::new(position.x + k * u, position.y + k * v));
+            }
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_scaled_bounds_include_tips() {
+        let quiver =
+            Quiver::new("field", vec![[0.0, 0.0], [1.0, 1.0]], [[1.0, 0.0], [0.0, -2.0]]).scale(QuiverScale::Data(0.5));
+        let bounds = quiver.bounds();
+        assert_eq!(bounds.min(), [0.0, 0.0]);
+        assert_eq!(bounds.max(), [1.0, 1.0]);
+
+        let quiver = quiver.scale(QuiverScale::Data(2.0));
+        assert_eq!(quiver.bounds().min(), [0.0, -3.0]);
+        assert_eq!(quiver.bounds().max(), [2.0, 1.0]);
+    }
+}
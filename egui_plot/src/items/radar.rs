@@ -0,0 +1,437 @@
+use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Id;
+use egui::Mesh;
+use egui::Pos2;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use emath::Float as _;
+use emath::NumExt as _;
+use emath::Vec2;
+use emath::vec2;
+
+use crate::Legend;
+use crate::Plot;
+use crate::PlotResponse;
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::DEFAULT_FILL_ALPHA;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+use crate::label::format_number;
+
+/// Room left around the outer ring for the axis names, as a fraction of the
+/// radius.
+const LABEL_MARGIN: f64 = 0.25;
+
+/// A radar (or spider) chart: axes arranged radially around a center, each
+/// with its own range, and one closed polygon per series connecting its
+/// values on every axis.
+///
+/// Like [`crate::PieChart`], the chart sits at a given center and radius in
+/// plot coordinates but stays circular whatever the aspect ratio, with the
+/// radius measured along the X axis. Add it to an existing plot with
+/// [`crate::PlotUi::radar_chart`], or show it on its own with
+/// [`Self::show`].
+///
+/// Each series gets its own legend entry. Hovering a vertex shows the value of
+/// the series on that axis.
+pub struct RadarChart {
+    pub(crate) center: PlotPoint,
+    pub(crate) radius: f64,
+    pub(crate) axes: Vec<(String, RangeInclusive<f64>)>,
+    pub(crate) series: Vec<(String, Vec<f64>)>,
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) rings: usize,
+    pub(crate) fill_alpha: f32,
+    pub(crate) width: f32,
+}
+
+impl RadarChart {
+    /// A radar chart centered on `center`, with the given `radius` along the
+    /// X axis. Add axes with [`Self::axis`] and series with
+    /// [`Self::series`].
+    pub fn new(center: impl Into<PlotPoint>, radius: f64) -> Self {
+        Self {
+            center: center.into(),
+            radius,
+            axes: Vec::new(),
+            series: Vec::new(),
+            colors: Vec::new(),
+            rings: 4,
+            fill_alpha: DEFAULT_FILL_ALPHA,
+            width: 1.5,
+        }
+    }
+
+    /// Add an axis, mapping `range` from the center to the outer ring. Values
+    /// outside the range are clamped to it.
+    ///
+    /// The first axis points up, the others follow clockwise.
+    #[inline]
+    pub fn axis(mut self, name: impl Into<String>, range: RangeInclusive<f64>) -> Self {
+        self.axes.push((name.into(), range));
+        self
+    }
+
+    /// Add a series, with one value per axis, in the order of the axes.
+    #[inline]
+    pub fn series(mut self, name: impl Into<String>, values: impl Into<Vec<f64>>) -> Self {
+        self.series.push((name.into(), values.into()));
+        self
+    }
+
+    /// Set the color of each series. Series without a color get an
+    /// auto-assigned one.
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Number of concentric rings of the grid. Default: `4`.
+    #[inline]
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    /// Opacity of the polygon fills. Default: `0.05`.
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: f32) -> Self {
+        self.fill_alpha = fill_alpha;
+        self
+    }
+
+    /// Stroke width of the polygons. Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show the chart on its own, in a plot without axes, grid or
+    /// navigation, and with a legend.
+    pub fn show(self, ui: &mut Ui, id_salt: impl std::hash::Hash) -> PlotResponse<()> {
+        Plot::new(id_salt)
+            .data_aspect(1.0)
+            .show_axes(false)
+            .show_grid(false)
+            .show_x(false)
+            .show_y(false)
+            .allow_zoom(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .allow_boxed_zoom(false)
+            .legend(Legend::default())
+            .show(ui, |plot_ui| plot_ui.radar_chart(self))
+    }
+
+    /// Build the grid item and one item per series, with the colors filled
+    /// in by `auto_color` where missing.
+    pub(crate) fn into_items(self, mut auto_color: impl FnMut() -> Color32) -> (RadarGrid, Vec<RadarSeries>) {
+        let frame = RadarFrame {
+            center: self.center,
+            radius: self.radius,
+            axes: self.axes,
+        };
+        let series = self
+            .series
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, values))| {
+                let color = self
+                    .colors
+                    .get(index)
+                    .copied()
+                    .filter(|color| *color != Color32::TRANSPARENT)
+                    .unwrap_or_else(&mut auto_color);
+                RadarSeries {
+                    base: PlotItemBase::new(name),
+                    frame: frame.clone(),
+                    values,
+                    color,
+                    fill_alpha: self.fill_alpha,
+                    width: self.width,
+                }
+            })
+            .collect();
+        let mut base = PlotItemBase::new(String::new());
+        base.id = Id::new(("radar_grid", frame.center.x.to_bits(), frame.center.y.to_bits()));
+        base.allow_hover = false;
+        let grid = RadarGrid {
+            base,
+            frame,
+            rings: self.rings,
+        };
+        (grid, series)
+    }
+}
+
+/// Placement and axes shared by the items of a [`RadarChart`].
+#[derive(Clone)]
+pub(crate) struct RadarFrame {
+    center: PlotPoint,
+    radius: f64,
+    axes: Vec<(String, RangeInclusive<f64>)>,
+}
+
+impl RadarFrame {
+    /// Center and outer radius on screen.
+    fn screen_circle(&self, transform: &PlotTransform) -> (Pos2, f32) {
+        let center = transform.position_from_point(&self.center);
+        let radius = (transform.position_from_point_x(self.center.x + self.radius) - center.x).abs();
+        (center, radius)
+    }
+
+    /// Unit vector on screen along the given axis. Screen Y goes down.
+    fn direction(&self, axis: usize) -> Vec2 {
+        let angle = FRAC_PI_2 - TAU * axis as f32 / self.axes.len() as f32;
+        vec2(angle.cos(), -angle.sin())
+    }
+
+    /// Position of `value` along its axis, from `0.0` at the center to `1.0`
+    /// on the outer ring.
+    fn fraction(&self, axis: usize, value: f64) -> f32 {
+        let range = &self.axes[axis].1;
+        let span = range.end() - range.start();
+        if span == 0.0 || !value.is_finite() {
+            return 0.0;
+        }
+        ((value - range.start()) / span).clamp(0.0, 1.0) as f32
+    }
+
+    fn bounds(&self, margin: f64) -> PlotBounds {
+        let r = self.radius * (1.0 + margin);
+        PlotBounds::from_min_max(
+            [self.center.x - r, self.center.y - r],
+            [self.center.x + r, self.center.y + r],
+        )
+    }
+}
+
+/// The rings, spokes and axis names of a [`RadarChart`].
+pub(crate) struct RadarGrid {
+    base: PlotItemBase,
+    frame: RadarFrame,
+    rings: usize,
+}
+
+impl PlotItem for RadarGrid {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let axes = self.frame.axes.len();
+        if axes == 0 {
+            return;
+        }
+        let (center, radius) = self.frame.screen_circle(transform);
+        let stroke = Stroke::new(1.0, ui.visuals().weak_text_color().gamma_multiply(0.5));
+
+        for ring in 1..=self.rings {
+            let r = radius * ring as f32 / self.rings as f32;
+            let corners = (0..axes).map(|axis| center + r * self.frame.direction(axis)).collect();
+            shapes.push(Shape::closed_line(corners, stroke));
+        }
+
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let color = ui.visuals().text_color();
+        for (axis, (name, range)) in self.frame.axes.iter().enumerate() {
+            let direction = self.frame.direction(axis);
+            shapes.push(Shape::line_segment([center, center + radius * direction], stroke));
+
+            // Anchor the label on the side facing the center.
+            let anchor = Align2([
+                if direction.x > 0.3 {
+                    egui::Align::Min
+                } else if direction.x < -0.3 {
+                    egui::Align::Max
+                } else {
+                    egui::Align::Center
+                },
+                if direction.y > 0.3 {
+                    egui::Align::Min
+                } else if direction.y < -0.3 {
+                    egui::Align::Max
+                } else {
+                    egui::Align::Center
+                },
+            ]);
+            let text = format!(
+                "{name}\n{}…{}",
+                format_number(*range.start(), 3),
+                format_number(*range.end(), 3)
+            );
+            ui.fonts_mut(|f| {
+                shapes.push(Shape::text(
+                    f,
+                    center + (radius + 6.0) * direction,
+                    anchor,
+                    text,
+                    font_id.clone(),
+                    color,
+                ));
+            });
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.frame.bounds(LABEL_MARGIN)
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// One series of a [`RadarChart`].
+pub(crate) struct RadarSeries {
+    base: PlotItemBase,
+    frame: RadarFrame,
+    values: Vec<f64>,
+    color: Color32,
+    fill_alpha: f32,
+    width: f32,
+}
+
+impl RadarSeries {
+    /// The screen positions of the vertices, one per axis.
+    fn vertices(&self, transform: &PlotTransform) -> Vec<Pos2> {
+        let (center, radius) = self.frame.screen_circle(transform);
+        (0..self.frame.axes.len())
+            .map(|axis| {
+                let value = self.values.get(axis).copied().unwrap_or(f64::NAN);
+                center + radius * self.frame.fraction(axis, value) * self.frame.direction(axis)
+            })
+            .collect()
+    }
+}
+
+impl PlotItem for RadarSeries {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let vertices = self.vertices(transform);
+        if vertices.is_empty() {
+            return;
+        }
+        let mut stroke = Stroke::new(self.width, self.color);
+        let mut fill_alpha = self.fill_alpha;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+
+        // The polygon is star-shaped around the center, so a fan from there
+        // fills it even where it is not convex.
+        let (center, _) = self.frame.screen_circle(transform);
+        let fill = self.color.gamma_multiply(fill_alpha);
+        let mut mesh = Mesh::default();
+        mesh.colored_vertex(center, fill);
+        for vertex in &vertices {
+            mesh.colored_vertex(*vertex, fill);
+        }
+        let n = vertices.len() as u32;
+        for i in 0..n {
+            mesh.add_triangle(0, 1 + i, 1 + (i + 1) % n);
+        }
+        shapes.push(Shape::mesh(mesh));
+        shapes.push(Shape::closed_line(vertices, stroke));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.frame.bounds(0.0)
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.vertices(transform)
+            .iter()
+            .enumerate()
+            .map(|(index, vertex)| ClosestElem {
+                index,
+                dist_sq: vertex.distance_sq(point),
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let Some(vertex) = self.vertices(plot.transform).get(elem.index).copied() else {
+            return;
+        };
+        shapes.push(Shape::circle_filled(vertex, 3.0 + self.width, self.color));
+
+        let axis = &self.frame.axes[elem.index].0;
+        let value = self.values.get(elem.index).copied().unwrap_or(f64::NAN);
+        show_tooltip(plot_area_response, format!("{}\n{axis}: {value}", self.name()));
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_map_to_their_axis_range() {
+        let chart = RadarChart::new([0.0, 0.0], 1.0)
+            .axis("a", 0.0..=10.0)
+            .axis("b", -1.0..=1.0)
+            .series("s", [5.0, 2.0]);
+        let (_, series) = chart.into_items(|| Color32::RED);
+        let frame = &series[0].frame;
+        assert_eq!(frame.fraction(0, 5.0), 0.5);
+        assert_eq!(frame.fraction(1, 2.0), 1.0);
+        assert_eq!(frame.fraction(1, -1.0), 0.0);
+
+        // The first axis points up.
+        assert!((frame.direction(0) - vec2(0.0, -1.0)).length() < 1e-6);
+    }
+}
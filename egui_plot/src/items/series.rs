@@ -1,7 +1,9 @@
+use std::ops::Range;
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use egui::Color32;
+use egui::Context;
 use egui::Id;
 use egui::Mesh;
 use egui::Rgba;
@@ -12,18 +14,34 @@ use egui::epaint::PathStroke;
 use emath::NumExt as _;
 use emath::Pos2;
 use emath::Rect;
+use emath::Vec2;
 use emath::pos2;
 
 use crate::aesthetics::LineStyle;
+use crate::aesthetics::MarkerShape;
+use crate::aesthetics::StepMode;
 use crate::axis::PlotTransform;
+use crate::axis::YAxis;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
+use crate::colors::BASE_COLORS;
 use crate::colors::DEFAULT_FILL_ALPHA;
 use crate::data::PlotPoints;
+use crate::data::PointsView;
+use crate::data::SeriesKey;
+use crate::downsample;
+use crate::downsample::DownsampleMethod;
+use crate::items::Heatmap;
 use crate::items::PlotGeometry;
 use crate::items::PlotItem;
 use crate::items::PlotItemBase;
+use crate::items::points::push_marker;
+use crate::lod;
 use crate::math::y_intersection;
+use crate::simplify;
+
+/// Resolution of the color palette for [`Line::gradient`].
+const PALETTE_RESOLUTION: usize = 128;
 
 /// A series of values forming a path.
 pub struct Line<'a> {
@@ -34,7 +52,33 @@ pub struct Line<'a> {
     pub(crate) fill_alpha: f32,
     pub(crate) gradient_color: Option<Arc<dyn Fn(PlotPoint) -> Color32 + Send + Sync>>,
     pub(crate) gradient_fill: bool,
+    pub(crate) gradient: Option<Arc<dyn Fn(usize, PlotPoint) -> f64 + Send + Sync>>,
+    pub(crate) gradient_palette: Vec<Color32>,
     pub(crate) style: LineStyle,
+    pub(crate) segment_style: Option<Arc<dyn Fn(usize) -> LineStyle + Send + Sync>>,
+    pub(crate) step: Option<StepMode>,
+
+    /// Largest distance along x between consecutive points that are joined.
+    pub(crate) gap_threshold: Option<f64>,
+
+    /// Shape and radius of the markers drawn at each point, if any.
+    pub(crate) markers: Option<(MarkerShape, f32)>,
+    pub(crate) filled_markers: bool,
+
+    /// How to pick the points to draw, if not all of them.
+    pub(crate) downsample: Option<DownsampleMethod>,
+
+    /// Whether the points are sorted by x, so the ones in view can be found
+    /// by binary search.
+    pub(crate) sorted_x: bool,
+
+    /// Distance in ui points by which the path may move when leaving out
+    /// vertices, if it is simplified.
+    pub(crate) simplify: Option<f32>,
+
+    /// Which version of the series the points are, for the caches of large
+    /// lines.
+    pub(crate) version: Option<u64>,
 }
 
 impl<'a> Line<'a> {
@@ -48,7 +92,18 @@ impl<'a> Line<'a> {
             fill_alpha: DEFAULT_FILL_ALPHA,
             gradient_color: None,
             gradient_fill: false,
+            gradient: None,
+            gradient_palette: Vec::new(),
             style: LineStyle::Solid,
+            segment_style: None,
+            step: None,
+            gap_threshold: None,
+            markers: None,
+            filled_markers: true,
+            downsample: None,
+            sorted_x: false,
+            simplify: None,
+            version: None,
         }
     }
 
@@ -66,6 +121,9 @@ impl<'a> Line<'a> {
     ///
     /// Setting the `gradient_fill` parameter to `true` will use the gradient
     /// color callback for the fill area as well when `fill()` is set.
+    ///
+    /// Replaces [`Self::gradient`]: whichever of the two is set last colors
+    /// the line.
     #[inline]
     pub fn gradient_color(
         mut self,
@@ -74,6 +132,39 @@ impl<'a> Line<'a> {
     ) -> Self {
         self.gradient_color = Some(callback);
         self.gradient_fill = gradient_fill;
+        self.gradient = None;
+        self
+    }
+
+    /// Color the line by a scalar per point, e.g. speed, time or a third
+    /// coordinate. The callback receives the index and position of each
+    /// point; its values are mapped linearly onto a palette given by base
+    /// colors from low to high, and the colors are blended along each
+    /// segment. An empty slice selects the same palette as a [`Heatmap`].
+    ///
+    /// The line is drawn solid, whatever [`Self::style`] is set. The range of
+    /// the values is kept with the levels of detail of the line, see
+    /// [`Self::version`], and the callback is only called for the points
+    /// drawn.
+    ///
+    /// Replaces [`Self::gradient_color`]: whichever of the two is set last
+    /// colors the line.
+    ///
+    /// [`Heatmap`]: crate::Heatmap
+    #[inline]
+    pub fn gradient(
+        mut self,
+        base_colors: &[Color32],
+        value_fn: impl Fn(usize, PlotPoint) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        let base_colors = if base_colors.is_empty() {
+            &BASE_COLORS
+        } else {
+            base_colors
+        };
+        self.gradient = Some(Arc::new(value_fn));
+        self.gradient_color = None;
+        self.gradient_palette = Heatmap::linear_gradient_from_base_colors(base_colors, PALETTE_RESOLUTION);
         self
     }
 
@@ -113,6 +204,114 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Choose the style of each segment of the line with a callback, given
+    /// the index of the segment, e.g. to dash the predicted part of a series.
+    /// Overrides [`Self::style`].
+    ///
+    /// Consecutive segments of the same style are drawn as one path, so
+    /// dash patterns continue across them. With [`Self::step`], every
+    /// horizontal and vertical part of a step counts as a segment.
+    #[inline]
+    pub fn segment_style(mut self, callback: impl Fn(usize) -> LineStyle + Send + Sync + 'static) -> Self {
+        self.segment_style = Some(Arc::new(callback));
+        self
+    }
+
+    /// Draw the line as steps: horizontal segments joined by vertical jumps,
+    /// e.g. for counters, histograms or sampled signals. The fill (see
+    /// [`Self::fill`]) follows the steps. Default: a straight line between
+    /// the points.
+    #[inline]
+    pub fn step(mut self, mode: StepMode) -> Self {
+        self.step = Some(mode);
+        self
+    }
+
+    /// Break the line between consecutive points farther apart than
+    /// `threshold` along x, e.g. where samples are missing, instead of joining
+    /// them with a misleading straight segment.
+    ///
+    /// The line always breaks at points with a non-finite coordinate.
+    #[inline]
+    pub fn gap_threshold(mut self, threshold: f64) -> Self {
+        self.gap_threshold = Some(threshold);
+        self
+    }
+
+    /// Also draw a marker of the given shape and radius, in ui points, at each
+    /// point, in the color of the line. The line and its markers share a
+    /// single legend entry. Default: no markers.
+    #[inline]
+    pub fn markers(mut self, shape: MarkerShape, radius: impl Into<f32>) -> Self {
+        self.markers = Some((shape, radius.into()));
+        self
+    }
+
+    /// Whether to fill the markers, see [`Self::markers`]. Default: `true`.
+    #[inline]
+    pub fn filled_markers(mut self, filled: bool) -> Self {
+        self.filled_markers = filled;
+        self
+    }
+
+    /// Draw only the points in view picked by `method`, so that series of
+    /// millions of points stay fast to draw without reducing them first.
+    /// Hovering, selecting and the bounds of the plot still use all points.
+    ///
+    /// The points have to be sorted by x. With [`Self::segment_style`], the
+    /// segments are counted between the points drawn. Default: all points are
+    /// drawn.
+    #[inline]
+    pub fn downsample(mut self, method: DownsampleMethod) -> Self {
+        self.downsample = Some(method);
+        self
+    }
+
+    /// Promise that the points are sorted by x, so that only the segments in
+    /// view are drawn, found by binary search, e.g. to pan quickly over a
+    /// long recording. This also makes [`Self::downsample`] find the points
+    /// in view faster, the point closest to the pointer be found by binary
+    /// search, and the x range of the bounds be read off the first and the
+    /// last point.
+    ///
+    /// If the points aren't sorted, parts of the line in view may not be
+    /// drawn or hovered. Default: `false`.
+    #[inline]
+    pub fn sorted_x(mut self, sorted: bool) -> Self {
+        self.sorted_x = sorted;
+        self
+    }
+
+    /// Leave out the vertices of the path on screen that move it by less
+    /// than `tolerance` ui points, with the Douglas–Peucker algorithm, so
+    /// that wiggly lines with many vertices per pixel tessellate to far fewer
+    /// triangles. A tolerance below a pixel makes no visible difference.
+    ///
+    /// Markers, hovering and the bounds still use all points. Lines with
+    /// [`Self::gradient`] or [`Self::segment_style`] aren't simplified.
+    /// Default: all vertices are drawn.
+    #[inline]
+    pub fn simplify(mut self, tolerance: f32) -> Self {
+        self.simplify = Some(tolerance);
+        self
+    }
+
+    /// Tell that the points are the same as the last frame this line was
+    /// drawn with the same `version`, except for points appended since, so
+    /// that the levels of detail of large lines, the columns of
+    /// [`DownsampleMethod::MinMax`] and the range of the values of
+    /// [`Self::gradient`] are kept between frames rather than computed anew
+    /// each frame. Change the version whenever points change
+    /// other than by appending.
+    ///
+    /// Not needed for the lines of a [`crate::RetainedSeries`], which keep
+    /// track of their points themselves. Default: no version.
+    #[inline]
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
     /// Name of this plot item.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -145,6 +344,74 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -156,15 +423,47 @@ impl<'a> Line<'a> {
     }
 }
 
-impl PlotItem for Line<'_> {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+impl Line<'_> {
+    /// The runs of consecutive points that are joined, between the gaps.
+    fn runs(&self) -> Vec<Range<usize>> {
+        let points = self.series.view();
+        let is_finite = |i: usize| points.get(i).x.is_finite() && points.get(i).y.is_finite();
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for i in 0..=points.len() {
+            let breaks = i == points.len()
+                || !is_finite(i)
+                || (i > start
+                    && self
+                        .gap_threshold
+                        .is_some_and(|threshold| (points.get(i).x - points.get(i - 1).x).abs() > threshold));
+            if breaks {
+                if i > start {
+                    runs.push(start..i);
+                }
+                start = if i < points.len() && is_finite(i) { i } else { i + 1 };
+            }
+        }
+        runs
+    }
+
+    /// The shapes of a run of joined points, `first_segment` being the index
+    /// of its first segment in the whole line.
+    fn run_shapes(
+        &self,
+        points: PointsView<'_>,
+        point_colors: Option<&[Color32]>,
+        first_segment: usize,
+        transform: &PlotTransform,
+        shapes: &mut Vec<Shape>,
+    ) {
         let Self {
             base,
-            series,
             stroke,
             fill,
             gradient_fill,
             style,
+            step,
             ..
         } = self;
         let mut fill = *fill;
@@ -173,7 +472,7 @@ impl PlotItem for Line<'_> {
         // if we have a gradient color, we need to wrap the stroke callback to transpose
         // the position to a value the caller can reason about
         if let Some(gradient_callback) = self.gradient_color.clone() {
-            let local_transform = *transform;
+            let local_transform = transform.clone();
             let wrapped_callback = move |_rec: Rect, pos: Pos2| -> Color32 {
                 let point = local_transform.value_from_position(pos);
                 gradient_callback(point)
@@ -181,11 +480,16 @@ impl PlotItem for Line<'_> {
             final_stroke = PathStroke::new_uv(stroke.width, wrapped_callback.clone());
         }
 
-        let values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
+        let mut values_tf = points.map(|v| transform.position_from_point(&v));
+        if let Some(step) = step {
+            values_tf = step.steps(&values_tf);
+        }
+        if let Some(tolerance) = self.simplify
+            && point_colors.is_none()
+            && self.segment_style.is_none()
+        {
+            values_tf = simplify::simplify(&values_tf, tolerance);
+        }
         let n_values = values_tf.len();
 
         // Fill the area between the line and a reference line, if required.
@@ -236,15 +540,163 @@ impl PlotItem for Line<'_> {
             mesh.colored_vertex(pos2(last.x, y), fill_color);
             shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
         }
-        style.style_line(values_tf, final_stroke, base.highlight, shapes);
+        if let Some(colors) = point_colors {
+            let mut colors = colors.to_vec();
+            if let Some(step) = step {
+                colors = step_colors(*step, &colors);
+            }
+            let mut width = stroke.width;
+            if base.highlight {
+                width *= 2.0;
+            }
+            shapes.push(Shape::Mesh(Arc::new(gradient_mesh(&values_tf, &colors, width))));
+        } else if let Some(segment_style) = &self.segment_style
+            && n_values >= 2
+        {
+            // Split the path into runs of segments sharing a style.
+            let mut start = 0;
+            while start + 1 < n_values {
+                let run_style = segment_style(first_segment + start);
+                let mut end = start + 1;
+                while end + 1 < n_values && segment_style(first_segment + end) == run_style {
+                    end += 1;
+                }
+                run_style.style_line(
+                    values_tf[start..=end].to_vec(),
+                    final_stroke.clone(),
+                    base.highlight,
+                    shapes,
+                );
+                start = end;
+            }
+        } else {
+            style.style_line(values_tf, final_stroke, base.highlight, shapes);
+        }
+    }
+
+    /// The color of the point at `index`, following the gradient if any, with
+    /// the values of [`Self::gradient`] in `value_range`.
+    fn point_color(&self, points: PointsView<'_>, value_range: (f64, f64), index: usize) -> Color32 {
+        let point = points.get(index);
+        if let Some(value_fn) = &self.gradient {
+            palette_color(&self.gradient_palette, value_range, value_fn(index, point))
+        } else if let Some(color_fn) = &self.gradient_color {
+            color_fn(point)
+        } else {
+            self.stroke.color
+        }
+    }
+}
+
+impl PlotItem for Line<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self { base, series, .. } = self;
+        let points = series.view();
+        let key = series.series_key(self.version);
+        let value_range = self.gradient.as_ref().map_or(EMPTY_RANGE, |value_fn| {
+            cached_value_range(ui.ctx(), base.id.with("gradient_range"), points, key, value_fn.as_ref())
+        });
+        // The colors of the points drawn, only if they follow the gradient.
+        let colors = |indices: &mut dyn Iterator<Item = usize>| -> Option<Vec<Color32>> {
+            self.gradient.is_some().then(|| {
+                indices
+                    .map(|index| self.point_color(points, value_range, index))
+                    .collect()
+            })
+        };
+
+        // Segments drawn for each interval between two points.
+        let segments_per_interval = match self.step {
+            None => 1,
+            Some(StepMode::Pre | StepMode::Post) => 2,
+            Some(StepMode::Mid) => 3,
+        };
+        // Large series are drawn from their levels of detail when zoomed out.
+        let pyramid = (self.downsample.is_none() && points.len() >= lod::LOD_MIN_POINTS)
+            .then(|| lod::cached_pyramid(ui.ctx(), base.id.with("lod"), points, key));
+        let reduced = self.downsample.is_some() || pyramid.is_some() || self.sorted_x;
+
+        // The points drawn, if not all of them.
+        let mut drawn = Vec::new();
+        for run in self.runs() {
+            let run = if self.sorted_x {
+                let visible =
+                    downsample::sorted_visible_range(points.slice(run.clone()), &transform.bounds().range_x());
+                run.start + visible.start..run.start + visible.end
+            } else {
+                run
+            };
+            let indices = if let Some(method) = self.downsample {
+                let visible = if self.sorted_x {
+                    run.clone()
+                } else {
+                    let visible = downsample::visible_range(points.slice(run.clone()), &transform.bounds().range_x());
+                    run.start + visible.start..run.start + visible.end
+                };
+                Some(method.indices(ui.ctx(), base.id, points, key, visible, transform))
+            } else if let Some(pyramid) = &pyramid {
+                pyramid.indices(points, run.clone(), transform)
+            } else {
+                None
+            };
+            if let Some(indices) = indices {
+                let run_points: Vec<PlotPoint> = indices.iter().map(|&index| points.get(index)).collect();
+                let run_colors = colors(&mut indices.iter().copied());
+                self.run_shapes(
+                    run_points[..].into(),
+                    run_colors.as_deref(),
+                    segments_per_interval * drawn.len(),
+                    transform,
+                    shapes,
+                );
+                drawn.extend(indices);
+            } else {
+                self.run_shapes(
+                    points.slice(run.clone()),
+                    colors(&mut run.clone()).as_deref(),
+                    segments_per_interval * run.start,
+                    transform,
+                    shapes,
+                );
+                if reduced {
+                    drawn.extend(run);
+                }
+            }
+        }
+
+        if let Some((shape, mut radius)) = self.markers {
+            if base.highlight {
+                radius *= 2f32.sqrt();
+            }
+            let marked: Vec<usize> = if reduced { drawn } else { (0..points.len()).collect() };
+            for index in marked {
+                let point = points.get(index);
+                if !(point.x.is_finite() && point.y.is_finite()) {
+                    continue;
+                }
+                let color = self.point_color(points, value_range, index);
+                let center = transform.position_from_point(&point);
+                let line_stroke = Stroke::new(radius / 5.0, color);
+                let (fill, stroke) = if self.filled_markers {
+                    (color, Stroke::NONE)
+                } else {
+                    (Color32::TRANSPARENT, line_stroke)
+                };
+                push_marker(shapes, shape, center, radius, fill, stroke, line_stroke);
+            }
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
-        self.series.generate_points(x_range);
+        self.series.query_points(x_range);
     }
 
     fn color(&self) -> Color32 {
-        self.stroke.color
+        if self.gradient.is_some() {
+            self.gradient_palette[self.gradient_palette.len() / 2]
+        } else {
+            self.stroke.color
+        }
     }
 
     fn base(&self) -> &PlotItemBase {
@@ -256,10 +708,253 @@ impl PlotItem for Line<'_> {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.series.points())
+        PlotGeometry::from_view(self.series.view())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        if self.sorted_x {
+            self.series.sorted_x_bounds()
+        } else {
+            self.series.bounds()
+        }
+    }
+
+    fn sorted_x(&self) -> bool {
+        self.sorted_x
+    }
+}
+
+/// The range of no values, to extend with [`value_range`].
+const EMPTY_RANGE: (f64, f64) = (f64::INFINITY, f64::NEG_INFINITY);
+
+/// `range` extended by the finite values of the points of `points` from
+/// `start` on.
+fn value_range(
+    points: PointsView<'_>,
+    start: usize,
+    value_fn: &dyn Fn(usize, PlotPoint) -> f64,
+    range: (f64, f64),
+) -> (f64, f64) {
+    (start..points.len())
+        .map(|index| value_fn(index, points.get(index)))
+        .filter(|value| value.is_finite())
+        .fold(range, |(min, max), value| (min.min(value), max.max(value)))
+}
+
+/// The range of the values of `points`, from the cache under `id` if it was
+/// computed from the same version of the series, or extended from it by the
+/// points appended since. Computed anew every time if the points have no
+/// `key`.
+fn cached_value_range(
+    ctx: &Context,
+    id: Id,
+    points: PointsView<'_>,
+    key: Option<SeriesKey>,
+    value_fn: &dyn Fn(usize, PlotPoint) -> f64,
+) -> (f64, f64) {
+    let Some(key) = key else {
+        return value_range(points, 0, value_fn, EMPTY_RANGE);
+    };
+    let cached = ctx.data(|data| data.get_temp::<(SeriesKey, (f64, f64))>(id));
+    let range = match cached {
+        Some((cached_key, range)) if key.extends(&cached_key) => value_range(points, cached_key.len(), value_fn, range),
+        _ => value_range(points, 0, value_fn, EMPTY_RANGE),
+    };
+    ctx.data_mut(|data| data.insert_temp(id, (key, range)));
+    range
+}
+
+/// The color of `value` on the palette, mapped linearly from the smallest to
+/// the largest value of `range`.
+fn palette_color(palette: &[Color32], (min, max): (f64, f64), value: f64) -> Color32 {
+    let v_rel = if max > min { (value - min) / (max - min) } else { 0.5 };
+    let v_rel = if v_rel.is_finite() { v_rel.clamp(0.0, 1.0) } else { 0.0 };
+    palette[(v_rel * (palette.len() - 1) as f64).round() as usize]
+}
+
+/// Repeat the colors of the points for the step corners that
+/// [`StepMode::steps`] inserts between them.
+fn step_colors(step: StepMode, colors: &[Color32]) -> Vec<Color32> {
+    let mut stepped = Vec::with_capacity(2 * colors.len());
+    for (i, &color) in colors.iter().enumerate() {
+        if i > 0 {
+            let previous = colors[i - 1];
+            match step {
+                StepMode::Pre => stepped.push(color),
+                StepMode::Post => stepped.push(previous),
+                StepMode::Mid => {
+                    stepped.push(previous);
+                    stepped.push(color);
+                }
+            }
+        }
+        stepped.push(color);
+    }
+    stepped
+}
+
+/// A strip of quads along the path with the given vertex colors, so the
+/// colors blend along each segment.
+fn gradient_mesh(points: &[Pos2], colors: &[Color32], width: f32) -> Mesh {
+    let mut mesh = Mesh::default();
+    let n = points.len().min(colors.len());
+    if n < 2 {
+        return mesh;
+    }
+    mesh.reserve_vertices(2 * n);
+    mesh.reserve_triangles(2 * (n - 1));
+
+    let direction = |from: Pos2, to: Pos2| {
+        let dir = (to - from).normalized();
+        dir.is_finite().then_some(dir)
+    };
+    let mut last_dir = Vec2::X;
+    for i in 0..n {
+        let incoming = (i > 0).then(|| direction(points[i - 1], points[i])).flatten();
+        let outgoing = (i + 1 < n).then(|| direction(points[i], points[i + 1])).flatten();
+        let (dir, segment_dir) = match (incoming, outgoing) {
+            (Some(a), Some(b)) => ((a + b).normalized(), b),
+            (Some(a), None) => (a, a),
+            (None, Some(b)) => (b, b),
+            (None, None) => (last_dir, last_dir),
+        };
+        let dir = if dir.is_finite() { dir } else { segment_dir };
+        last_dir = segment_dir;
+
+        // Lengthen the offset at corners so the strip keeps its width
+        // (a miter join), but not without bound at sharp turns.
+        let miter = dir.dot(segment_dir).at_least(0.5);
+        let offset = dir.rot90() * (0.5 * width / miter);
+        mesh.colored_vertex(points[i] + offset, colors[i]);
+        mesh.colored_vertex(points[i] - offset, colors[i]);
+        if i > 0 {
+            let k = 2 * i as u32;
+            mesh.add_triangle(k - 2, k - 1, k);
+            mesh.add_triangle(k - 1, k, k + 1);
+        }
+    }
+    mesh
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    #[test]
+    fn gradient_spans_the_palette() {
+        let palette = [Color32::RED, Color32::GREEN, Color32::BLUE];
+        let points = [
+            PlotPoint::new(0.0, 0.0),
+            PlotPoint::new(1.0, 5.0),
+            PlotPoint::new(2.0, f64::NAN),
+            PlotPoint::new(3.0, 10.0),
+        ];
+        let range = value_range(points[..].into(), 0, &|_, p| p.y, EMPTY_RANGE);
+        assert_eq!(range, (0.0, 10.0), "non-finite values are left out");
+        let colors = [0.0, 5.0, 10.0].map(|value| palette_color(&palette, range, value));
+        assert_eq!(colors, [Color32::RED, Color32::GREEN, Color32::BLUE]);
+
+        let flat = value_range(points[..].into(), 0, &|_, _| 1.0, EMPTY_RANGE);
+        assert_eq!(palette_color(&palette, flat, 1.0), Color32::GREEN);
+
+        // Extending a range by the points from an index on gives the same as
+        // going through all of them.
+        let head = value_range(points[..2].into(), 0, &|_, p| p.y, EMPTY_RANGE);
+        assert_eq!(value_range(points[..].into(), 2, &|_, p| p.y, head), range);
+    }
+
+    #[test]
+    fn cached_gradient_range_follows_the_version() {
+        let ctx = Context::default();
+        let id = Id::new("line");
+        let mut points = PlotPoints::Owned(vec![PlotPoint::new(0.0, 0.0), PlotPoint::new(1.0, 1.0)]);
+        let calls = AtomicUsize::new(0);
+        let value_fn = |_, point: PlotPoint| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            point.y
+        };
+        let range = |points: &PlotPoints<'_>, version| {
+            cached_value_range(&ctx, id, points.view(), points.series_key(version), &value_fn)
+        };
+        assert_eq!(range(&points, Some(0)), (0.0, 1.0));
+
+        let PlotPoints::Owned(owned) = &mut points else {
+            panic!("the points are owned");
+        };
+        owned.push(PlotPoint::new(2.0, 5.0));
+        calls.store(0, Ordering::Relaxed);
+        assert_eq!(range(&points, Some(0)), (0.0, 5.0), "extended by appended points");
+        assert_eq!(calls.load(Ordering::Relaxed), 1, "only the new point is gone through");
+
+        assert_eq!(range(&points, None), (0.0, 5.0));
+        assert_eq!(calls.load(Ordering::Relaxed), 4, "without a version, all points are");
+    }
+
+    #[test]
+    fn step_colors_match_step_corners() {
+        let colors = [Color32::RED, Color32::BLUE];
+        let positions = [pos2(0.0, 0.0), pos2(1.0, 1.0)];
+        for step in [StepMode::Pre, StepMode::Post, StepMode::Mid] {
+            assert_eq!(step_colors(step, &colors).len(), step.steps(&positions).len());
+        }
+        assert_eq!(
+            step_colors(StepMode::Post, &colors),
+            vec![Color32::RED, Color32::RED, Color32::BLUE]
+        );
+    }
+
+    #[test]
+    fn markers_follow_the_line_color() {
+        let points = [PlotPoint::new(0.0, 0.0), PlotPoint::new(1.0, 1.0)];
+        let line = Line::new("line", &points[..])
+            .color(Color32::RED)
+            .markers(MarkerShape::Circle, 3.0);
+        let points = PointsView::from(&points[..]);
+        assert_eq!(line.point_color(points, EMPTY_RANGE, 1), Color32::RED);
+
+        let line = line.gradient_color(Arc::new(|_| Color32::GREEN), false);
+        assert_eq!(line.point_color(points, EMPTY_RANGE, 1), Color32::GREEN);
+
+        // The gradient set last wins.
+        let line = line.gradient(&[Color32::RED, Color32::BLUE], |_, p| p.y);
+        let range = value_range(points, 0, &|_, p| p.y, EMPTY_RANGE);
+        assert_eq!(line.point_color(points, range, 0), Color32::RED);
+        assert_eq!(line.point_color(points, range, 1), Color32::BLUE);
+    }
+
+    #[test]
+    fn lines_break_at_gaps() {
+        let points = vec![
+            [0.0, 0.0],
+            [1.0, 1.0],
+            [2.0, f64::NAN],
+            [3.0, 1.0],
+            [4.0, 2.0],
+            [9.0, 1.0],
+        ];
+        let line = Line::new("line", points);
+        assert_eq!(line.runs(), vec![0..2, 3..6]);
+
+        let line = line.gap_threshold(2.0);
+        assert_eq!(line.runs(), vec![0..2, 3..5, 5..6]);
+    }
+
+    #[test]
+    fn f32_lines_are_hit_without_converting_their_points() {
+        let mut line = Line::new("line", PlotPoints::from_f32(vec![[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]]));
+        line.initialize(f64::NEG_INFINITY..=f64::INFINITY);
+        assert!(
+            matches!(line.geometry(), PlotGeometry::PointsF32(points) if points.len() == 3),
+            "the points stay f32"
+        );
+
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, 0.0], [2.0, 1.0]), false);
+        let pointer = transform.position_from_point(&PlotPoint::new(1.1, 0.9));
+        let closest = line.find_closest(pointer, &transform).map(|closest| closest.index);
+        assert_eq!(closest, Some(1), "the nearest point is found");
     }
 }
@@ -3,6 +3,7 @@ use std::ops::RangeInclusive;
 
 use egui::Align2;
 use egui::Color32;
+use egui::Id;
 use egui::Pos2;
 use egui::Rect;
 use egui::Shape;
@@ -18,6 +19,7 @@ use emath::TSTransform;
 use crate::aesthetics::LineStyle;
 use crate::axis::Axis;
 use crate::axis::PlotTransform;
+use crate::axis::YAxis;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
 use crate::colors::highlighted_color;
@@ -35,6 +37,11 @@ use crate::utils::find_name_candidate;
 const LABEL_PADDING: f32 = 4.0;
 
 /// A span covering a range on either axis.
+///
+/// The span is shaded across the whole plot in the other direction, like an
+/// [`crate::HLine`] or [`crate::VLine`] with an extent, e.g. to mark nighttime,
+/// tolerance bands or alarm regions. See [`Self::vertical`] and
+/// [`Self::horizontal`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct Span {
     base: PlotItemBase,
@@ -60,6 +67,18 @@ impl Span {
         }
     }
 
+    /// A vertical band covering `x_range` over the full height of the plot.
+    #[doc(alias = "VSpan")]
+    pub fn vertical(name: impl Into<String>, x_range: impl Into<RangeInclusive<f64>>) -> Self {
+        Self::new(name, x_range).axis(Axis::X)
+    }
+
+    /// A horizontal band covering `y_range` over the full width of the plot.
+    #[doc(alias = "HSpan")]
+    pub fn horizontal(name: impl Into<String>, y_range: impl Into<RangeInclusive<f64>>) -> Self {
+        Self::new(name, y_range).axis(Axis::Y)
+    }
+
     /// Select which axis the span applies to. This also sets the label
     /// alignment. If you want a different label alignment, you need to set
     /// it by calling `label_align` after this call.
@@ -124,6 +143,108 @@ impl Span {
         self
     }
 
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on,
+    /// and is written in the span.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
     #[inline]
     pub(crate) fn fill_color(&self) -> Color32 {
         self.fill
@@ -317,3 +438,19 @@ impl PlotItem for Span {
         &mut self.base
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertical_and_horizontal_spans() {
+        let night = Span::vertical("night", 20.0..=6.0);
+        assert_eq!(night.axis, Axis::X);
+        assert_eq!(night.range_sorted(), (6.0, 20.0));
+
+        let tolerance = Span::horizontal("tolerance", -0.5..=0.5);
+        assert_eq!(tolerance.axis, Axis::Y);
+        assert_eq!(tolerance.label_align, Align2::LEFT_CENTER);
+    }
+}
@@ -0,0 +1,565 @@
+use std::hash::Hash as _;
+use std::hash::Hasher as _;
+use std::ops::RangeInclusive;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use egui::Color32;
+use egui::ColorImage;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextureHandle;
+use egui::TextureOptions;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::pos2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::BASE_COLORS;
+use crate::cursor::Cursor;
+use crate::items::ClosestElem;
+use crate::items::Heatmap;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+
+/// Resolution of the color palette.
+const PALETTE_RESOLUTION: usize = 256;
+
+/// Source of [`SpectrogramBuffer::key`], so that the textures of different
+/// buffers never get mixed up.
+static NEXT_BUFFER_KEY: AtomicU64 = AtomicU64::new(0);
+
+/// The most recent columns of a spectrogram, e.g. one FFT per audio frame.
+///
+/// Keep it in your app state, push a column whenever one is ready, and show
+/// it with a [`Spectrogram`] every frame. Only the columns pushed since the
+/// last frame are uploaded to the texture.
+pub struct SpectrogramBuffer {
+    /// Unique per buffer, and changed by [`Self::clear`].
+    key: u64,
+
+    bins: usize,
+    capacity: usize,
+
+    /// Ring of `capacity` columns of `bins` values each, lowest frequency
+    /// first.
+    values: Vec<f32>,
+
+    /// Number of columns pushed since creation or the last clear.
+    pushed: u64,
+}
+
+impl SpectrogramBuffer {
+    /// Room for the last `capacity` columns of `bins` frequency bins each.
+    pub fn new(bins: usize, capacity: usize) -> Self {
+        let (bins, capacity) = (bins.max(1), capacity.max(1));
+        Self {
+            key: NEXT_BUFFER_KEY.fetch_add(1, Ordering::Relaxed),
+            bins,
+            capacity,
+            values: vec![f32::NAN; bins * capacity],
+            pushed: 0,
+        }
+    }
+
+    /// Append a column, lowest frequency first, evicting the oldest one when
+    /// full. Missing bins are left empty, extra ones are ignored.
+    pub fn push_column(&mut self, column: &[f32]) {
+        let slot = self.slot(self.pushed);
+        let values = &mut self.values[slot * self.bins..(slot + 1) * self.bins];
+        values.fill(f32::NAN);
+        for (value, &new) in values.iter_mut().zip(column) {
+            *value = new;
+        }
+        self.pushed += 1;
+    }
+
+    /// Remove all columns and restart the time axis.
+    pub fn clear(&mut self) {
+        self.key = NEXT_BUFFER_KEY.fetch_add(1, Ordering::Relaxed);
+        self.values.fill(f32::NAN);
+        self.pushed = 0;
+    }
+
+    /// The number of frequency bins per column.
+    #[inline]
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    /// The number of columns kept.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of columns pushed since creation or the last clear,
+    /// including evicted ones.
+    #[inline]
+    pub fn pushed(&self) -> u64 {
+        self.pushed
+    }
+
+    /// The number of columns held.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pushed.min(self.capacity as u64) as usize
+    }
+
+    /// Whether no column has been pushed yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pushed == 0
+    }
+
+    /// The ring slot of the column with the given index since the start.
+    fn slot(&self, column: u64) -> usize {
+        (column % self.capacity as u64) as usize
+    }
+
+    /// The index since the start of the oldest column held.
+    fn first(&self) -> u64 {
+        self.pushed - self.len() as u64
+    }
+
+    /// The value of a bin of the column with the given index since the start.
+    fn value(&self, column: u64, bin: usize) -> f32 {
+        self.values[self.slot(column) * self.bins + bin]
+    }
+}
+
+/// A spectrogram scrolling horizontally as columns are pushed to its
+/// [`SpectrogramBuffer`].
+///
+/// Time runs along X, one column every [`Self::column_duration`], and
+/// frequency along Y over [`Self::frequency_range`]. The values are colored
+/// through a palette, optionally on a decibel scale.
+pub struct Spectrogram<'a> {
+    base: PlotItemBase,
+    pub(crate) buffer: &'a SpectrogramBuffer,
+    time_origin: f64,
+    column_duration: f64,
+    frequency_range: RangeInclusive<f64>,
+    decibels: bool,
+
+    /// Values mapped to the palette, after the decibel scaling. `None`
+    /// means `0.0..=1.0`, or `-120.0..=0.0` with decibels.
+    range: Option<RangeInclusive<f64>>,
+
+    palette: Vec<Color32>,
+}
+
+impl<'a> Spectrogram<'a> {
+    /// Show the columns held by the buffer.
+    pub fn new(name: impl Into<String>, buffer: &'a SpectrogramBuffer) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            buffer,
+            time_origin: 0.0,
+            column_duration: 1.0,
+            frequency_range: 0.0..=buffer.bins as f64,
+            decibels: false,
+            range: None,
+            palette: Heatmap::linear_gradient_from_base_colors(&BASE_COLORS, PALETTE_RESOLUTION),
+        }
+    }
+
+    /// The time of the first column pushed to the buffer. Default: `0.0`.
+    #[inline]
+    pub fn time_origin(mut self, time: f64) -> Self {
+        self.time_origin = time;
+        self
+    }
+
+    /// The time between two columns, i.e. the width of a column in plot
+    /// coordinates. Default: `1.0`.
+    #[inline]
+    pub fn column_duration(mut self, duration: f64) -> Self {
+        self.column_duration = duration;
+        self
+    }
+
+    /// The frequencies of the lower edge of the first bin and the upper edge
+    /// of the last one, e.g. `0.0..=sample_rate / 2.0`. Default: the bin
+    /// indices.
+    #[inline]
+    pub fn frequency_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.frequency_range = range;
+        self
+    }
+
+    /// Show the values as power in decibels, `10 log10(value)`. Default:
+    /// `false`.
+    #[inline]
+    pub fn decibels(mut self, decibels: bool) -> Self {
+        self.decibels = decibels;
+        self
+    }
+
+    /// Values mapped to the ends of the palette, in decibels if
+    /// [`Self::decibels`] is set; values outside are clamped. Default:
+    /// `0.0..=1.0`, or `-120.0..=0.0` dB.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the color palette by specifying base colors from low to high.
+    #[inline]
+    pub fn palette(mut self, base_colors: &[Color32]) -> Self {
+        self.palette = Heatmap::linear_gradient_from_base_colors(base_colors, PALETTE_RESOLUTION);
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// A value as shown, i.e. after the decibel scaling.
+    fn scaled(&self, value: f32) -> f64 {
+        if self.decibels {
+            10.0 * f64::from(value).log10()
+        } else {
+            f64::from(value)
+        }
+    }
+
+    fn value_range(&self) -> (f64, f64) {
+        match (&self.range, self.decibels) {
+            (Some(range), _) => (*range.start(), *range.end()),
+            (None, false) => (0.0, 1.0),
+            (None, true) => (-120.0, 0.0),
+        }
+    }
+
+    /// The pixels of one column, highest frequency at the top.
+    fn column_pixels(&self, column: u64) -> Vec<Color32> {
+        let (min, max) = self.value_range();
+        let span = max - min;
+        let last = (self.palette.len() - 1) as f64;
+        (0..self.buffer.bins)
+            .rev()
+            .map(|bin| {
+                let value = self.scaled(self.buffer.value(column, bin));
+                if !(value.is_finite() || self.decibels && value == f64::NEG_INFINITY) {
+                    Color32::TRANSPARENT
+                } else if span > 0.0 {
+                    // Silence in decibels is -inf, which clamps to the bottom of the palette.
+                    let v_rel = ((value - min) / span).clamp(0.0, 1.0);
+                    self.palette[(v_rel * last).round() as usize]
+                } else {
+                    self.palette[0]
+                }
+            })
+            .collect()
+    }
+
+    /// The ring texture of the buffer, with the columns pushed since the last
+    /// frame uploaded. It is only rebuilt as a whole when the buffer, range
+    /// or palette change.
+    fn texture(&self, ui: &Ui) -> TextureHandle {
+        let buffer = self.buffer;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        buffer.key.hash(&mut hasher);
+        buffer.bins.hash(&mut hasher);
+        buffer.capacity.hash(&mut hasher);
+        self.decibels.hash(&mut hasher);
+        let (min, max) = self.value_range();
+        (min.to_bits(), max.to_bits()).hash(&mut hasher);
+        self.palette.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let id = self.base.id.with("spectrogram_texture");
+        let cached = ui.data(|d| d.get_temp::<(u64, u64, TextureHandle)>(id));
+        let texture = match cached {
+            Some((cached_key, uploaded, mut texture))
+                if cached_key == key && buffer.pushed - uploaded <= buffer.capacity as u64 =>
+            {
+                for column in uploaded..buffer.pushed {
+                    let image = ColorImage::new([1, buffer.bins], self.column_pixels(column));
+                    texture.set_partial([buffer.slot(column), 0], image, TextureOptions::NEAREST);
+                }
+                texture
+            }
+            _ => {
+                let mut pixels = vec![Color32::TRANSPARENT; buffer.capacity * buffer.bins];
+                for column in buffer.first()..buffer.pushed {
+                    let slot = buffer.slot(column);
+                    for (row, color) in self.column_pixels(column).into_iter().enumerate() {
+                        pixels[row * buffer.capacity + slot] = color;
+                    }
+                }
+                let image = ColorImage::new([buffer.capacity, buffer.bins], pixels);
+                ui.ctx()
+                    .load_texture(self.base.name.clone(), image, TextureOptions::NEAREST)
+            }
+        };
+        ui.data_mut(|d| d.insert_temp(id, (key, buffer.pushed, texture.clone())));
+        texture
+    }
+
+    /// The time at the left edge of the column with the given index since
+    /// the start.
+    fn time(&self, column: u64) -> f64 {
+        self.time_origin + column as f64 * self.column_duration
+    }
+
+    /// The screen rectangle of the columns `start..end`, given as indices
+    /// since the start.
+    fn screen_rect(&self, transform: &PlotTransform, start: u64, end: u64) -> Rect {
+        Rect::from_two_pos(
+            transform.position_from_point(&PlotPoint::new(self.time(start), *self.frequency_range.end())),
+            transform.position_from_point(&PlotPoint::new(self.time(end), *self.frequency_range.start())),
+        )
+    }
+}
+
+impl PlotItem for Spectrogram<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let buffer = self.buffer;
+        if buffer.is_empty() {
+            return;
+        }
+        let texture = self.texture(ui);
+
+        // The held columns are contiguous in time, but may wrap around the
+        // end of the ring: draw them as up to two parts.
+        let capacity = buffer.capacity as u64;
+        let (first, end) = (buffer.first(), buffer.pushed);
+        let wrap = (first / capacity + 1) * capacity;
+        for (start, end) in [(first, end.min(wrap)), (wrap, end)] {
+            if start >= end {
+                continue;
+            }
+            let u0 = buffer.slot(start) as f32 / capacity as f32;
+            let u1 = u0 + (end - start) as f32 / capacity as f32;
+            shapes.push(Shape::image(
+                texture.id(),
+                self.screen_rect(transform, start, end),
+                Rect::from_min_max(pos2(u0, 0.0), pos2(u1, 1.0)),
+                Color32::WHITE,
+            ));
+        }
+
+        if self.base.highlight {
+            shapes.push(Shape::rect_stroke(
+                self.screen_rect(transform, first, end),
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+                egui::StrokeKind::Outside,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.palette[self.palette.len() / 2]
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if self.buffer.is_empty() {
+            return PlotBounds::NOTHING;
+        }
+        PlotBounds::from_min_max(
+            [self.time(self.buffer.first()), *self.frequency_range.start()],
+            [self.time(self.buffer.pushed), *self.frequency_range.end()],
+        )
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let buffer = self.buffer;
+        let rect = self.screen_rect(transform, buffer.first(), buffer.pushed);
+        if buffer.is_empty() || !rect.contains(point) {
+            return None;
+        }
+        let offset = (point - rect.min) / rect.size();
+        let column = ((offset.x * buffer.len() as f32) as usize).min(buffer.len() - 1);
+        let row = ((offset.y * buffer.bins as f32) as usize).min(buffer.bins - 1);
+        Some(ClosestElem {
+            index: column * buffer.bins + (buffer.bins - 1 - row),
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        _shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        _plot: &PlotConfig<'_>,
+        _label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let buffer = self.buffer;
+        let (column, bin) = (elem.index / buffer.bins, elem.index % buffer.bins);
+        let column = buffer.first() + column as u64;
+        let (low, high) = (*self.frequency_range.start(), *self.frequency_range.end());
+        let frequency = low + (bin as f64 + 0.5) * (high - low) / buffer.bins as f64;
+        let value = self.scaled(buffer.value(column, bin));
+        let unit = if self.decibels { " dB" } else { "" };
+        show_tooltip(
+            plot_area_response,
+            format!(
+                "{}\nt = {:.3}, f = {frequency:.1}: {value:.2}{unit}",
+                self.name(),
+                self.time(column)
+            ),
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_evicts_the_oldest_column() {
+        let mut buffer = SpectrogramBuffer::new(2, 3);
+        for i in 0..5 {
+            buffer.push_column(&[i as f32, 10.0 * i as f32]);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.first(), 2);
+        assert_eq!(buffer.value(2, 1), 20.0);
+        assert_eq!(buffer.value(4, 0), 4.0);
+
+        let spectrogram = Spectrogram::new("spectrogram", &buffer)
+            .time_origin(1.0)
+            .column_duration(0.5);
+        let bounds = spectrogram.bounds();
+        assert_eq!(bounds.min(), [2.0, 0.0]);
+        assert_eq!(bounds.max(), [3.5, 2.0]);
+    }
+
+    #[test]
+    fn silence_is_the_bottom_of_the_palette() {
+        let mut buffer = SpectrogramBuffer::new(2, 1);
+        buffer.push_column(&[0.0, 1.0]);
+        let spectrogram = Spectrogram::new("spectrogram", &buffer)
+            .decibels(true)
+            .palette(&[Color32::BLACK, Color32::WHITE]);
+
+        // Highest frequency first.
+        assert_eq!(spectrogram.column_pixels(0), [Color32::WHITE, Color32::BLACK]);
+    }
+}
@@ -0,0 +1,361 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Mesh;
+use egui::Pos2;
+use egui::Rgba;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::NumExt as _;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::cursor::Cursor;
+use crate::items::Bar;
+use crate::items::BarChart;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::show_tooltip;
+use crate::label::LabelFormatter;
+
+/// A stacked area chart: several series over the same x values, each drawn
+/// on top of the sum of the previous ones.
+///
+/// Each series becomes its own plot item, with its own legend entry. Hovering
+/// a band reports the value of its series, not the accumulated one.
+///
+/// Add it with [`crate::PlotUi::stacked_lines`].
+pub struct StackedLines {
+    pub(crate) xs: Vec<f64>,
+    pub(crate) series: Vec<(String, Vec<f64>)>,
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) width: f32,
+    pub(crate) fill_alpha: f32,
+}
+
+impl StackedLines {
+    /// A stack over the given x values. Add series with [`Self::series`].
+    pub fn new(xs: impl Into<Vec<f64>>) -> Self {
+        Self {
+            xs: xs.into(),
+            series: Vec::new(),
+            colors: Vec::new(),
+            width: 1.5,
+            fill_alpha: 0.5,
+        }
+    }
+
+    /// Add a series on top of the previous ones, with one value per x value.
+    ///
+    /// Missing values count as `0.0`, extra ones are ignored.
+    #[inline]
+    pub fn series(mut self, name: impl Into<String>, ys: impl Into<Vec<f64>>) -> Self {
+        self.series.push((name.into(), ys.into()));
+        self
+    }
+
+    /// Set the color of each series, from the bottom up. Series without a
+    /// color get an auto-assigned one.
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Stroke width of the line at the top of each band. `0.0` for no lines.
+    /// Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the alpha of the band fills. Default: `0.5`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Accumulate the series into bands, with the colors filled in by
+    /// `auto_color` where missing.
+    pub(crate) fn into_bands(self, mut auto_color: impl FnMut() -> Color32) -> Vec<StackedBand> {
+        let mut bottom = vec![0.0; self.xs.len()];
+        self.series
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, ys))| {
+                let values: Vec<f64> = (0..self.xs.len()).map(|i| ys.get(i).copied().unwrap_or(0.0)).collect();
+                let top: Vec<f64> = bottom
+                    .iter()
+                    .zip(&values)
+                    .map(|(bottom, value)| bottom + value)
+                    .collect();
+                let color = self
+                    .colors
+                    .get(index)
+                    .copied()
+                    .filter(|color| *color != Color32::TRANSPARENT)
+                    .unwrap_or_else(&mut auto_color);
+                let band = StackedBand {
+                    base: PlotItemBase::new(name),
+                    lower: self
+                        .xs
+                        .iter()
+                        .zip(&bottom)
+                        .map(|(&x, &y)| PlotPoint::new(x, y))
+                        .collect(),
+                    upper: self.xs.iter().zip(&top).map(|(&x, &y)| PlotPoint::new(x, y)).collect(),
+                    values,
+                    color,
+                    width: self.width,
+                    fill_alpha: self.fill_alpha,
+                };
+                bottom = top;
+                band
+            })
+            .collect()
+    }
+}
+
+/// One series of a [`StackedLines`].
+pub(crate) struct StackedBand {
+    base: PlotItemBase,
+
+    /// The accumulated values below and including this series.
+    lower: Vec<PlotPoint>,
+    upper: Vec<PlotPoint>,
+
+    /// The values of this series alone.
+    values: Vec<f64>,
+
+    color: Color32,
+    width: f32,
+    fill_alpha: f32,
+}
+
+impl PlotItem for StackedBand {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let upper: Vec<Pos2> = self.upper.iter().map(|p| transform.position_from_point(p)).collect();
+        let lower: Vec<Pos2> = self.lower.iter().map(|p| transform.position_from_point(p)).collect();
+
+        let mut fill_alpha = self.fill_alpha;
+        let mut width = self.width;
+        if self.base.highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+            width *= 2.0;
+        }
+        let fill_color: Color32 = Rgba::from(self.color).to_opaque().multiply(fill_alpha).into();
+
+        let n = upper.len() as u32;
+        let mut mesh = Mesh::default();
+        for (&u, &l) in upper.iter().zip(&lower) {
+            mesh.colored_vertex(u, fill_color);
+            mesh.colored_vertex(l, fill_color);
+        }
+        for i in 0..n.saturating_sub(1) {
+            mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+            mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if width > 0.0 {
+            shapes.push(Shape::line(upper, Stroke::new(width, self.color)));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.upper)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.lower.iter().chain(&self.upper) {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &Option<LabelFormatter<'_>>,
+    ) {
+        let line_color = if plot.ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+
+        // The marker and rulers go on the stacked value…
+        let stacked = self.upper[elem.index];
+        shapes.push(Shape::circle_filled(
+            plot.transform.position_from_point(&stacked),
+            3.0,
+            line_color,
+        ));
+        if plot.show_crosshair {
+            if plot.show_x {
+                cursors.push(Cursor::Vertical { x: stacked.x });
+            }
+            if plot.show_y {
+                cursors.push(Cursor::Horizontal { y: stacked.y });
+            }
+        }
+
+        // …but the readout shows the value of this series alone.
+        if let Some(custom_label) = label_formatter {
+            let value = PlotPoint::new(stacked.x, self.values[elem.index]);
+            show_tooltip(plot_area_response, custom_label(self.name(), &value));
+        }
+    }
+}
+
+/// A stacked bar chart: several segments per argument, each stacked on the
+/// previous ones. Positive values stack upwards from zero, negative values
+/// downwards.
+///
+/// Each segment becomes its own [`BarChart`], with its own color and legend
+/// entry. Hovering a bar reports the value of its segment.
+///
+/// Add it with [`crate::PlotUi::stacked_bars`].
+pub struct StackedBars {
+    pub(crate) arguments: Vec<f64>,
+    pub(crate) segments: Vec<(String, Vec<f64>)>,
+    pub(crate) colors: Vec<Color32>,
+    pub(crate) width: f64,
+    pub(crate) horizontal: bool,
+}
+
+impl StackedBars {
+    /// A stack of bars at the given arguments (x positions, or y positions
+    /// when [`Self::horizontal`]). Add segments with [`Self::segment`].
+    pub fn new(arguments: impl Into<Vec<f64>>) -> Self {
+        Self {
+            arguments: arguments.into(),
+            segments: Vec::new(),
+            colors: Vec::new(),
+            width: 0.5,
+            horizontal: false,
+        }
+    }
+
+    /// Add a segment on top of the previous ones, with one value per argument.
+    ///
+    /// Missing values count as `0.0`, extra ones are ignored.
+    #[inline]
+    pub fn segment(mut self, name: impl Into<String>, values: impl Into<Vec<f64>>) -> Self {
+        self.segments.push((name.into(), values.into()));
+        self
+    }
+
+    /// Set the color of each segment, from the first one on. Segments without
+    /// a color get an auto-assigned one.
+    #[inline]
+    pub fn colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.colors = colors.into();
+        self
+    }
+
+    /// Set the width (thickness) of the bars. Default: `0.5`.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Lay the bars out horizontally: arguments on the Y axis, values on the
+    /// X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Build one chart per segment, with the colors filled in by
+    /// `auto_color` where missing.
+    pub(crate) fn into_charts(self, mut auto_color: impl FnMut() -> Color32) -> Vec<BarChart> {
+        let mut positive = vec![0.0; self.arguments.len()];
+        let mut negative = vec![0.0; self.arguments.len()];
+        self.segments
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, values))| {
+                let bars = self
+                    .arguments
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &argument)| {
+                        let value = values.get(i).copied().unwrap_or(0.0);
+                        let stack = if value < 0.0 {
+                            &mut negative[i]
+                        } else {
+                            &mut positive[i]
+                        };
+                        let bar = Bar::new(argument, value).base_offset(*stack).width(self.width);
+                        *stack += value;
+                        if self.horizontal { bar.horizontal() } else { bar }
+                    })
+                    .collect();
+                let color = self
+                    .colors
+                    .get(index)
+                    .copied()
+                    .filter(|color| *color != Color32::TRANSPARENT)
+                    .unwrap_or_else(&mut auto_color);
+                BarChart::new(name, bars).color(color)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bands_accumulate() {
+        let bands = StackedLines::new([0.0, 1.0])
+            .series("a", [1.0, 2.0])
+            .series("b", [3.0])
+            .into_bands(|| Color32::RED);
+        assert_eq!(bands[1].lower, [PlotPoint::new(0.0, 1.0), PlotPoint::new(1.0, 2.0)]);
+        assert_eq!(bands[1].upper, [PlotPoint::new(0.0, 4.0), PlotPoint::new(1.0, 2.0)]);
+        assert_eq!(bands[1].values, [3.0, 0.0]);
+    }
+
+    #[test]
+    fn negative_segments_stack_downwards() {
+        let charts = StackedBars::new([0.0])
+            .segment("a", [1.0])
+            .segment("b", [-2.0])
+            .segment("c", [3.0])
+            .segment("d", [-1.0])
+            .into_charts(|| Color32::RED);
+        let offsets: Vec<_> = charts.iter().map(|chart| chart.bars[0].base_offset).collect();
+        assert_eq!(offsets, [Some(0.0), Some(0.0), Some(1.0), Some(-2.0)]);
+    }
+}
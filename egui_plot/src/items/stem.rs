@@ -0,0 +1,284 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::pos2;
+
+use crate::aesthetics::MarkerShape;
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::data::PlotPoints;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::points::push_marker;
+
+impl<'a> Stem<'a> {
+    /// A stem from the baseline to each point, with a marker at the tip.
+    pub fn new(name: impl Into<String>, series: impl Into<PlotPoints<'a>>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            series: series.into(),
+            baseline: 0.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            shape: Some(MarkerShape::Circle),
+            filled: true,
+            radius: 3.0,
+        }
+    }
+
+    /// The Y value the stems start from. Default: `0.0`.
+    #[inline]
+    pub fn baseline(mut self, baseline: impl Into<f64>) -> Self {
+        self.baseline = baseline.into();
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width of the stems.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Color of the stems and markers. Default is `Color32::TRANSPARENT`
+    /// which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the shape of the markers at the tips, or `None` for bare stems.
+    /// Default: [`MarkerShape::Circle`].
+    #[inline]
+    pub fn marker(mut self, shape: impl Into<Option<MarkerShape>>) -> Self {
+        self.shape = shape.into();
+        self
+    }
+
+    /// Whether to fill the markers. Default: `true`.
+    #[inline]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Set the maximum extent of the markers around the tips, in ui points.
+    /// Default: `3.0`.
+    #[inline]
+    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Allow dragging the elements of this item with the pan button. While
+    /// one is dragged, [`crate::PlotResponse::dragged_point`] reports its
+    /// index and where to, and the plot doesn't pan. Default: `false`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.base_mut().draggable = draggable;
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+}
+
+/// A stem (or lollipop) plot: a vertical line from a baseline to each point,
+/// with a marker at the tip.
+///
+/// Useful for discrete signals and impulse responses.
+pub struct Stem<'a> {
+    base: PlotItemBase,
+
+    pub(crate) series: PlotPoints<'a>,
+
+    pub(crate) baseline: f64,
+
+    pub(crate) stroke: Stroke,
+
+    /// `None` for no markers.
+    pub(crate) shape: Option<MarkerShape>,
+
+    pub(crate) filled: bool,
+
+    /// The maximum extent of the markers from the tips.
+    pub(crate) radius: f32,
+}
+
+impl PlotItem for Stem<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        let mut radius = self.radius;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+            radius *= 2f32.sqrt();
+        }
+        let (fill, marker_stroke) = if self.filled {
+            (stroke.color, Stroke::NONE)
+        } else {
+            (Color32::TRANSPARENT, stroke)
+        };
+
+        for point in self.series.points() {
+            let tip = transform.position_from_point(point);
+            let base = transform.position_from_point_y(self.baseline);
+            shapes.push(Shape::line_segment([pos2(tip.x, base), tip], stroke));
+            if let Some(shape) = self.shape {
+                push_marker(shapes, shape, tip, radius, fill, marker_stroke, stroke);
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.series.bounds();
+        if bounds.is_valid_x() {
+            bounds.extend_with(&PlotPoint::new(bounds.min()[0], self.baseline));
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_include_baseline() {
+        let stem = Stem::new("stem", vec![[0.0, 1.0], [1.0, 2.0]]).baseline(-1.0);
+        let bounds = stem.bounds();
+        assert_eq!(bounds.min(), [0.0, -1.0]);
+        assert_eq!(bounds.max(), [1.0, 2.0]);
+    }
+}
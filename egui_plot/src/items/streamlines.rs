@@ -0,0 +1,442 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rot2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+
+/// Number of cells across the occupancy mask for a density of `1.0`.
+const MASK_CELLS: f64 = 30.0;
+
+/// Integration step, in mask cells.
+const STEP: f64 = 0.1;
+
+/// Upper limit on the number of integration steps in each direction.
+const MAX_STEPS: usize = 10_000;
+
+impl Streamlines {
+    /// Streamlines of the vector field with components `u` and `v`.
+    ///
+    /// - `u` and `v` are sampled on the same regular grid of nodes, row by
+    ///   row, starting at the bottom, like for a [`crate::Contour`].
+    /// - `cols` is the number of columns (i.e. the length of each row).
+    /// - `u.len()` should equal `v.len()` and be a multiple of `cols`,
+    ///   otherwise nothing is drawn.
+    ///
+    /// By default the grid spans `0..=cols - 1` and `0..=rows - 1`, see
+    /// [`Self::extent`].
+    pub fn new(name: impl Into<String>, u: impl Into<Vec<f64>>, v: impl Into<Vec<f64>>, cols: usize) -> Self {
+        let (u, v) = (u.into(), v.into());
+        let rows = if cols == 0 || u.len() != v.len() || u.len() % cols != 0 {
+            0
+        } else {
+            u.len() / cols
+        };
+        Self {
+            base: PlotItemBase::new(name.into()),
+            u,
+            v,
+            cols,
+            rows,
+            pos: PlotPoint::new(0.0, 0.0),
+            size: [cols.saturating_sub(1) as f64, rows.saturating_sub(1) as f64],
+            density: 1.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            arrow_size: 6.0,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Place the grid over the given ranges in plot coordinates: the first
+    /// node is at the start of both ranges, the last one at their end.
+    #[inline]
+    pub fn extent(mut self, x: RangeInclusive<f64>, y: RangeInclusive<f64>) -> Self {
+        self.pos = PlotPoint::new(*x.start(), *y.start());
+        self.size = [x.end() - x.start(), y.end() - y.start()];
+        self
+    }
+
+    /// How closely the streamlines are packed. At `1.0`, the domain is split
+    /// into 30×30 cells, each crossed by at most one streamline. Default:
+    /// `1.0`.
+    #[inline]
+    pub fn density(mut self, density: f64) -> Self {
+        self.density = density.max(0.01);
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will
+    /// be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Size of the arrowhead halfway along each streamline, in ui points.
+    /// `0.0` for no arrowheads. Default: `6.0`.
+    #[inline]
+    pub fn arrow_size(mut self, arrow_size: impl Into<f32>) -> Self {
+        self.arrow_size = arrow_size.into();
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    #[inline]
+    pub fn hoverable(mut self, hoverable: bool) -> Self {
+        self.base_mut().allow_hover = hoverable;
+        self
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// The velocity at grid coordinates `(gx, gy)`, in grid cells per unit of
+    /// time, interpolated bilinearly. `None` outside of the grid or where the
+    /// field is not finite.
+    fn velocity(&self, gx: f64, gy: f64) -> Option<(f64, f64)> {
+        let (max_x, max_y) = ((self.cols - 1) as f64, (self.rows - 1) as f64);
+        if !(0.0..=max_x).contains(&gx) || !(0.0..=max_y).contains(&gy) {
+            return None;
+        }
+        let (i, j) = (
+            (gx.floor() as usize).min(self.cols - 2),
+            (gy.floor() as usize).min(self.rows - 2),
+        );
+        let (tx, ty) = (gx - i as f64, gy - j as f64);
+        let bilinear = |values: &[f64]| {
+            let at = |col: usize, row: usize| values[row * self.cols + col];
+            (1.0 - ty) * ((1.0 - tx) * at(i, j) + tx * at(i + 1, j))
+                + ty * ((1.0 - tx) * at(i, j + 1) + tx * at(i + 1, j + 1))
+        };
+
+        // Convert from plot units to grid cells.
+        let u = bilinear(&self.u) * max_x / self.size[0];
+        let v = bilinear(&self.v) * max_y / self.size[1];
+        (u.is_finite() && v.is_finite()).then_some((u, v))
+    }
+
+    /// Integrate the streamlines, in plot coordinates.
+    fn integrate(&self) -> Vec<Vec<PlotPoint>> {
+        if self.rows < 2 || self.cols < 2 || self.size[0] == 0.0 || self.size[1] == 0.0 {
+            return Vec::new();
+        }
+
+        // Each cell of the mask is crossed by at most one streamline.
+        let mask_size = [
+            (MASK_CELLS * self.density).round().max(1.0) as usize,
+            (MASK_CELLS * self.density).round().max(1.0) as usize,
+        ];
+        let mut mask = vec![false; mask_size[0] * mask_size[1]];
+        let cells_per_grid = [
+            mask_size[0] as f64 / (self.cols - 1) as f64,
+            mask_size[1] as f64 / (self.rows - 1) as f64,
+        ];
+        let mask_cell = |gx: f64, gy: f64| {
+            let mx = ((gx * cells_per_grid[0]) as usize).min(mask_size[0] - 1);
+            let my = ((gy * cells_per_grid[1]) as usize).min(mask_size[1] - 1);
+            my * mask_size[0] + mx
+        };
+
+        // Move at constant speed, in mask cells.
+        let step = |gx: f64, gy: f64, direction: f64| {
+            let (u, v) = self.velocity(gx, gy)?;
+            let (u, v) = (u * cells_per_grid[0], v * cells_per_grid[1]);
+            let speed = u.hypot(v);
+            (speed > 0.0).then(|| {
+                (
+                    direction * STEP * u / speed / cells_per_grid[0],
+                    direction * STEP * v / speed / cells_per_grid[1],
+                )
+            })
+        };
+
+        let mut lines = Vec::new();
+        for seed in 0..mask.len() {
+            if mask[seed] {
+                continue;
+            }
+            let start = (
+                ((seed % mask_size[0]) as f64 + 0.5) / cells_per_grid[0],
+                ((seed / mask_size[0]) as f64 + 0.5) / cells_per_grid[1],
+            );
+            if self.velocity(start.0, start.1).is_none() {
+                continue;
+            }
+
+            mask[seed] = true;
+            let mut visited = vec![seed];
+            let mut halves = [-1.0, 1.0].map(|direction| {
+                let mut points = Vec::new();
+                let (mut gx, mut gy) = start;
+                let mut cell = seed;
+                for _ in 0..MAX_STEPS {
+                    // Midpoint method.
+                    let Some((dx, dy)) = step(gx, gy, direction) else {
+                        break;
+                    };
+                    let Some((dx, dy)) = step(gx + 0.5 * dx, gy + 0.5 * dy, direction) else {
+                        break;
+                    };
+                    let (next_x, next_y) = (gx + dx, gy + dy);
+                    if self.velocity(next_x, next_y).is_none() {
+                        break;
+                    }
+                    let next_cell = mask_cell(next_x, next_y);
+                    if next_cell != cell {
+                        if mask[next_cell] {
+                            break;
+                        }
+                        mask[next_cell] = true;
+                        visited.push(next_cell);
+                        cell = next_cell;
+                    }
+                    (gx, gy) = (next_x, next_y);
+                    points.push((gx, gy));
+                }
+                points
+            });
+
+            // Drop streamlines too short to show a direction.
+            if visited.len() < 3 {
+                for cell in visited {
+                    mask[cell] = false;
+                }
+                mask[seed] = true;
+                continue;
+            }
+
+            let [backward, forward] = &mut halves;
+            backward.reverse();
+            let line = backward
+                .iter()
+                .chain([&start])
+                .chain(forward.iter())
+                .map(|&(gx, gy)| {
+                    PlotPoint::new(
+                        self.pos.x + gx / (self.cols - 1) as f64 * self.size[0],
+                        self.pos.y + gy / (self.rows - 1) as f64 * self.size[1],
+                    )
+                })
+                .collect();
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Streamlines of a 2D vector field sampled on a regular grid: curves
+/// following the flow, spread evenly over the domain, with an arrowhead
+/// showing the direction.
+///
+/// See also [`crate::Quiver`] to draw the vectors themselves.
+pub struct Streamlines {
+    base: PlotItemBase,
+
+    u: Vec<f64>,
+    v: Vec<f64>,
+    cols: usize,
+    rows: usize,
+
+    /// Position of the first node.
+    pos: PlotPoint,
+
+    /// Distance from the first to the last node, in plot coordinates.
+    size: [f64; 2],
+
+    density: f64,
+    pub(crate) stroke: Stroke,
+    arrow_size: f32,
+
+    /// The integrated streamlines, in plot coordinates.
+    lines: Vec<Vec<PlotPoint>>,
+}
+
+impl PlotItem for Streamlines {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        let mut arrow_size = self.arrow_size;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+            arrow_size *= 2f32.sqrt();
+        }
+
+        let rot = Rot2::from_angle(std::f32::consts::TAU / 12.0);
+        for line in &self.lines {
+            let points: Vec<Pos2> = line.iter().map(|point| transform.position_from_point(point)).collect();
+
+            if arrow_size > 0.0 && points.len() >= 2 {
+                let middle = points.len() / 2;
+                let dir = (points[middle] - points[middle - 1]).normalized();
+                if dir.is_finite() {
+                    let tip = points[middle];
+                    shapes.push(Shape::convex_polygon(
+                        vec![
+                            tip,
+                            tip - arrow_size * (rot * dir),
+                            tip - arrow_size * (rot.inverse() * dir),
+                        ],
+                        stroke.color,
+                        Stroke::NONE,
+                    ));
+                }
+            }
+            shapes.push(Shape::line(points, stroke));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        self.lines = self.integrate();
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if self.rows == 0 {
+            return PlotBounds::NOTHING;
+        }
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.pos);
+        bounds.extend_with(&PlotPoint::new(self.pos.x + self.size[0], self.pos.y + self.size[1]));
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_flow_gives_straight_lines() {
+        let streamlines = Streamlines::new("flow", vec![1.0; 9], vec![0.0; 9], 3).density(0.1);
+        let lines = streamlines.integrate();
+        assert!(!lines.is_empty());
+        for line in &lines {
+            let y = line[0].y;
+            assert!(line.iter().all(|point| (point.y - y).abs() < 1e-9));
+            assert!(line.windows(2).all(|w| w[1].x > w[0].x));
+        }
+    }
+}
@@ -9,6 +9,7 @@ use egui::Ui;
 use egui::WidgetText;
 use egui::epaint::TextShape;
 use emath::Align2;
+use emath::Vec2;
 
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
@@ -17,6 +18,9 @@ use crate::items::PlotGeometry;
 use crate::items::PlotItem;
 use crate::items::PlotItemBase;
 
+/// Rounding of the corners of the text box, in ui points.
+const CORNER_RADIUS: f32 = 2.0;
+
 impl Text {
     pub fn new(name: impl Into<String>, position: PlotPoint, text: impl Into<WidgetText>) -> Self {
         Self {
@@ -25,6 +29,9 @@ impl Text {
             position,
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
+            background: Color32::TRANSPARENT,
+            frame_stroke: Stroke::NONE,
+            padding: Vec2::ZERO,
         }
     }
 
@@ -36,12 +43,39 @@ impl Text {
     }
 
     /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
+    ///
+    /// This is the point of the text box, including its padding, that is
+    /// placed at the position, e.g. `Align2::LEFT_BOTTOM` puts the text
+    /// above and to the right of it.
     #[inline]
     pub fn anchor(mut self, anchor: Align2) -> Self {
         self.anchor = anchor;
         self
     }
 
+    /// Fill the box behind the text, to keep it readable over the data.
+    /// Default is `Color32::TRANSPARENT`, i.e. no background.
+    #[inline]
+    pub fn background(mut self, fill: impl Into<Color32>) -> Self {
+        self.background = fill.into();
+        self
+    }
+
+    /// Draw a frame around the text box. Default: no frame.
+    #[inline]
+    pub fn frame_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.frame_stroke = stroke.into();
+        self
+    }
+
+    /// Space between the text and the edges of its box, in ui points.
+    /// Default: none.
+    #[inline]
+    pub fn padding(mut self, padding: impl Into<Vec2>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
     /// Name of this plot item.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -74,6 +108,56 @@ impl Text {
         self
     }
 
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
     /// Sets the id of this plot item.
     ///
     /// By default the id is determined from the name passed to [`Self::new`],
@@ -99,9 +183,20 @@ impl PlotItem for Text {
                 .into_galley(ui, Some(egui::TextWrapMode::Extend), f32::INFINITY, TextStyle::Small);
 
         let pos = transform.position_from_point(&self.position);
-        let rect = self.anchor.anchor_size(pos, galley.size());
+        let rect = self.anchor.anchor_size(pos, galley.size() + 2.0 * self.padding);
 
-        shapes.push(TextShape::new(rect.min, galley, color).into());
+        if self.background != Color32::TRANSPARENT {
+            shapes.push(Shape::rect_filled(rect, CORNER_RADIUS, self.background));
+        }
+        if self.frame_stroke != Stroke::NONE {
+            shapes.push(Shape::rect_stroke(
+                rect,
+                CORNER_RADIUS,
+                self.frame_stroke,
+                egui::StrokeKind::Inside,
+            ));
+        }
+        shapes.push(TextShape::new(rect.min + self.padding, galley, color).into());
 
         if self.base.highlight {
             shapes.push(Shape::rect_stroke(
@@ -146,4 +241,7 @@ pub struct Text {
     pub(crate) position: PlotPoint,
     pub(crate) color: Color32,
     pub(crate) anchor: Align2,
+    pub(crate) background: Color32,
+    pub(crate) frame_stroke: Stroke,
+    pub(crate) padding: Vec2,
 }
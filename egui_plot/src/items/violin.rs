@@ -0,0 +1,596 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::CornerRadius;
+use egui::Id;
+use egui::Mesh;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use egui::epaint::RectShape;
+use emath::NumExt as _;
+use emath::Pos2;
+
+use crate::aesthetics::Orientation;
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::highlighted_color;
+use crate::cursor::Cursor;
+use crate::items::BoxSpread;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::add_rulers_and_text;
+use crate::label::LabelFormatter;
+use crate::math::find_closest_rect;
+use crate::math::quantile;
+use crate::rect_elem::RectElement;
+
+/// Number of points at which the density is evaluated for
+/// [`ViolinElem::from_samples`].
+const KDE_POINTS: usize = 64;
+
+/// A diagram containing a series of [`ViolinElem`] elements.
+///
+/// Like a [`crate::BoxPlot`], but showing the whole distribution of the
+/// values as a density mirrored around each argument.
+pub struct ViolinPlot {
+    base: PlotItemBase,
+
+    pub(crate) violins: Vec<ViolinElem>,
+    default_color: Color32,
+
+    /// A custom element formatter
+    pub(crate) element_formatter: Option<Box<dyn Fn(&ViolinElem, &ViolinPlot) -> String>>,
+}
+
+impl ViolinPlot {
+    /// Create a plot containing multiple `violins`. It defaults to vertically
+    /// oriented elements.
+    pub fn new(name: impl Into<String>, violins: Vec<ViolinElem>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            violins,
+            default_color: Color32::TRANSPARENT,
+            element_formatter: None,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already
+    /// have a specific color. This is the color that shows up in the
+    /// legend. It can be overridden at the element level (see
+    /// [`ViolinElem`]). Default is `Color32::TRANSPARENT` which means a color
+    /// will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for violin in &mut self.violins {
+            if violin.fill == Color32::TRANSPARENT && violin.stroke.color == Color32::TRANSPARENT {
+                violin.fill = plot_color.linear_multiply(0.2);
+                violin.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&ViolinElem, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+}
+
+impl PlotItem for ViolinPlot {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for violin in &self.violins {
+            violin.add_shapes(transform, self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for violin in &self.violins {
+            bounds.merge(&violin.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.violins, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &Option<LabelFormatter<'_>>,
+    ) {
+        let violin = &self.violins[elem.index];
+
+        violin.add_shapes(plot.transform, true, shapes);
+        let text = self.element_formatter.as_ref().map(|fmt| fmt(violin, self));
+        add_rulers_and_text(violin, plot, text, shapes, cursors);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// A violin in a [`ViolinPlot`] diagram.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViolinElem {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Which direction the violin faces in the diagram.
+    pub orientation: Orientation,
+
+    /// Position on the argument (input) axis -- X if vertical, Y if horizontal.
+    pub argument: f64,
+
+    /// `(value, density)` pairs, sorted by value.
+    pub density: Vec<(f64, f64)>,
+
+    /// Quartiles and whiskers, drawn as a thin box inside the violin if
+    /// [`Self::show_box`] is set.
+    pub spread: Option<BoxSpread>,
+
+    /// Whether to draw [`Self::spread`] inside the violin.
+    pub show_box: bool,
+
+    /// Thickness of the violin where the density is highest.
+    pub violin_width: f64,
+
+    /// Line width and color
+    pub stroke: Stroke,
+
+    /// Fill color
+    pub fill: Color32,
+}
+
+impl ViolinElem {
+    /// Create a violin from a precomputed density, given as
+    /// `(value, density)` pairs. Its `orientation` is set by its
+    /// [`ViolinPlot`] parent.
+    ///
+    /// The density is scaled such that its maximum spans
+    /// [`Self::violin_width`].
+    pub fn from_density(argument: f64, density: impl Into<Vec<(f64, f64)>>) -> Self {
+        let mut density = density.into();
+        density.retain(|(value, density)| value.is_finite() && density.is_finite());
+        density.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            argument,
+            orientation: Orientation::default(),
+            name: String::default(),
+            density,
+            spread: None,
+            show_box: false,
+            violin_width: 0.5,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Create a violin from raw samples, using a Gaussian kernel density
+    /// estimate with Silverman's rule of thumb for the bandwidth.
+    ///
+    /// The density spans the range of the samples. The quartiles and extremes
+    /// are computed as well, see [`Self::show_box`].
+    pub fn from_samples(argument: f64, samples: &[f64]) -> Self {
+        let mut samples: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        samples.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (samples.first(), samples.last()) else {
+            return Self::from_density(argument, Vec::new());
+        };
+
+        let density = if min < max {
+            let bandwidth = silverman_bandwidth(&samples);
+            (0..KDE_POINTS)
+                .map(|i| {
+                    let value = min + (max - min) * i as f64 / (KDE_POINTS - 1) as f64;
+                    (value, gaussian_kde(&samples, bandwidth, value))
+                })
+                .collect()
+        } else {
+            vec![(min, 1.0)]
+        };
+
+        let mut violin = Self::from_density(argument, density);
+        violin.spread = Some(BoxSpread::new(
+            min,
+            quantile(&samples, 0.25),
+            quantile(&samples, 0.5),
+            quantile(&samples, 0.75),
+            max,
+        ));
+        violin
+    }
+
+    /// Name of this violin element.
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the quartiles and whiskers, e.g. for a precomputed density.
+    #[inline]
+    pub fn spread(mut self, spread: BoxSpread) -> Self {
+        self.spread = Some(spread);
+        self
+    }
+
+    /// Overlay the quartiles as a thin box, the median as a dot and the
+    /// whiskers as a line, like a [`crate::BoxElem`]. Needs a
+    /// [`Self::spread`]. Default: `false`.
+    #[inline]
+    pub fn show_box(mut self, show_box: bool) -> Self {
+        self.show_box = show_box;
+        self
+    }
+
+    /// Add a custom stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Add a custom fill color.
+    #[inline]
+    pub fn fill(mut self, color: impl Into<Color32>) -> Self {
+        self.fill = color.into();
+        self
+    }
+
+    /// Set the violin width, where the density is highest.
+    #[inline]
+    pub fn violin_width(mut self, width: f64) -> Self {
+        self.violin_width = width;
+        self
+    }
+
+    /// Set orientation of the element as vertical. Argument axis is X.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = Orientation::Vertical;
+        self
+    }
+
+    /// Set orientation of the element as horizontal. Argument axis is Y.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self
+    }
+
+    /// Half of the violin width at each value of the density.
+    fn half_widths(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let max_density = self.density.iter().map(|(_, d)| *d).fold(0.0, f64::max);
+        let scale = if max_density > 0.0 {
+            0.5 * self.violin_width / max_density
+        } else {
+            0.0
+        };
+        self.density
+            .iter()
+            .map(move |&(value, density)| (value, density * scale))
+    }
+
+    fn add_shapes(&self, transform: &PlotTransform, highlighted: bool, shapes: &mut Vec<Shape>) {
+        let (stroke, fill) = if highlighted {
+            highlighted_color(self.stroke, self.fill)
+        } else {
+            (self.stroke, self.fill)
+        };
+
+        let position = |argument: f64, value: f64| transform.position_from_point(&self.point_at(argument, value));
+        let left: Vec<Pos2> = self
+            .half_widths()
+            .map(|(value, half)| position(self.argument - half, value))
+            .collect();
+        let right: Vec<Pos2> = self
+            .half_widths()
+            .map(|(value, half)| position(self.argument + half, value))
+            .collect();
+
+        // The outline is not convex, so fill it as a strip of quads.
+        let mut mesh = Mesh::default();
+        for (&l, &r) in left.iter().zip(&right) {
+            mesh.colored_vertex(l, fill);
+            mesh.colored_vertex(r, fill);
+        }
+        for i in 0..left.len().saturating_sub(1) as u32 {
+            mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+            mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        let mut outline = left;
+        outline.extend(right.into_iter().rev());
+        shapes.push(Shape::closed_line(outline, stroke));
+
+        if let Some(spread) = self.spread.as_ref().filter(|_| self.show_box) {
+            let box_width = 0.1 * self.violin_width;
+            shapes.push(Shape::line_segment(
+                [
+                    position(self.argument, spread.lower_whisker),
+                    position(self.argument, spread.upper_whisker),
+                ],
+                stroke,
+            ));
+            let rect = transform.rect_from_values(
+                &self.point_at(self.argument - box_width / 2.0, spread.quartile1),
+                &self.point_at(self.argument + box_width / 2.0, spread.quartile3),
+            );
+            shapes.push(Shape::Rect(RectShape::new(
+                rect,
+                CornerRadius::ZERO,
+                stroke.color,
+                Stroke::NONE,
+                egui::StrokeKind::Inside,
+            )));
+            shapes.push(Shape::circle_filled(
+                position(self.argument, spread.median),
+                (0.5 * rect.width().min(rect.height())).at_least(2.0),
+                fill.to_opaque(),
+            ));
+        }
+    }
+
+    /// The extreme values of the violin.
+    fn value_range(&self) -> (f64, f64) {
+        let first = self.density.first().map_or(0.0, |(value, _)| *value);
+        let last = self.density.last().map_or(0.0, |(value, _)| *value);
+        match &self.spread {
+            Some(spread) => (first.min(spread.lower_whisker), last.max(spread.upper_whisker)),
+            None => (first, last),
+        }
+    }
+}
+
+impl RectElement for ViolinElem {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        self.point_at(self.argument - self.violin_width / 2.0, self.value_range().0)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        self.point_at(self.argument + self.violin_width / 2.0, self.value_range().1)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        let values = if let Some(spread) = &self.spread {
+            vec![
+                spread.median,
+                spread.quartile1,
+                spread.quartile3,
+                spread.upper_whisker,
+                spread.lower_whisker,
+            ]
+        } else {
+            let (min, max) = self.value_range();
+            vec![min, max]
+        };
+        values
+            .into_iter()
+            .map(|value| self.point_at(self.argument, value))
+            .collect()
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        self.point_at(self.argument, self.value_range().1)
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos();
+        let scale = match self.orientation {
+            Orientation::Horizontal => scale[0],
+            Orientation::Vertical => scale[1],
+        };
+        let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1);
+        if let Some(spread) = &self.spread {
+            format!(
+                "Max = {max:.decimals$}\
+                 \nQuartile 3 = {q3:.decimals$}\
+                 \nMedian = {med:.decimals$}\
+                 \nQuartile 1 = {q1:.decimals$}\
+                 \nMin = {min:.decimals$}",
+                max = spread.upper_whisker,
+                q3 = spread.quartile3,
+                med = spread.median,
+                q1 = spread.quartile1,
+                min = spread.lower_whisker,
+            )
+        } else {
+            let (min, max) = self.value_range();
+            format!("Max = {max:.decimals$}\nMin = {min:.decimals$}")
+        }
+    }
+}
+
+/// Silverman's rule of thumb for the bandwidth of a Gaussian kernel, for
+/// sorted samples.
+fn silverman_bandwidth(samples: &[f64]) -> f64 {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let std_dev = (samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0)).sqrt();
+    let iqr = quantile(samples, 0.75) - quantile(samples, 0.25);
+    let spread = if iqr > 0.0 { std_dev.min(iqr / 1.34) } else { std_dev };
+    0.9 * spread * n.powf(-0.2)
+}
+
+/// Gaussian kernel density estimate at `value`.
+fn gaussian_kde(samples: &[f64], bandwidth: f64, value: f64) -> f64 {
+    let norm = 1.0 / (samples.len() as f64 * bandwidth * std::f64::consts::TAU.sqrt());
+    norm * samples
+        .iter()
+        .map(|s| (-0.5 * ((value - s) / bandwidth).powi(2)).exp())
+        .sum::<f64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_computes_quartiles_and_density() {
+        let violin = ViolinElem::from_samples(0.0, &[4.0, 1.0, 3.0, 2.0, 5.0]);
+        assert_eq!(violin.spread, Some(BoxSpread::new(1.0, 2.0, 3.0, 4.0, 5.0)));
+        assert_eq!(violin.density.len(), KDE_POINTS);
+
+        // Symmetric samples give a symmetric density, highest in the middle.
+        let (first, middle) = (violin.density[0].1, violin.density[KDE_POINTS / 2].1);
+        assert!((first - violin.density[KDE_POINTS - 1].1).abs() < 1e-12);
+        assert!(middle > first);
+    }
+}
@@ -0,0 +1,347 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::rulers_color;
+use crate::cursor::Cursor;
+use crate::items::Bar;
+use crate::items::ClosestElem;
+use crate::items::PlotConfig;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::items::add_rulers_and_text;
+use crate::label::LabelFormatter;
+use crate::math::find_closest_rect;
+use crate::rect_elem::RectElement as _;
+
+/// A waterfall chart: each bar starts where the previous one ended, so the
+/// bars show how a running total is built up from increases and decreases.
+///
+/// Add steps with [`Self::delta`] and bars showing the running total so far
+/// with [`Self::total`]. The bars are placed at `x = 0, 1, 2, …`.
+pub struct Waterfall {
+    base: PlotItemBase,
+
+    /// The name of each bar, and its change, or `None` for a total.
+    pub(crate) steps: Vec<(String, Option<f64>)>,
+
+    pub(crate) increase_color: Color32,
+    pub(crate) decrease_color: Color32,
+
+    /// Color of the totals. `Color32::TRANSPARENT` means that it will be
+    /// picked automatically.
+    pub(crate) total_color: Color32,
+
+    pub(crate) bar_width: f64,
+
+    /// Stroke of the lines joining the bars, if any. A transparent color
+    /// means the color of the rulers.
+    pub(crate) connector: Option<Stroke>,
+}
+
+impl Waterfall {
+    /// An empty waterfall chart. Add bars with [`Self::delta`] and
+    /// [`Self::total`].
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            steps: Vec::new(),
+            increase_color: Color32::from_rgb(60, 160, 80),
+            decrease_color: Color32::from_rgb(200, 70, 60),
+            total_color: Color32::TRANSPARENT,
+            bar_width: 0.6,
+            connector: Some(Stroke::new(1.0, Color32::TRANSPARENT)),
+        }
+    }
+
+    /// Add a bar changing the running total by `value`.
+    #[inline]
+    pub fn delta(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.steps.push((name.into(), Some(value)));
+        self
+    }
+
+    /// Add one bar per `(name, value)` change, see [`Self::delta`].
+    #[inline]
+    pub fn deltas<S: Into<String>>(mut self, deltas: impl IntoIterator<Item = (S, f64)>) -> Self {
+        self.steps
+            .extend(deltas.into_iter().map(|(name, value)| (name.into(), Some(value))));
+        self
+    }
+
+    /// Add a bar from zero to the running total so far, e.g. a subtotal or
+    /// the final result.
+    #[inline]
+    pub fn total(mut self, name: impl Into<String>) -> Self {
+        self.steps.push((name.into(), None));
+        self
+    }
+
+    /// Color of the bars increasing the total. Default: green.
+    #[inline]
+    pub fn increase_color(mut self, color: impl Into<Color32>) -> Self {
+        self.increase_color = color.into();
+        self
+    }
+
+    /// Color of the bars decreasing the total. Default: red.
+    #[inline]
+    pub fn decrease_color(mut self, color: impl Into<Color32>) -> Self {
+        self.decrease_color = color.into();
+        self
+    }
+
+    /// Color of the totals, which also shows up in the legend. Default is
+    /// `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn total_color(mut self, color: impl Into<Color32>) -> Self {
+        self.total_color = color.into();
+        self
+    }
+
+    /// Set the width of the bars. Default: `0.6`.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Set the stroke of the lines joining the end of each bar to the start
+    /// of the next, or `None` to hide them. Default: a thin line in the
+    /// color of the rulers.
+    #[inline]
+    pub fn connector(mut self, stroke: impl Into<Option<Stroke>>) -> Self {
+        self.connector = stroke.into();
+        self
+    }
+
+    /// Name of this plot item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Setting the name via this method does not change the item's id, so you
+    /// can use it to change the name dynamically between frames without
+    /// losing the item's state. You should make sure the name passed to
+    /// [`Self::new`] is unique and stable for each item, or set unique and
+    /// stable ids explicitly via [`Self::id`].
+    #[expect(clippy::needless_pass_by_value, reason = "to allow various string types")]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.base_mut().name = name.to_string();
+        self
+    }
+
+    /// Highlight this plot item, typically by scaling it up.
+    ///
+    /// If false, the item may still be highlighted via user interaction.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.base_mut().highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.base_mut().allow_hover = hovering;
+        self
+    }
+
+    /// Whether hovering this item highlights it and shows its values. Turn
+    /// this off for background items, like reference bands, so they don't
+    /// take the hover from the data in front of them. Default: `true`.
+    ///
+    /// Same as [`Self::allow_hover`].
+    #[inline]
+    pub fn hoverable(self, hoverable: bool) -> Self {
+        self.allow_hover(hoverable)
+    }
+
+    /// Whether clicking this item reports it in
+    /// [`crate::PlotResponse::clicked_item`]. Default: `true`.
+    #[inline]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.base_mut().clickable = clickable;
+        self
+    }
+
+    /// How near the pointer has to be to hover this item, in ui points.
+    /// A larger radius makes thin lines easier to hover. Default: the
+    /// interaction radius of the style, or the radius of
+    /// [`crate::Plot::hover_snap`].
+    #[inline]
+    pub fn hover_radius(mut self, radius: f32) -> Self {
+        self.base_mut().hover_radius = Some(radius);
+        self
+    }
+
+    /// Where this item ranks when several items are near the pointer: the
+    /// items of the highest priority are hovered first, e.g. a line over the
+    /// bars under it. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.base_mut().hover_priority = priority;
+        self
+    }
+
+    /// Reuse the shapes of this item from the previous frame as long as
+    /// `key`, the bounds and size of the plot, and the color and highlight of
+    /// the item stay the same, instead of generating them anew every frame,
+    /// e.g. for a reference curve that never changes. Change `key` whenever
+    /// the data or the style of the item changes.
+    ///
+    /// Shapes aren't cached on plots with an axis transform.
+    #[inline]
+    pub fn cache_shapes(mut self, key: impl std::hash::Hash) -> Self {
+        self.base_mut().cache_key = Some(Id::new(key));
+        self
+    }
+
+    /// Draw this item against the given Y axis. Default: [`YAxis::Primary`].
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.base_mut().y_axis = y_axis;
+        self
+    }
+
+    /// Sets the id of this plot item.
+    ///
+    /// By default the id is determined from the name passed to [`Self::new`],
+    /// but it can be explicitly set to a different value.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.base_mut().id = id.into();
+        self
+    }
+
+    /// One floating bar per step, starting at the running total before it.
+    fn bars(&self) -> Vec<Bar> {
+        let mut running = 0.0;
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, (name, delta))| {
+                let (bar, color) = match *delta {
+                    Some(delta) => {
+                        let bar = Bar::new(index as f64, delta).base_offset(running);
+                        running += delta;
+                        let color = if delta < 0.0 {
+                            self.decrease_color
+                        } else {
+                            self.increase_color
+                        };
+                        (bar, color)
+                    }
+                    None => (Bar::new(index as f64, running), self.total_color),
+                };
+                bar.name(name)
+                    .width(self.bar_width)
+                    .fill(color.linear_multiply(0.6))
+                    .stroke(Stroke::new(1.0, color))
+            })
+            .collect()
+    }
+}
+
+impl PlotItem for Waterfall {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let bars = self.bars();
+
+        if let Some(mut stroke) = self.connector {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = rulers_color(ui);
+            }
+            for pair in bars.windows(2) {
+                // The running total after the first bar, which the second starts from.
+                let level = pair[0].base_offset.unwrap_or(0.0) + pair[0].value;
+                let start = PlotPoint::new(pair[0].argument + 0.5 * pair[0].bar_width, level);
+                let end = PlotPoint::new(pair[1].argument - 0.5 * pair[1].bar_width, level);
+                let segment: [Pos2; 2] = [
+                    transform.position_from_point(&start),
+                    transform.position_from_point(&end),
+                ];
+                shapes.push(Shape::line_segment(segment, stroke));
+            }
+        }
+
+        for bar in &bars {
+            bar.add_shapes(transform, self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.total_color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for bar in &self.bars() {
+            bounds.merge(&bar.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.bars(), point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &Option<LabelFormatter<'_>>,
+    ) {
+        let bars = self.bars();
+        let bar = &bars[elem.index];
+
+        bar.add_shapes(plot.transform, true, shapes);
+        add_rulers_and_text(bar, plot, None, shapes, cursors);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_start_at_the_running_total() {
+        let waterfall = Waterfall::new("budget")
+            .delta("income", 10.0)
+            .delta("rent", -4.0)
+            .total("left")
+            .delta("bonus", 2.0);
+        let bars = waterfall.bars();
+        let spans: Vec<_> = bars.iter().map(|bar| (bar.lower(), bar.upper())).collect();
+        assert_eq!(spans, [(0.0, 10.0), (6.0, 10.0), (0.0, 6.0), (6.0, 8.0)]);
+        assert_eq!(bars[1].stroke.color, waterfall.decrease_color);
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use emath::NumExt as _;
 
 use crate::bounds::PlotPoint;
@@ -16,6 +18,72 @@ pub fn format_number(number: f64, num_decimals: usize) -> String {
     }
 }
 
+/// SI prefixes from `1e-24` to `1e24`, in steps of `1e3`.
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// Format a tick value with an SI prefix, e.g. `1.2k`, `3.4 MHz` or `560µ`.
+///
+/// The prefix is chosen from the largest magnitude in `range`, so that all
+/// ticks in view share it, and the number of decimals from `step_size`.
+pub(crate) fn format_si(value: f64, step_size: f64, range: &RangeInclusive<f64>, unit: &str) -> String {
+    let magnitude = range.start().abs().max(range.end().abs());
+    let prefix_index = if magnitude.is_finite() && magnitude > 0.0 {
+        ((magnitude.log10() / 3.0).floor() as i32 + 8).clamp(0, SI_PREFIXES.len() as i32 - 1)
+    } else {
+        8
+    };
+    let scale = 10_f64.powi(3 * (prefix_index - 8));
+
+    let num_decimals = decimals_for_step(step_size / scale).max(0) as usize;
+    let number = emath::format_with_decimals_in_range(value / scale, num_decimals..=num_decimals);
+
+    let prefix = SI_PREFIXES[prefix_index as usize];
+    if unit.is_empty() {
+        format!("{number}{prefix}")
+    } else {
+        format!("{number} {prefix}{unit}")
+    }
+}
+
+/// Format a tick value in scientific notation, e.g. `1.23e-5`.
+///
+/// The mantissa gets as many digits as `step_size` needs, so that neighboring
+/// ticks never look the same, however far the plot is zoomed in.
+pub(crate) fn format_scientific(value: f64, step_size: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{value}");
+    }
+    let exponent = value.abs().log10().floor() as i32;
+    let num_decimals = (decimals_for_step(step_size) + exponent).max(0) as usize;
+    format!("{value:.num_decimals$e}")
+}
+
+/// The number of decimals needed to tell values `step_size` apart.
+///
+/// Negative for steps of `10` and above.
+fn decimals_for_step(step_size: f64) -> i32 {
+    if step_size > 0.0 && step_size.is_finite() {
+        // E.g. a step of `0.5` needs one decimal. The epsilon avoids an extra decimal
+        // from rounding errors at exact powers of ten.
+        (-step_size.log10() - 1e-9).ceil() as i32
+    } else {
+        0
+    }
+}
+
+/// Append a unit to a formatted value, e.g. `12.3 ms`.
+///
+/// Empty values and units are left as they are.
+pub(crate) fn with_unit(text: String, unit: &str) -> String {
+    if text.is_empty() || unit.is_empty() {
+        text
+    } else {
+        format!("{text} {unit}")
+    }
+}
+
 type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
 
 /// Optional label formatter function for customizing hover labels.
@@ -23,11 +91,68 @@ pub type LabelFormatter<'a> = Box<LabelFormatterFn<'a>>;
 
 /// Default label formatter that shows the x and y coordinates with 3 decimal
 /// places.
+///
+/// Without a label formatter, points snapped to with
+/// [`crate::Plot::hover_snap`] are labeled like this, followed by the
+/// [`crate::AxisHints::unit`]s of their axes.
 pub fn default_label_formatter(name: &str, value: &PlotPoint) -> String {
+    label_with_units(name, value, ["", ""])
+}
+
+/// The [`default_label_formatter`] with the units of the X and Y axis.
+pub(crate) fn label_with_units(name: &str, value: &PlotPoint, [x_unit, y_unit]: [&str; 2]) -> String {
     let prefix = if name.is_empty() {
         String::new()
     } else {
         format!("{name}\n")
     };
-    format!("{}x = {:.3}\ny = {:.3}", prefix, value.x, value.y)
+    format!(
+        "{}x = {}\ny = {}",
+        prefix,
+        with_unit(format!("{:.3}", value.x), x_unit),
+        with_unit(format!("{:.3}", value.y), y_unit)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn si_prefix_is_shared_across_ticks() {
+        let range = 0.0..=4500.0;
+        assert_eq!(format_si(500.0, 500.0, &range, ""), "0.5k");
+        assert_eq!(format_si(1000.0, 500.0, &range, ""), "1.0k");
+        assert_eq!(format_si(1200.0, 100.0, &range, "Hz"), "1.2 kHz");
+
+        assert_eq!(format_si(560e-6, 10e-6, &(0.0..=0.0009), ""), "560µ");
+        assert_eq!(format_si(3.4e6, 0.1e6, &(0.0..=5e6), "W"), "3.4 MW");
+        assert_eq!(format_si(42.0, 1.0, &(0.0..=100.0), "s"), "42 s");
+    }
+
+    #[test]
+    fn labels_show_the_units() {
+        let value = PlotPoint::new(1.5, -2.0);
+        assert_eq!(label_with_units("", &value, ["s", "V"]), "x = 1.500 s\ny = -2.000 V");
+        assert_eq!(
+            label_with_units("signal", &value, ["", "V"]),
+            "signal\nx = 1.500\ny = -2.000 V"
+        );
+        assert_eq!(
+            label_with_units("signal", &value, ["", ""]),
+            default_label_formatter("signal", &value)
+        );
+    }
+
+    #[test]
+    fn scientific_precision_follows_step() {
+        assert_eq!(format_scientific(1.2345e-5, 1e-8), "1.234e-5");
+        assert_eq!(format_scientific(1.2345e-5, 1e-6), "1.2e-5");
+        assert_eq!(format_scientific(1.5e9, 5e8), "1.5e9");
+        assert_eq!(format_scientific(-2e3, 1e3), "-2e3");
+        assert_eq!(format_scientific(0.0, 1e-3), "0");
+
+        // Deep zoom: neighbors stay distinct.
+        assert_ne!(format_scientific(1.0 + 1e-9, 1e-9), format_scientific(1.0 + 2e-9, 1e-9));
+    }
 }
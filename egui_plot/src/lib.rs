@@ -10,60 +10,144 @@
 
 mod aesthetics;
 mod axis;
+mod axis_transform;
 mod bounds;
+mod brush;
 mod colors;
+mod context_menu;
 mod cursor;
 mod data;
+mod downsample;
+mod drag;
+#[cfg(feature = "glow")]
+mod gpu_points;
 mod grid;
+mod hover;
+mod input;
 mod items;
 mod label;
+mod lod;
 mod math;
+mod measure;
 mod memory;
 mod overlays;
+mod parallel;
 mod placement;
 mod plot;
+mod polar;
 mod rect_elem;
+mod region;
+mod retained;
+mod selection;
+mod shape_cache;
+mod simplify;
+mod snap;
 mod utils;
 
+pub use crate::aesthetics::ArrowHead;
+pub use crate::aesthetics::DashPattern;
+pub use crate::aesthetics::FillRule;
 pub use crate::aesthetics::LineStyle;
+pub use crate::aesthetics::MarkerPath;
 pub use crate::aesthetics::MarkerShape;
 pub use crate::aesthetics::Orientation;
+pub use crate::aesthetics::StepMode;
 pub use crate::axis::Axis;
 pub use crate::axis::AxisHints;
+pub use crate::axis::AxisInteraction;
 pub use crate::axis::PlotTransform;
+pub use crate::axis::YAxis;
+pub use crate::axis_transform::AxisTransform;
+pub use crate::axis_transform::BrokenAxisTransform;
+pub use crate::axis_transform::CategoricalAxisTransform;
+pub use crate::axis_transform::DecibelAxisTransform;
+pub use crate::axis_transform::LinearAxisTransform;
+pub use crate::axis_transform::LogAxisTransform;
+pub use crate::axis_transform::LogitAxisTransform;
+pub use crate::axis_transform::PowerAxisTransform;
+pub use crate::axis_transform::SqrtAxisTransform;
+pub use crate::axis_transform::SymlogAxisTransform;
+pub use crate::axis_transform::TimeAxisTransform;
+pub use crate::axis_transform::TimeZone;
+pub use crate::axis_transform::UtcOffset;
 pub use crate::bounds::PlotBounds;
 pub use crate::bounds::PlotPoint;
+pub use crate::brush::BrushGroup;
 pub use crate::colors::color_from_strength;
+pub use crate::context_menu::ContextMenuTarget;
 pub use crate::cursor::Cursor;
+pub use crate::data::PlotDataSource;
 pub use crate::data::PlotPoints;
+pub use crate::data::RollingPlotPoints;
+pub use crate::downsample::DownsampleMethod;
+pub use crate::drag::DraggedLine;
+pub use crate::drag::DraggedPoint;
 pub use crate::grid::GridInput;
 pub use crate::grid::GridMark;
+pub use crate::grid::Tick;
 pub use crate::grid::log_grid_spacer;
 pub use crate::grid::uniform_grid_spacer;
+pub use crate::hover::PlotItemHit;
+pub use crate::hover::SnapDistance;
+pub use crate::input::PlotInputConfig;
+pub use crate::input::PlotInteractionConfig;
+pub use crate::input::ScrollAction;
+pub use crate::input::ZoomAnchor;
+pub use crate::items::Annotation;
 pub use crate::items::Arrows;
+pub use crate::items::Band;
 pub use crate::items::Bar;
 pub use crate::items::BarChart;
+pub use crate::items::BinRule;
 pub use crate::items::BoxElem;
 pub use crate::items::BoxPlot;
 pub use crate::items::BoxSpread;
 pub use crate::items::ClosestElem;
+pub use crate::items::ColorImageItem;
+pub use crate::items::Contour;
+pub use crate::items::CubicBezier;
+pub use crate::items::ErrorBars;
+pub use crate::items::Events;
 pub use crate::items::FilledArea;
+pub use crate::items::Gantt;
+pub use crate::items::GanttBar;
+pub use crate::items::GroupedBars;
 pub use crate::items::HLine;
 pub use crate::items::Heatmap;
+pub use crate::items::Hist2d;
+pub use crate::items::Histogram;
+pub use crate::items::ImplicitCurve;
 pub use crate::items::Line;
+pub use crate::items::ParametricLine;
+pub use crate::items::PieChart;
 pub use crate::items::PlotConfig;
 pub use crate::items::PlotGeometry;
 pub use crate::items::PlotImage;
 pub use crate::items::PlotItem;
 pub use crate::items::PlotItemBase;
+pub use crate::items::PointStyle;
 pub use crate::items::Points;
 pub use crate::items::Polygon;
+pub use crate::items::Quiver;
+pub use crate::items::QuiverScale;
+pub use crate::items::RadarChart;
 pub use crate::items::Span;
+pub use crate::items::Spectrogram;
+pub use crate::items::SpectrogramBuffer;
+pub use crate::items::StackedBars;
+pub use crate::items::StackedLines;
+pub use crate::items::Stem;
+pub use crate::items::Streamlines;
 pub use crate::items::Text;
 pub use crate::items::VLine;
+pub use crate::items::ViolinElem;
+pub use crate::items::ViolinPlot;
+pub use crate::items::Waterfall;
+pub use crate::items::WhiskerRule;
 pub use crate::label::LabelFormatter;
 pub use crate::label::default_label_formatter;
 pub use crate::label::format_number;
+pub use crate::measure::Measurement;
 pub use crate::memory::PlotMemory;
 pub use crate::overlays::ColorConflictHandling;
 pub use crate::overlays::CoordinatesFormatter;
@@ -75,3 +159,12 @@ pub use crate::placement::VPlacement;
 pub use crate::plot::Plot;
 pub use crate::plot::PlotResponse;
 pub use crate::plot::PlotUi;
+pub use crate::polar::PolarPlot;
+pub use crate::polar::PolarPlotUi;
+pub use crate::region::PlotRegion;
+pub use crate::region::RegionKind;
+pub use crate::retained::RetainedSeries;
+pub use crate::selection::BoxSelection;
+pub use crate::selection::LassoSelection;
+pub use crate::selection::SelectedPoints;
+pub use crate::snap::DragSnap;
@@ -0,0 +1,271 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::Context;
+use egui::Id;
+
+use crate::axis::PlotTransform;
+use crate::data::PointsView;
+use crate::data::SeriesKey;
+use crate::downsample::sorted_visible_range;
+use crate::parallel;
+
+/// Number of points from which lines are drawn from the levels of detail.
+pub(crate) const LOD_MIN_POINTS: usize = 100_000;
+
+/// How many groups of one level make up a group of the next coarser one.
+const LEVEL_FACTOR: usize = 4;
+
+/// Coarser and coarser versions of a large series, for drawing it zoomed out
+/// without going through all of its points.
+///
+/// Each level keeps the lowest and the highest point of groups of
+/// consecutive points, `LEVEL_FACTOR` times as many per group as the level
+/// before, so a level drawn with a couple of groups per ui point looks the
+/// same as all the points.
+#[derive(Clone)]
+pub(crate) struct LodPyramid {
+    /// The indices of the lowest and the highest point of each group, for
+    /// each level from the finest. Empty if the points aren't sorted by x,
+    /// which is needed to find the groups in view.
+    levels: Vec<Vec<[usize; 2]>>,
+}
+
+impl LodPyramid {
+    pub(crate) fn new(points: PointsView<'_>) -> Self {
+        let mut pyramid = Self { levels: Vec::new() };
+        pyramid.extend(points, 0);
+        pyramid
+    }
+
+    /// Update the levels built from the first `old_len` of `points` for the
+    /// points appended since, recomputing only the groups they fall in.
+    fn extend(&mut self, points: PointsView<'_>, old_len: usize) {
+        // Also not sorted if any x is NaN.
+        let new = points.slice(old_len.saturating_sub(1)..points.len());
+        let sorted = new.iter().zip(new.iter().skip(1)).all(|(a, b)| a.x <= b.x);
+        if !sorted || (old_len > 1 && self.levels.is_empty()) {
+            self.levels = Vec::new();
+            return;
+        }
+
+        let lower = |a: usize, b: usize| {
+            if y_or(points.get(b).y, f64::INFINITY) < y_or(points.get(a).y, f64::INFINITY) {
+                b
+            } else {
+                a
+            }
+        };
+        let higher = |a: usize, b: usize| {
+            if y_or(points.get(b).y, f64::NEG_INFINITY) > y_or(points.get(a).y, f64::NEG_INFINITY) {
+                b
+            } else {
+                a
+            }
+        };
+        let merge = |groups: &[[usize; 2]]| {
+            groups
+                .iter()
+                .copied()
+                .reduce(|[low, high], [next_low, next_high]| [lower(low, next_low), higher(high, next_high)])
+                .unwrap_or_default()
+        };
+
+        // The groups that only hold old points stay the same.
+        let kept = old_len / LEVEL_FACTOR;
+        let new_groups = parallel::map_range(kept..points.len().div_ceil(LEVEL_FACTOR), |group| {
+            let start = group * LEVEL_FACTOR;
+            (start..(start + LEVEL_FACTOR).min(points.len())).fold([start; 2], |[low, high], index| {
+                [lower(low, index), higher(high, index)]
+            })
+        });
+        if self.levels.is_empty() {
+            self.levels.push(Vec::new());
+        }
+        self.levels[0].truncate(kept);
+        self.levels[0].extend(new_groups);
+
+        let mut level = 0;
+        while self.levels[level].len() > 1 {
+            if self.levels.len() == level + 1 {
+                self.levels.push(Vec::new());
+            }
+            let (finer, coarser) = self.levels.split_at_mut(level + 1);
+            let (finer, coarser) = (&finer[level], &mut coarser[0]);
+            let kept = old_len / group_size(level + 1);
+            coarser.truncate(kept);
+            coarser.extend(finer[kept * LEVEL_FACTOR..].chunks(LEVEL_FACTOR).map(merge));
+            level += 1;
+        }
+    }
+
+    /// The indices of the points of `run` in view to draw, in order, from
+    /// the coarsest level that still has two groups per ui point, or `None`
+    /// to draw all the points.
+    pub(crate) fn indices(
+        &self,
+        points: PointsView<'_>,
+        run: Range<usize>,
+        transform: &PlotTransform,
+    ) -> Option<Vec<usize>> {
+        if self.levels.is_empty() {
+            return None;
+        }
+        let visible = sorted_visible_range(points.slice(run.clone()), &transform.bounds().range_x());
+        let visible = run.start + visible.start..run.start + visible.end;
+        if visible.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let per_ui_point = visible.len() as f64 / transform.frame().width().max(1.0) as f64;
+        let (level, groups) = self
+            .levels
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(level, _)| 2.0 * group_size(*level) as f64 <= per_ui_point)?;
+        let size = group_size(level);
+
+        // The ends of the range in view are always drawn, so the line still
+        // leaves the view and doesn't join across gaps.
+        let last = visible.end - 1;
+        let mut indices = vec![visible.start];
+        for &[low, high] in &groups[visible.start / size..=last / size] {
+            for index in [low.min(high), low.max(high)] {
+                if visible.start < index && index < last && indices.last() != Some(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        if last > visible.start {
+            indices.push(last);
+        }
+        Some(indices)
+    }
+}
+
+/// The number of points per group at `level`.
+fn group_size(level: usize) -> usize {
+    LEVEL_FACTOR.pow(level as u32 + 1)
+}
+
+/// `y` if it is finite, and `fallback` otherwise.
+fn y_or(y: f64, fallback: f64) -> f64 {
+    if y.is_finite() { y } else { fallback }
+}
+
+/// The levels of detail of `points`, from the cache under `id` if they were
+/// built from the same version of the series, or extended from it if points
+/// were appended since, e.g. to a [`crate::RetainedSeries`]. Built anew
+/// every time if the points have no `key`.
+pub(crate) fn cached_pyramid(ctx: &Context, id: Id, points: PointsView<'_>, key: Option<SeriesKey>) -> Arc<LodPyramid> {
+    let Some(key) = key else {
+        return Arc::new(LodPyramid::new(points));
+    };
+
+    // Taken out of the cache, so that it isn't copied to be extended.
+    let cached = ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<Option<(SeriesKey, Arc<LodPyramid>)>>(id)
+            .take()
+    });
+    let pyramid = match cached {
+        Some((cached_key, mut pyramid)) if key.extends(&cached_key) => {
+            if key.len() > cached_key.len() {
+                Arc::make_mut(&mut pyramid).extend(points, cached_key.len());
+            }
+            pyramid
+        }
+        _ => Arc::new(LodPyramid::new(points)),
+    };
+    ctx.data_mut(|data| data.insert_temp(id, Some((key, pyramid.clone()))));
+    pyramid
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::Rect;
+    use emath::pos2;
+
+    use super::*;
+    use crate::bounds::PlotBounds;
+    use crate::bounds::PlotPoint;
+    use crate::data::PlotPoints;
+
+    #[test]
+    fn zoomed_out_lines_use_a_coarse_level() {
+        let mut points: Vec<PlotPoint> = (0..10_000).map(|x| PlotPoint::new(x as f64, 0.0)).collect();
+        points[5_003].y = 7.0;
+        points[6_001].y = -7.0;
+        let pyramid = LodPyramid::new(points[..].into());
+
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, -10.0], [10_000.0, 10.0]), false);
+        let Some(indices) = pyramid.indices(points[..].into(), 0..points.len(), &transform) else {
+            panic!("a level fits");
+        };
+        assert!(
+            indices.len() < 2_000,
+            "only {} of 10000 points are drawn",
+            indices.len()
+        );
+        assert!(
+            indices.contains(&5_003) && indices.contains(&6_001),
+            "the extremes are kept"
+        );
+        assert!(indices.windows(2).all(|w| w[0] < w[1]), "indices are in order");
+
+        // Zoomed in, all points in view are drawn.
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, -10.0], [50.0, 10.0]), false);
+        assert!(
+            pyramid
+                .indices(points[..].into(), 0..points.len(), &transform)
+                .is_none(),
+            "no level fits"
+        );
+
+        // Extending the levels gives the same as building them anew.
+        let mut extended = LodPyramid::new(points[..1_001].into());
+        extended.extend(points[..].into(), 1_001);
+        assert_eq!(extended.levels, pyramid.levels, "extended levels match");
+
+        let unsorted = [PlotPoint::new(1.0, 0.0), PlotPoint::new(0.0, 0.0)];
+        assert!(
+            LodPyramid::new(unsorted[..].into()).levels.is_empty(),
+            "unsorted points have no levels"
+        );
+    }
+
+    #[test]
+    fn cached_levels_follow_the_version() {
+        let ctx = Context::default();
+        let id = Id::new("line");
+        let mut points: PlotPoints<'_> = (0..1_000).map(|x| [x as f64, 0.0]).collect();
+        let pyramid = cached_pyramid(&ctx, id, points.view(), points.series_key(Some(0)));
+
+        // Changed in the same version, so the cached levels are kept.
+        let PlotPoints::Owned(owned) = &mut points else {
+            panic!("collected points are owned");
+        };
+        owned[500].y = 3.0;
+        let kept = cached_pyramid(&ctx, id, points.view(), points.series_key(Some(0)));
+        assert!(Arc::ptr_eq(&kept, &pyramid), "levels are kept");
+
+        let changed = cached_pyramid(&ctx, id, points.view(), points.series_key(Some(1)));
+        assert_eq!(
+            changed.levels,
+            LodPyramid::new(points.view()).levels,
+            "levels are rebuilt for a new version"
+        );
+
+        let PlotPoints::Owned(owned) = &mut points else {
+            panic!("collected points are owned");
+        };
+        owned.extend((1_000..1_100).map(|x| PlotPoint::new(x as f64, 1.0)));
+        let extended = cached_pyramid(&ctx, id, points.view(), points.series_key(Some(1)));
+        assert_eq!(
+            extended.levels,
+            LodPyramid::new(points.view()).levels,
+            "levels are extended"
+        );
+    }
+}
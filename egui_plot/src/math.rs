@@ -12,6 +12,14 @@ pub fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
         .then_some(((y * (p1.x - p2.x)) - (p1.x * p2.y - p1.y * p2.x)) / (p1.y - p2.y))
 }
 
+/// The `q`-quantile of sorted samples, interpolated linearly.
+pub fn quantile(samples: &[f64], q: f64) -> f64 {
+    let position = q * (samples.len() - 1) as f64;
+    let (i, t) = (position.floor() as usize, position.fract());
+    let next = samples.get(i + 1).copied().unwrap_or(samples[i]);
+    samples[i] + t * (next - samples[i])
+}
+
 pub fn find_closest_rect<'a, T>(
     rects: impl IntoIterator<Item = &'a T>,
     point: Pos2,
@@ -0,0 +1,112 @@
+use egui::Align2;
+use egui::Painter;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotPoint;
+use crate::label::format_number;
+
+/// Space between the text of the measurement label and its box, in ui points.
+const LABEL_MARGIN: f32 = 4.0;
+
+/// A measurement between two points of a plot, see
+/// [`crate::Plot::measure_mode`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    /// Where the measurement starts, in plot coordinates.
+    pub start: PlotPoint,
+
+    /// Where the measurement ends, in plot coordinates.
+    pub end: PlotPoint,
+}
+
+impl Measurement {
+    /// The difference in x from the start to the end.
+    pub fn dx(&self) -> f64 {
+        self.end.x - self.start.x
+    }
+
+    /// The difference in y from the start to the end.
+    pub fn dy(&self) -> f64 {
+        self.end.y - self.start.y
+    }
+
+    /// The distance between the start and the end, in plot units.
+    pub fn distance(&self) -> f64 {
+        self.dx().hypot(self.dy())
+    }
+
+    /// The slope `dy / dx` from the start to the end. Infinite for vertical
+    /// measurements.
+    pub fn slope(&self) -> f64 {
+        self.dy() / self.dx()
+    }
+
+    /// Draw the measurement as a line between its ends, with its values in a
+    /// label next to the middle.
+    pub(crate) fn paint(&self, ui: &Ui, painter: &Painter, transform: &PlotTransform) {
+        let start = transform.position_from_point(&self.start);
+        let end = transform.position_from_point(&self.end);
+        let visuals = ui.visuals();
+        let stroke = Stroke::new(1.5, visuals.strong_text_color());
+        painter.add(Shape::line_segment([start, end], stroke));
+        painter.add(Shape::circle_filled(start, 3.0, stroke.color));
+        painter.add(Shape::circle_filled(end, 3.0, stroke.color));
+
+        // As many decimals as it takes to tell apart values a pixel apart.
+        let decimals = transform.dvalue_dpos().map(|step| decimals_for(step.abs()));
+        let text = format!(
+            "Δx = {}\nΔy = {}\ndistance = {}\nslope = {}",
+            format_number(self.dx(), decimals[0]),
+            format_number(self.dy(), decimals[1]),
+            format_number(self.distance(), decimals[0].max(decimals[1])),
+            format_number(self.slope(), 3),
+        );
+        let galley = painter.layout_no_wrap(text, TextStyle::Small.resolve(ui.style()), visuals.text_color());
+        let anchor = start.lerp(end, 0.5) + vec2(8.0 + LABEL_MARGIN, 0.0);
+        let text_rect = Align2::LEFT_CENTER.anchor_size(anchor, galley.size());
+        painter.rect(
+            text_rect.expand(LABEL_MARGIN),
+            2.0,
+            visuals.extreme_bg_color,
+            visuals.widgets.noninteractive.bg_stroke,
+            egui::StrokeKind::Inside,
+        );
+        painter.galley(text_rect.min, galley, visuals.text_color());
+    }
+}
+
+/// The number of decimals needed to show differences of `step`.
+fn decimals_for(step: f64) -> usize {
+    if step > 0.0 && step.is_finite() {
+        (-step.log10()).ceil().max(0.0) as usize
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurement_values() {
+        let measurement = Measurement {
+            start: PlotPoint::new(1.0, 1.0),
+            end: PlotPoint::new(4.0, -3.0),
+        };
+        assert_eq!(measurement.dx(), 3.0);
+        assert_eq!(measurement.dy(), -4.0);
+        assert_eq!(measurement.distance(), 5.0);
+        assert!((measurement.slope() + 4.0 / 3.0).abs() < 1e-12, "slope is dy / dx");
+
+        assert_eq!(decimals_for(0.01), 2);
+        assert_eq!(decimals_for(0.05), 2);
+        assert_eq!(decimals_for(20.0), 0);
+    }
+}
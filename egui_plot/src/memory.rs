@@ -1,12 +1,21 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use egui::Context;
 use egui::Id;
 use egui::Pos2;
+use egui::Vec2;
 use egui::Vec2b;
 
 use crate::axis::PlotTransform;
 use crate::bounds::PlotBounds;
+use crate::context_menu::ContextMenuTarget;
+use crate::measure::Measurement;
+use crate::region::PlotRegion;
+use crate::region::RegionDrag;
+
+/// The number of views kept to go back to.
+const MAX_VIEW_HISTORY: usize = 100;
 
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -27,9 +36,70 @@ pub struct PlotMemory {
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
+    /// The transform of the secondary Y axis from last frame, if any.
+    ///
+    /// Its X bounds are the same as those of [`Self::transform`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) secondary_y_transform: Option<PlotTransform>,
+
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
+    /// The lasso being drawn, in screen coordinates. Empty if there is none.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) lasso: Vec<Pos2>,
+
+    /// The item and index of the data point being dragged, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) dragged_point: Option<(Id, usize)>,
+
+    /// The line being dragged, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) dragged_line: Option<Id>,
+
+    /// The measurement placed with [`crate::Plot::measure_mode`], if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) measurement: Option<Measurement>,
+
+    /// What was right-clicked to open the context menu.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) context_menu_target: Option<ContextMenuTarget>,
+
+    /// Earlier views to go back to, the latest last.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) view_history: Vec<PlotBounds>,
+
+    /// Views that were undone, the latest undone last.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) view_future: Vec<PlotBounds>,
+
+    /// Whether the view changed in the last frame, so that a continuous pan
+    /// or zoom is recorded only once.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) view_changing: bool,
+
+    /// Whether [`crate::Plot::follow_latest`] was paused by the user moving
+    /// away from the latest data.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) follow_paused: bool,
+
+    /// The speed of kinetic panning, in ui points per second, see
+    /// [`crate::Plot::kinetic_panning`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) pan_velocity: Vec2,
+
+    /// The regions created with [`crate::Plot::region_mode`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) regions: Vec<PlotRegion>,
+
+    /// The region being created or resized, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) region_drag: Option<RegionDrag>,
+
+    /// The indices of the points marked as selected, by item id.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) selected: ahash::HashMap<Id, BTreeSet<usize>>,
+
     /// The thickness of each of the axes the previous frame.
     ///
     /// This is used in the next frame to make the axes thicker
@@ -41,7 +111,7 @@ pub struct PlotMemory {
 impl PlotMemory {
     #[inline]
     pub fn transform(&self) -> PlotTransform {
-        self.transform
+        self.transform.clone()
     }
 
     #[inline]
@@ -60,6 +130,107 @@ impl PlotMemory {
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// The measurement placed with [`crate::Plot::measure_mode`], if any.
+    #[inline]
+    pub fn measurement(&self) -> Option<Measurement> {
+        self.measurement
+    }
+
+    /// Dismiss the measurement, as pressing escape over the plot does.
+    #[inline]
+    pub fn clear_measurement(&mut self) {
+        self.measurement = None;
+    }
+
+    /// The regions created with [`crate::Plot::region_mode`], in the order
+    /// they were created.
+    #[inline]
+    pub fn regions(&self) -> &[PlotRegion] {
+        &self.regions
+    }
+
+    /// The regions created with [`crate::Plot::region_mode`], e.g. to add
+    /// regions loaded from a file or to remove them all.
+    #[inline]
+    pub fn regions_mut(&mut self) -> &mut Vec<PlotRegion> {
+        self.region_drag = None;
+        &mut self.regions
+    }
+
+    /// Whether the point at `index` of the item with the given id is marked
+    /// as selected.
+    #[inline]
+    pub fn is_selected(&self, id: Id, index: usize) -> bool {
+        self.selected.get(&id).is_some_and(|indices| indices.contains(&index))
+    }
+
+    /// The selected points as pairs of item id and index, in no particular
+    /// order of the items.
+    pub fn selection(&self) -> impl Iterator<Item = (Id, usize)> + '_ {
+        self.selected
+            .iter()
+            .flat_map(|(id, indices)| indices.iter().map(|index| (*id, *index)))
+    }
+
+    /// Mark the point at `index` of the item with the given id as selected.
+    ///
+    /// Selected points are drawn with a ring around them in the selection
+    /// color until they are deselected, e.g. to show which rows of a table
+    /// next to the plot are selected. Only items made of points, like
+    /// [`crate::Points`] and [`crate::Line`], show the selection.
+    pub fn select(&mut self, id: Id, index: usize) {
+        self.selected.entry(id).or_default().insert(index);
+    }
+
+    /// Unmark the point at `index` of the item with the given id.
+    pub fn deselect(&mut self, id: Id, index: usize) {
+        if let Some(indices) = self.selected.get_mut(&id) {
+            indices.remove(&index);
+            if indices.is_empty() {
+                self.selected.remove(&id);
+            }
+        }
+    }
+
+    /// Select exactly the given pairs of item id and index, see
+    /// [`Self::select`].
+    pub fn set_selection(&mut self, selection: impl IntoIterator<Item = (Id, usize)>) {
+        self.selected.clear();
+        for (id, index) in selection {
+            self.select(id, index);
+        }
+    }
+
+    /// Deselect all points.
+    #[inline]
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Remember the view before the user changed it, forgetting the undone
+    /// views.
+    pub(crate) fn record_view(&mut self, bounds: PlotBounds) {
+        self.view_future.clear();
+        self.view_history.push(bounds);
+        if self.view_history.len() > MAX_VIEW_HISTORY {
+            self.view_history.remove(0);
+        }
+    }
+
+    /// The view to go back to from `current`, if any.
+    pub(crate) fn undo_view(&mut self, current: PlotBounds) -> Option<PlotBounds> {
+        let previous = self.view_history.pop()?;
+        self.view_future.push(current);
+        Some(previous)
+    }
+
+    /// The view to go forth to from `current`, if any was undone.
+    pub(crate) fn redo_view(&mut self, current: PlotBounds) -> Option<PlotBounds> {
+        let next = self.view_future.pop()?;
+        self.view_history.push(current);
+        Some(next)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -1,5 +1,6 @@
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
+use crate::label::with_unit;
 
 type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a;
 
@@ -7,6 +8,9 @@ type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a
 /// [`crate::Plot::coordinates_formatter`].
 pub struct CoordinatesFormatter<'a> {
     function: Box<CoordinatesFormatterFn<'a>>,
+
+    /// Set by [`Self::with_decimals`], which also shows the units of the axes.
+    num_decimals: Option<usize>,
 }
 
 impl<'a> CoordinatesFormatter<'a> {
@@ -15,18 +19,29 @@ impl<'a> CoordinatesFormatter<'a> {
     pub fn new(function: impl Fn(&PlotPoint, &PlotBounds) -> String + 'a) -> Self {
         Self {
             function: Box::new(function),
+            num_decimals: None,
         }
     }
 
-    /// Show a fixed number of decimal places.
+    /// Show a fixed number of decimal places, followed by the
+    /// [`crate::AxisHints::unit`] of the main axes.
     pub fn with_decimals(num_decimals: usize) -> Self {
         Self {
             function: Box::new(move |value, _| format!("x: {:.d$}\ny: {:.d$}", value.x, value.y, d = num_decimals)),
+            num_decimals: Some(num_decimals),
         }
     }
 
-    pub(crate) fn format(&self, value: &PlotPoint, bounds: &PlotBounds) -> String {
-        (self.function)(value, bounds)
+    /// Format the coordinates, with the `units` of the main X and Y axes.
+    pub(crate) fn format(&self, value: &PlotPoint, bounds: &PlotBounds, [x_unit, y_unit]: [&str; 2]) -> String {
+        match self.num_decimals {
+            Some(d) => format!(
+                "x: {}\ny: {}",
+                with_unit(format!("{:.d$}", value.x), x_unit),
+                with_unit(format!("{:.d$}", value.y), y_unit),
+            ),
+            None => (self.function)(value, bounds),
+        }
     }
 }
 
@@ -0,0 +1,33 @@
+//! Work on the points of large series, spread over all cores with the
+//! `rayon` feature: transforming them to the screen, building their levels
+//! of detail and their positions on the GPU. The feature also turns on the
+//! parallel tessellation of large shapes in `epaint`.
+//!
+//! Items themselves are prepared one after another on the thread drawing
+//! the plot, as they hold callbacks that aren't required to be `Send` or
+//! `Sync`. Only the per-point work of large ones is split into chunks.
+
+/// Number of elements from which the work is split into chunks for all
+/// cores, below which spreading it out costs more than it saves.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_LEN: usize = 50_000;
+
+/// `f` applied to each element of `items`, in order.
+pub(crate) fn map<I: Sync, T: Send>(items: &[I], f: impl Fn(&I) -> T + Sync + Send) -> Vec<T> {
+    #[cfg(feature = "rayon")]
+    if items.len() >= PARALLEL_MIN_LEN {
+        use rayon::prelude::*;
+        return items.par_iter().map(f).collect();
+    }
+    items.iter().map(f).collect()
+}
+
+/// `f` applied to each index in `range`, in order.
+pub(crate) fn map_range<T: Send>(range: std::ops::Range<usize>, f: impl Fn(usize) -> T + Sync + Send) -> Vec<T> {
+    #[cfg(feature = "rayon")]
+    if range.len() >= PARALLEL_MIN_LEN {
+        use rayon::prelude::*;
+        return range.into_par_iter().map(f).collect();
+    }
+    range.map(f).collect()
+}
@@ -3,8 +3,12 @@ use std::sync::Arc;
 
 use egui::Color32;
 use egui::CursorIcon;
+use egui::EventFilter;
 use egui::Id;
+use egui::Key;
+use egui::KeyboardShortcut;
 use egui::Layout;
+use egui::Modifiers;
 use egui::Painter;
 use egui::PointerButton;
 use egui::Response;
@@ -28,26 +32,56 @@ use emath::vec2;
 
 use crate::axis::Axis;
 use crate::axis::AxisHints;
+use crate::axis::AxisInteraction;
 use crate::axis::AxisWidget;
 use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::axis_transform::AxisTransform;
+use crate::axis_transform::AxisTransforms;
+use crate::axis_transform::CategoricalAxisTransform;
+use crate::axis_transform::data_to_plot;
+use crate::axis_transform::modify_in_plot_space;
+use crate::axis_transform::plot_to_data;
+use crate::bounds::BoundsLimits;
 use crate::bounds::BoundsLinkGroups;
 use crate::bounds::BoundsModification;
 use crate::bounds::LinkedBounds;
 use crate::bounds::PlotBounds;
 use crate::bounds::PlotPoint;
+use crate::brush;
+use crate::brush::BrushGroup;
 use crate::colors::rulers_color;
+use crate::context_menu::ContextMenuFn;
+use crate::context_menu::ContextMenuTarget;
 use crate::cursor::Cursor;
 use crate::cursor::CursorLinkGroups;
 use crate::cursor::PlotFrameCursors;
+use crate::drag::DraggedLine;
+use crate::drag::DraggedPoint;
 use crate::grid::GridInput;
 use crate::grid::GridMark;
 use crate::grid::GridSpacer;
+use crate::grid::Tick;
+use crate::hover::PlotItemHit;
+use crate::hover::SnapDistance;
+use crate::hover::nearest_point;
+use crate::hover::show_snapped_point;
+use crate::hover::y_at_x;
+use crate::input;
+use crate::input::PlotInputConfig;
+use crate::input::PlotInteractionConfig;
+use crate::input::ScrollAction;
+use crate::input::ZoomAnchor;
 use crate::items;
+use crate::items::ClosestElem;
 use crate::items::PlotItem;
 use crate::items::Span;
 use crate::items::horizontal_line;
 use crate::items::vertical_line;
 use crate::label::LabelFormatter;
+use crate::label::format_number;
+use crate::label::label_with_units;
+use crate::measure::Measurement;
 use crate::memory::PlotMemory;
 use crate::overlays::CoordinatesFormatter;
 use crate::overlays::Legend;
@@ -55,12 +89,32 @@ use crate::overlays::LegendWidget;
 use crate::placement::Corner;
 use crate::placement::HPlacement;
 use crate::placement::VPlacement;
+use crate::region::PlotRegion;
+use crate::region::RegionDrag;
+use crate::region::RegionKind;
+use crate::retained::RetainedSeries;
+use crate::selection::BoxSelection;
+use crate::selection::LassoSelection;
+use crate::selection::SelectedPoints;
+use crate::selection::bounds_contain;
+use crate::selection::polygon_contains;
+use crate::shape_cache;
+use crate::snap;
+use crate::snap::DragSnap;
 
 /// Combined axis widgets: `[x_axis_widgets, y_axis_widgets]`
 type AxisWidgets<'a> = [Vec<crate::axis::AxisWidget<'a>>; 2];
 
 /// Combined axis responses: `[x_axis_responses, y_axis_responses]`
-type AxisResponses = [Vec<Response>; 2];
+type AxisResponses = [Vec<AxisResponse>; 2];
+
+/// The response of an axis widget, with what dragging and scrolling over it
+/// does.
+struct AxisResponse {
+    response: Response,
+    drag: AxisInteraction,
+    scroll: AxisInteraction,
+}
 
 /// A 2D plot, e.g. a graph of a function.
 ///
@@ -90,18 +144,33 @@ pub struct Plot<'a> {
 
     center_axis: Vec2b,
     allow_zoom: Vec2b,
+    pinch_axis_threshold: f32,
+    pinch_proportional: bool,
+    kinetic_friction: Option<f32>,
+    zoom_anchor: [ZoomAnchor; 2],
     allow_drag: Vec2b,
     allow_axis_zoom_drag: Vec2b,
     allow_scroll: Vec2b,
     allow_double_click_reset: bool,
+    input_config: PlotInputConfig,
+    view_history_keys: bool,
     allow_boxed_zoom: bool,
+    box_select: bool,
+    lasso_modifiers: Option<Modifiers>,
+    measure_mode: bool,
+    region_mode: Option<RegionKind>,
+    drag_snap: Option<DragSnap<'a>>,
+    show_lasso: bool,
     default_auto_bounds: Vec2b,
+    follow_latest: Option<f64>,
+    bounds_limits: BoundsLimits,
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
-    pan_pointer_button: PointerButton,
-    boxed_zoom_pointer_button: PointerButton,
+    interaction_config: PlotInteractionConfig,
     linked_axes: Option<(Id, Vec2b)>,
     linked_cursors: Option<(Id, Vec2b)>,
+    brush_group: Option<BrushGroup>,
+    show_linked_readouts: bool,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -116,7 +185,11 @@ pub struct Plot<'a> {
     show_x: bool,
     show_y: bool,
     show_crosshair: bool,
+    hover_snap: Option<(f32, SnapDistance)>,
+    show_axis_readouts: bool,
     label_formatter: Option<LabelFormatter<'a>>,
+    context_menu: Option<ContextMenuFn<'a>>,
+    context_menu_builtins: bool,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
@@ -127,8 +200,19 @@ pub struct Plot<'a> {
 
     show_grid: Vec2b,
     grid_spacing: Rangef,
-    grid_spacers: [GridSpacer<'a>; 2],
+    grid_spacers: [Option<GridSpacer<'a>>; 2],
+    ticks: [Option<Vec<Tick>>; 2],
+    tick_counts: [Option<usize>; 2],
     clamp_grid: bool,
+    axis_transforms: AxisTransforms,
+
+    /// Index of the secondary X axis in `x_axes`, if any.
+    secondary_x_axis: Option<usize>,
+    secondary_x_axis_transform: Option<Arc<dyn AxisTransform>>,
+
+    /// Index of the secondary Y axis in `y_axes`, if any.
+    secondary_y_axis: Option<usize>,
+    secondary_y_axis_transform: Option<Arc<dyn AxisTransform>>,
 
     sense: Sense,
 }
@@ -142,18 +226,33 @@ impl<'a> Plot<'a> {
 
             center_axis: false.into(),
             allow_zoom: true.into(),
+            pinch_axis_threshold: 2.0,
+            pinch_proportional: false,
+            kinetic_friction: None,
+            zoom_anchor: [ZoomAnchor::Pointer; 2],
             allow_drag: true.into(),
             allow_axis_zoom_drag: true.into(),
             allow_scroll: true.into(),
             allow_double_click_reset: true,
+            input_config: PlotInputConfig::default(),
+            view_history_keys: true,
             allow_boxed_zoom: true,
+            box_select: false,
+            lasso_modifiers: None,
+            measure_mode: false,
+            region_mode: None,
+            drag_snap: None,
+            show_lasso: true,
             default_auto_bounds: true.into(),
+            follow_latest: None,
+            bounds_limits: BoundsLimits::default(),
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
-            pan_pointer_button: PointerButton::Primary,
-            boxed_zoom_pointer_button: PointerButton::Secondary,
+            interaction_config: PlotInteractionConfig::default(),
             linked_axes: None,
             linked_cursors: None,
+            brush_group: None,
+            show_linked_readouts: false,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -168,7 +267,11 @@ impl<'a> Plot<'a> {
             show_x: true,
             show_y: true,
             show_crosshair: true,
+            hover_snap: None,
+            show_axis_readouts: false,
             label_formatter: None,
+            context_menu: None,
+            context_menu_builtins: true,
             coordinates_formatter: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
@@ -179,8 +282,17 @@ impl<'a> Plot<'a> {
 
             show_grid: true.into(),
             grid_spacing: Rangef::new(8.0, 300.0),
-            grid_spacers: [crate::grid::log_grid_spacer(10), crate::grid::log_grid_spacer(10)],
+            grid_spacers: [None, None],
+            ticks: [None, None],
+            tick_counts: [None, None],
             clamp_grid: false,
+            axis_transforms: Default::default(),
+
+            secondary_x_axis: None,
+            secondary_x_axis_transform: None,
+
+            secondary_y_axis: None,
+            secondary_y_axis_transform: None,
 
             sense: egui::Sense::click_and_drag(),
         }
@@ -281,6 +393,30 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Snap the hover to the nearest data point within `radius` ui points of
+    /// the pointer, among all series. The point is highlighted, the rulers go
+    /// through it, and a tooltip shows the name of the series and the values
+    /// of the point, formatted by [`Self::label_formatter`] if it is set.
+    ///
+    /// By default, the topmost item within the interaction radius of the
+    /// style is hovered, and there is only a tooltip if a label formatter is
+    /// set.
+    #[inline]
+    pub fn hover_snap(mut self, radius: f32, distance: SnapDistance) -> Self {
+        self.hover_snap = Some((radius, distance));
+        self
+    }
+
+    /// Label the crosshair with its values on the axes, in a box where each
+    /// ruler meets an axis, formatted like the tick labels of that axis.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_axis_readouts(mut self, show: bool) -> Self {
+        self.show_axis_readouts = show;
+        self
+    }
+
     /// Always keep the X-axis centered. Default: `false`.
     #[inline]
     pub fn center_x_axis(mut self, on: bool) -> Self {
@@ -308,6 +444,63 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// How predominant one axis has to be in a two-finger pinch on a touch
+    /// screen for the pinch to zoom only that axis.
+    ///
+    /// With the default of `2.0`, a pinch zooms only the X axis if the
+    /// fingers move apart (or together) at least twice as much horizontally
+    /// as vertically, and vice versa. Other pinches zoom both axes alike.
+    /// Either way, only the axes in [`Self::allow_zoom`] are zoomed.
+    #[inline]
+    pub fn pinch_axis_threshold(mut self, threshold: f32) -> Self {
+        self.pinch_axis_threshold = threshold;
+        self
+    }
+
+    /// Always zoom both axes alike on a two-finger pinch, see
+    /// [`Self::pinch_axis_threshold`]. Default: `false`.
+    #[inline]
+    pub fn pinch_proportional(mut self, on: bool) -> Self {
+        self.pinch_proportional = on;
+        self
+    }
+
+    /// What stays in place when zooming by scrolling or pinching, on both
+    /// axes. Default: [`ZoomAnchor::Pointer`].
+    #[inline]
+    pub fn zoom_anchor(mut self, anchor: ZoomAnchor) -> Self {
+        self.zoom_anchor = [anchor; 2];
+        self
+    }
+
+    /// What stays in place along the X axis when zooming by scrolling or
+    /// pinching. Default: [`ZoomAnchor::Pointer`].
+    #[inline]
+    pub fn zoom_anchor_x(mut self, anchor: ZoomAnchor) -> Self {
+        self.zoom_anchor[0] = anchor;
+        self
+    }
+
+    /// What stays in place along the Y axis when zooming by scrolling or
+    /// pinching. Default: [`ZoomAnchor::Pointer`].
+    #[inline]
+    pub fn zoom_anchor_y(mut self, anchor: ZoomAnchor) -> Self {
+        self.zoom_anchor[1] = anchor;
+        self
+    }
+
+    /// Keep panning after a drag that ends with the pointer still moving,
+    /// slowing down until the view comes to rest, like on touch screens.
+    ///
+    /// `friction` is how quickly the view slows down: its speed drops by a
+    /// factor of `e` every `1.0 / friction` seconds, e.g. `5.0`. A press on
+    /// the plot stops it. Default: off.
+    #[inline]
+    pub fn kinetic_panning(mut self, friction: f32) -> Self {
+        self.kinetic_friction = Some(friction);
+        self
+    }
+
     /// Whether to allow scrolling in the plot. Default: `true`.
     #[inline]
     pub fn allow_scroll<T>(mut self, on: T) -> Self
@@ -319,6 +512,9 @@ impl<'a> Plot<'a> {
     }
 
     /// Whether to allow double clicking to reset the view.
+    ///
+    /// Double clicking an axis instead fits only that axis to the data visible
+    /// along the other one, e.g. the Y axis to the data in the current X range.
     /// Default: `true`.
     #[inline]
     pub fn allow_double_click_reset(mut self, on: bool) -> Self {
@@ -326,6 +522,23 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Configure the keyboard navigation of the plot, see
+    /// [`PlotInputConfig`].
+    #[inline]
+    pub fn input_config(mut self, input_config: PlotInputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Whether ⌘/Ctrl+Z and ⌘/Ctrl+Shift+Z step back and forth through the
+    /// earlier views while the plot is hovered, like [`PlotUi::undo_view`]
+    /// and [`PlotUi::redo_view`]. Default: `true`.
+    #[inline]
+    pub fn view_history_keys(mut self, on: bool) -> Self {
+        self.view_history_keys = on;
+        self
+    }
+
     /// Set the side margin as a fraction of the plot size. Only used for auto
     /// bounds.
     ///
@@ -350,17 +563,103 @@ impl<'a> Plot<'a> {
 
     /// Config the button pointer to use for drag-to-pan. Default:
     /// [`Secondary`](PointerButton::Primary)
+    ///
+    /// Same as [`PlotInteractionConfig::pan_button`].
     #[inline]
     pub fn pan_pointer_button(mut self, pan_pointer_button: PointerButton) -> Self {
-        self.pan_pointer_button = pan_pointer_button;
+        self.interaction_config.pan_button = pan_pointer_button;
         self
     }
 
     /// Config the button pointer to use for boxed zooming. Default:
     /// [`Secondary`](PointerButton::Secondary)
+    ///
+    /// Same as [`PlotInteractionConfig::box_zoom_button`].
     #[inline]
     pub fn boxed_zoom_pointer_button(mut self, boxed_zoom_pointer_button: PointerButton) -> Self {
-        self.boxed_zoom_pointer_button = boxed_zoom_pointer_button;
+        self.interaction_config.box_zoom_button = boxed_zoom_pointer_button;
+        self
+    }
+
+    /// Remap the pointer gestures of the plot, see
+    /// [`PlotInteractionConfig`].
+    ///
+    /// This replaces the buttons set with [`Self::pan_pointer_button`] and
+    /// [`Self::boxed_zoom_pointer_button`].
+    #[inline]
+    pub fn interaction_config(mut self, interaction_config: PlotInteractionConfig) -> Self {
+        self.interaction_config = interaction_config;
+        self
+    }
+
+    /// Turn the boxed zoom gesture into a selection: instead of zooming, the
+    /// data points within the dragged box are returned in
+    /// [`PlotResponse::box_selection`]. Default: `false`.
+    ///
+    /// This works even if [`Self::allow_boxed_zoom`] is off.
+    #[inline]
+    pub fn box_select(mut self, on: bool) -> Self {
+        self.box_select = on;
+        self
+    }
+
+    /// Draw a freehand lasso by dragging with [`Self::pan_pointer_button`]
+    /// while holding `modifiers`, e.g. [`Modifiers::SHIFT`]. The data points
+    /// within the lasso are returned in [`PlotResponse::lasso_selection`].
+    ///
+    /// The plot doesn't pan while the lasso is drawn. Default: off.
+    #[inline]
+    pub fn lasso_select(mut self, modifiers: Modifiers) -> Self {
+        self.lasso_modifiers = Some(modifiers);
+        self
+    }
+
+    /// Dragging with [`Self::pan_pointer_button`] places a measurement
+    /// between two points instead of panning, showing the differences in x
+    /// and y, the distance and the slope between them.
+    ///
+    /// The measurement stays, also after leaving this mode, until the next
+    /// one replaces it, or it is dismissed by clicking the plot in this
+    /// mode, pressing escape over the plot, or
+    /// [`PlotMemory::clear_measurement`]. Default: `false`.
+    #[inline]
+    pub fn measure_mode(mut self, on: bool) -> Self {
+        self.measure_mode = on;
+        self
+    }
+
+    /// Dragging with [`Self::pan_pointer_button`] creates a region of the
+    /// given kind instead of panning, e.g. to label intervals of a recorded
+    /// signal. Set to `None` to leave this mode.
+    ///
+    /// In this mode, dragging the edges of a region resizes it, and clicking
+    /// the handle in its corner deletes it. The regions stay in
+    /// [`PlotMemory::regions`] and are shown also after leaving this mode.
+    /// Default: `None`.
+    #[inline]
+    pub fn region_mode(mut self, kind: impl Into<Option<RegionKind>>) -> Self {
+        self.region_mode = kind.into();
+        self
+    }
+
+    /// Snap draggable points and lines to grid lines, to the points of other
+    /// items, or to where a function moves them, see [`DragSnap`].
+    ///
+    /// This applies on top of the steps of [`crate::HLine::snap`] and
+    /// [`crate::VLine::snap`]. Holding
+    /// [`PlotInteractionConfig::snap_bypass_modifiers`] drags without
+    /// snapping. Default: no snapping.
+    #[inline]
+    pub fn drag_snap(mut self, snap: DragSnap<'a>) -> Self {
+        self.drag_snap = Some(snap);
+        self
+    }
+
+    /// Whether to draw the lasso while it is being drawn out, see
+    /// [`Self::lasso_select`]. Default: `true`.
+    #[inline]
+    pub fn show_lasso(mut self, show: bool) -> Self {
+        self.show_lasso = show;
         self
     }
 
@@ -379,6 +678,9 @@ impl<'a> Plot<'a> {
 
     /// Whether to allow dragging in the axis areas to zoom the plot. Default:
     /// `true`.
+    ///
+    /// Whether dragging an axis zooms or pans is set per axis with
+    /// [`AxisHints::drag_interaction`].
     #[inline]
     pub fn allow_axis_zoom_drag<T>(mut self, on: T) -> Self
     where
@@ -420,6 +722,42 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Show a context menu when the plot is right-clicked.
+    ///
+    /// `add_contents` fills the menu, knowing which item was clicked, if any,
+    /// and where. Built-in entries to hide the clicked item, reset the view
+    /// and copy the clicked coordinates follow, unless turned off with
+    /// [`Self::context_menu_builtins`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::Line;
+    /// use egui_plot::Plot;
+    /// use egui_plot::PlotPoints;
+    /// let line = Line::new("sin", PlotPoints::from_explicit_callback(f64::sin, .., 100));
+    /// Plot::new("my_plot")
+    ///     .context_menu(|ui, target| {
+    ///         if target.item.is_some() && ui.button("Tag this series").clicked() {
+    ///             // …
+    ///         }
+    ///     })
+    ///     .show(ui, |plot_ui| plot_ui.line(line));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn context_menu(mut self, add_contents: impl Fn(&mut Ui, &ContextMenuTarget) + 'a) -> Self {
+        self.context_menu = Some(Box::new(add_contents));
+        self
+    }
+
+    /// Whether the context menu ends with the built-in entries, see
+    /// [`Self::context_menu`]. Default: `true`.
+    #[inline]
+    pub fn context_menu_builtins(mut self, show: bool) -> Self {
+        self.context_menu_builtins = show;
+        self
+    }
+
     /// Show the pointer coordinates in the plot.
     #[inline]
     pub fn coordinates_formatter(mut self, position: Corner, formatter: CoordinatesFormatter<'a>) -> Self {
@@ -431,7 +769,7 @@ impl<'a> Plot<'a> {
     /// axis.
     ///
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other
-    /// units.
+    /// units, or the grid of the [`Self::x_axis_transform`], if any.
     ///
     /// The function has this signature:
     /// ```ignore
@@ -477,19 +815,94 @@ impl<'a> Plot<'a> {
     ///
     /// There are helpers for common cases, see [`crate::grid::log_grid_spacer`]
     /// and [`crate::grid::uniform_grid_spacer`].
+    ///
+    /// With an [`AxisTransform`], the input is in plot space, while the
+    /// returned values are in data space, see [`AxisTransform::grid_marks`].
     #[inline]
     pub fn x_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
-        self.grid_spacers[0] = Box::new(spacer);
+        self.grid_spacers[0] = Some(Box::new(spacer));
         self
     }
 
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other
-    /// units.
+    /// units, or the grid of the [`Self::y_axis_transform`], if any.
     ///
     /// See [`Self::x_grid_spacer`] for explanation.
     #[inline]
     pub fn y_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
-        self.grid_spacers[1] = Box::new(spacer);
+        self.grid_spacers[1] = Some(Box::new(spacer));
+        self
+    }
+
+    /// Put the X ticks and grid lines at exactly these data values, instead
+    /// of generating them.
+    ///
+    /// Each tick is a value, optionally with a label that replaces the
+    /// formatted value, e.g. `vec![(0.0, "start"), (42.0, "peak")]`, or
+    /// `vec![0.0, 0.5, 1.0]`. This overrides [`Self::x_grid_spacer`] and
+    /// [`Self::x_tick_count`]. The labels are shown on the main X axis, and
+    /// its formatter labels the other ticks.
+    #[inline]
+    pub fn x_ticks<T: Into<Tick>>(self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks(Axis::X, ticks.into_iter().map(Into::into).collect())
+    }
+
+    /// Put the Y ticks and grid lines at exactly these data values.
+    ///
+    /// See [`Self::x_ticks`] for details.
+    #[inline]
+    pub fn y_ticks<T: Into<Tick>>(self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks(Axis::Y, ticks.into_iter().map(Into::into).collect())
+    }
+
+    fn ticks(mut self, axis: Axis, ticks: Vec<Tick>) -> Self {
+        self.ticks[usize::from(axis)] = Some(ticks);
+        self
+    }
+
+    /// The axes along `axis`, with the labels of the explicit ticks put on
+    /// the main one, in front of its formatter.
+    fn labeled_axes(&self, axis: Axis) -> Vec<AxisHints<'a>> {
+        let (mut axes, secondary) = match axis {
+            Axis::X => (self.x_axes.clone(), self.secondary_x_axis),
+            Axis::Y => (self.y_axes.clone(), self.secondary_y_axis),
+        };
+        let labels: Vec<(f64, String)> = self.ticks[usize::from(axis)]
+            .iter()
+            .flatten()
+            .filter_map(|tick| Some((tick.value, tick.label.clone()?)))
+            .collect();
+        if let (Some(main), false, false) = (axes.first_mut(), labels.is_empty(), secondary == Some(0)) {
+            let formatter = Arc::clone(&main.formatter);
+            *main = main.clone().formatter(move |mark, range| {
+                labels
+                    .iter()
+                    .find(|(value, _)| *value == mark.value)
+                    .map_or_else(|| formatter(mark, range), |(_, label)| label.clone())
+            });
+        }
+        axes
+    }
+
+    /// Aim for about `count` evenly spaced ticks on the X axis, at 1, 2 or 5
+    /// times a power of ten.
+    ///
+    /// This replaces the default three levels of grid lines with a single
+    /// one. With a [`Self::x_grid_spacer`] or [`Self::x_axis_transform`],
+    /// their grid is used instead, with a minimum step of a `count`th of the
+    /// visible range.
+    #[inline]
+    pub fn x_tick_count(mut self, count: usize) -> Self {
+        self.tick_counts[0] = Some(count);
+        self
+    }
+
+    /// Aim for about `count` evenly spaced ticks on the Y axis.
+    ///
+    /// See [`Self::x_tick_count`] for details.
+    #[inline]
+    pub fn y_tick_count(mut self, count: usize) -> Self {
+        self.tick_counts[1] = Some(count);
         self
     }
 
@@ -575,6 +988,42 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Follow streaming data: keep the right edge of the X bounds at the
+    /// largest X of the data, showing the last `x_window` of it.
+    ///
+    /// Following pauses as soon as the user pans or zooms along X, or the X
+    /// bounds are set from code, and resumes on [`PlotUi::go_live`] or on a
+    /// double-click reset. See also [`PlotUi::is_live`].
+    #[inline]
+    pub fn follow_latest(mut self, x_window: f64) -> Self {
+        self.follow_latest = Some(x_window);
+        self
+    }
+
+    /// Keep the view between `min` and `max`, e.g. so that users can't pan
+    /// far away from the data. Use infinite values to leave sides open.
+    ///
+    /// Applies to panning and zooming by the user as well as to bounds set
+    /// from code or fit to the data.
+    #[inline]
+    pub fn bounds_limits(mut self, min: [f64; 2], max: [f64; 2]) -> Self {
+        self.bounds_limits.extent = PlotBounds::from_min_max(min, max);
+        self
+    }
+
+    /// Keep the width and height of the view between `min_span` and
+    /// `max_span`, i.e. limit how far the user can zoom in and out along each
+    /// axis, e.g. to keep from zooming into numeric noise.
+    ///
+    /// Applies to bounds set from code or fit to the data as well. The view
+    /// never gets larger than [`Self::bounds_limits`].
+    #[inline]
+    pub fn span_limits(mut self, min_span: [f64; 2], max_span: [f64; 2]) -> Self {
+        self.bounds_limits.min_span = min_span;
+        self.bounds_limits.max_span = max_span;
+        self
+    }
+
     /// Expand bounds to fit all items across the x axis, including values given
     /// by `include_x`.
     #[deprecated = "Use `auto_bounds` instead"]
@@ -646,6 +1095,28 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add this plot to a brush group, so that the points selected in any
+    /// plot of the group are highlighted in all of them, see [`BrushGroup`].
+    /// A plot cannot belong to more than one brush group.
+    #[inline]
+    pub fn brush_group(mut self, group: BrushGroup) -> Self {
+        self.brush_group = Some(group);
+        self
+    }
+
+    /// Where the vertical cursor of another plot in the cursor link group
+    /// crosses the series of this plot, mark the crossing and write the
+    /// value of the series there. Works whatever the Y axes of the plots.
+    ///
+    /// Requires linking the X cursor with [`Self::link_cursor`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_linked_readouts(mut self, show: bool) -> Self {
+        self.show_linked_readouts = show;
+        self
+    }
+
     /// Round grid positions to full pixels to avoid aliasing. Improves plot
     /// appearance but might have an undesired effect when shifting the plot
     /// bounds. Enabled by default.
@@ -728,6 +1199,54 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Turn the main X-axis into a categorical axis.
+    ///
+    /// Category `i` is placed at `x = i`: put your bars or points there. Grid
+    /// marks only land on category centers, and the tick labels show the
+    /// category names, also when zooming.
+    ///
+    /// This replaces the [`Self::x_axis_transform`] and
+    /// [`Self::x_axis_formatter`]. See [`CategoricalAxisTransform`].
+    #[inline]
+    pub fn x_categories<S: Into<String>>(mut self, labels: impl IntoIterator<Item = S>) -> Self {
+        let categories = Arc::new(CategoricalAxisTransform::new(labels));
+        self.axis_transforms[0] = Some(Arc::clone(&categories) as Arc<dyn AxisTransform>);
+        self.x_axis_formatter(move |mark, _range| categories.format(mark))
+    }
+
+    /// Turn the main Y-axis into a categorical axis.
+    ///
+    /// See [`Self::x_categories`] for details.
+    #[inline]
+    pub fn y_categories<S: Into<String>>(mut self, labels: impl IntoIterator<Item = S>) -> Self {
+        let categories = Arc::new(CategoricalAxisTransform::new(labels));
+        self.axis_transforms[1] = Some(Arc::clone(&categories) as Arc<dyn AxisTransform>);
+        self.y_axis_formatter(move |mark, _range| categories.format(mark))
+    }
+
+    /// Map the X axis through an [`AxisTransform`], e.g. a
+    /// [`crate::LogAxisTransform`].
+    ///
+    /// All values passed to and returned from the plot (items, bounds,
+    /// pointer coordinates, grid marks, …) stay in data space; only the
+    /// layout on screen, zooming and panning follow the transform. The grid
+    /// comes from [`AxisTransform::grid_marks`], unless overridden with
+    /// [`Self::x_grid_spacer`].
+    #[inline]
+    pub fn x_axis_transform(mut self, transform: Box<dyn AxisTransform>) -> Self {
+        self.axis_transforms[0] = Some(transform.into());
+        self
+    }
+
+    /// Map the Y axis through an [`AxisTransform`].
+    ///
+    /// See [`Self::x_axis_transform`] for details.
+    #[inline]
+    pub fn y_axis_transform(mut self, transform: Box<dyn AxisTransform>) -> Self {
+        self.axis_transforms[1] = Some(transform.into());
+        self
+    }
+
     /// Set the minimum width of the main y-axis, in ui points.
     ///
     /// The width will automatically expand if any tickmark text is wider than
@@ -753,7 +1272,12 @@ impl<'a> Plot<'a> {
     /// considered the main axis.
     #[inline]
     pub fn custom_x_axes(mut self, hints: Vec<AxisHints<'a>>) -> Self {
+        let secondary = self.secondary_x_axis.map(|index| self.x_axes[index].clone());
         self.x_axes = hints;
+        if let Some(secondary) = secondary {
+            self.secondary_x_axis = Some(self.x_axes.len());
+            self.x_axes.push(secondary);
+        }
         self
     }
 
@@ -763,7 +1287,75 @@ impl<'a> Plot<'a> {
     /// considered the main axis.
     #[inline]
     pub fn custom_y_axes(mut self, hints: Vec<AxisHints<'a>>) -> Self {
+        let secondary = self.secondary_y_axis.map(|index| self.y_axes[index].clone());
         self.y_axes = hints;
+        if let Some(secondary) = secondary {
+            self.secondary_y_axis = Some(self.y_axes.len());
+            self.y_axes.push(secondary);
+        }
+        self
+    }
+
+    /// Add a secondary X axis on top, with its own label and formatter.
+    ///
+    /// By default it mirrors the ticks of the main X axis. Use
+    /// [`Self::secondary_x_axis_transform`] to show a different quantity
+    /// instead, e.g. elapsed seconds above a sample index.
+    ///
+    /// Like the other axes, it can be dragged to zoom.
+    #[inline]
+    pub fn secondary_x_axis(mut self, hints: AxisHints<'a>) -> Self {
+        let hints = hints.placement(VPlacement::Top);
+        if let Some(index) = self.secondary_x_axis {
+            self.x_axes[index] = hints;
+        } else {
+            self.secondary_x_axis = Some(self.x_axes.len());
+            self.x_axes.push(hints);
+        }
+        self
+    }
+
+    /// Map the secondary X axis through its own [`AxisTransform`].
+    ///
+    /// The transform maps the values shown on the secondary axis to the plot
+    /// space of the main X axis: a tick at `value` is drawn where the main
+    /// axis has `data_to_plot(value)` in plot space. For instance, with a
+    /// linear main axis showing sample indices, a transform with
+    /// `data_to_plot(seconds) = seconds * sample_rate` labels the top axis in
+    /// seconds. The grid marks of the secondary axis come from
+    /// [`AxisTransform::grid_marks`].
+    ///
+    /// See [`Self::secondary_x_axis`].
+    #[inline]
+    pub fn secondary_x_axis_transform(mut self, transform: Box<dyn AxisTransform>) -> Self {
+        self.secondary_x_axis_transform = Some(transform.into());
+        self
+    }
+
+    /// Add a secondary Y axis on the right, with its own bounds, label and
+    /// formatter.
+    ///
+    /// Items are drawn against it with [`crate::Line::y_axis`] or
+    /// [`crate::Points::y_axis`]. Its bounds are fitted to those items, and
+    /// follow the primary Y axis when panning and zooming.
+    #[inline]
+    pub fn secondary_y_axis(mut self, hints: AxisHints<'a>) -> Self {
+        let hints = hints.placement(HPlacement::Right);
+        if let Some(index) = self.secondary_y_axis {
+            self.y_axes[index] = hints;
+        } else {
+            self.secondary_y_axis = Some(self.y_axes.len());
+            self.y_axes.push(hints);
+        }
+        self
+    }
+
+    /// Map the secondary Y axis through an [`AxisTransform`].
+    ///
+    /// See [`Self::secondary_y_axis`] and [`Self::x_axis_transform`].
+    #[inline]
+    pub fn secondary_y_axis_transform(mut self, transform: Box<dyn AxisTransform>) -> Self {
+        self.secondary_y_axis_transform = Some(transform.into());
         self
     }
 
@@ -824,28 +1416,32 @@ impl<'a> Plot<'a> {
     }
 
     fn allocate_axis_responses(&self, ui: &mut Ui, axis_widgets: &AxisWidgets<'_>) -> AxisResponses {
+        let allocate = |ui: &mut Ui, widget: &AxisWidget<'_>, d: usize| {
+            let response = ui.allocate_rect(widget.rect, Sense::click_and_drag());
+            let drag = widget.hints.drag_interaction;
+            let response = match drag {
+                _ if !self.allow_axis_zoom_drag[d] => response,
+                AxisInteraction::Zoom if d == 0 => response.on_hover_cursor(CursorIcon::ResizeHorizontal),
+                AxisInteraction::Zoom => response.on_hover_cursor(CursorIcon::ResizeVertical),
+                AxisInteraction::Pan if response.dragged() => response.on_hover_cursor(CursorIcon::Grabbing),
+                AxisInteraction::Pan => response.on_hover_cursor(CursorIcon::Grab),
+                AxisInteraction::None => response,
+            };
+            AxisResponse {
+                response,
+                drag,
+                scroll: widget.hints.scroll_interaction,
+            }
+        };
+
         let x_axis_responses = axis_widgets[0]
             .iter()
-            .map(|widget| {
-                let axis_response = ui.allocate_rect(widget.rect, Sense::drag());
-                if self.allow_axis_zoom_drag.x {
-                    axis_response.on_hover_cursor(CursorIcon::ResizeHorizontal)
-                } else {
-                    axis_response
-                }
-            })
+            .map(|widget| allocate(ui, widget, 0))
             .collect::<Vec<_>>();
 
         let y_axis_responses = axis_widgets[1]
             .iter()
-            .map(|widget| {
-                let axis_response = ui.allocate_rect(widget.rect, Sense::drag());
-                if self.allow_axis_zoom_drag.y {
-                    axis_response.on_hover_cursor(CursorIcon::ResizeVertical)
-                } else {
-                    axis_response
-                }
-            })
+            .map(|widget| allocate(ui, widget, 1))
             .collect::<Vec<_>>();
 
         [x_axis_responses, y_axis_responses]
@@ -866,31 +1462,64 @@ impl<'a> Plot<'a> {
                 auto_bounds: self.default_auto_bounds,
                 hovered_legend_item: None,
                 hidden_items: Default::default(),
-                transform: PlotTransform::new_with_invert_axis(
+                transform: PlotTransform::new_with_axis_transforms(
                     plot_rect,
                     self.min_auto_bounds,
                     self.center_axis,
                     Vec2b::new(self.invert_x, self.invert_y),
+                    self.axis_transforms.clone(),
                 ),
+                secondary_y_transform: None,
                 last_click_pos_for_zoom: None,
+                lasso: Vec::new(),
+                dragged_point: None,
+                dragged_line: None,
+                measurement: None,
+                context_menu_target: None,
+                view_history: Vec::new(),
+                view_future: Vec::new(),
+                view_changing: false,
+                follow_paused: false,
+                pan_velocity: Vec2::ZERO,
+                regions: Vec::new(),
+                region_drag: None,
+                selected: Default::default(),
                 x_axis_thickness: Default::default(),
                 y_axis_thickness: Default::default(),
             }
         } else {
-            PlotMemory::load(ui.ctx(), plot_id).unwrap_or_else(|| PlotMemory {
+            let mut mem = PlotMemory::load(ui.ctx(), plot_id).unwrap_or_else(|| PlotMemory {
                 auto_bounds: self.default_auto_bounds,
                 hovered_legend_item: None,
                 hidden_items: Default::default(),
-                transform: PlotTransform::new_with_invert_axis(
+                transform: PlotTransform::new_with_axis_transforms(
                     plot_rect,
                     self.min_auto_bounds,
                     self.center_axis,
                     Vec2b::new(self.invert_x, self.invert_y),
+                    self.axis_transforms.clone(),
                 ),
+                secondary_y_transform: None,
                 last_click_pos_for_zoom: None,
+                lasso: Vec::new(),
+                dragged_point: None,
+                dragged_line: None,
+                measurement: None,
+                context_menu_target: None,
+                view_history: Vec::new(),
+                view_future: Vec::new(),
+                view_changing: false,
+                follow_paused: false,
+                pan_velocity: Vec2::ZERO,
+                regions: Vec::new(),
+                region_drag: None,
+                selected: Default::default(),
                 x_axis_thickness: Default::default(),
                 y_axis_thickness: Default::default(),
-            })
+            });
+            // The axis transforms aren't persisted, and may have changed since the last frame.
+            mem.transform.set_axis_transforms(self.axis_transforms.clone());
+            mem
         }
     }
 
@@ -984,6 +1613,11 @@ impl<'a> Plot<'a> {
         // Allow double-clicking to reset to the initial bounds.
         if self.allow_double_click_reset && plot_ui.response.double_clicked() {
             mem.auto_bounds = true.into();
+            mem.follow_paused = false;
+        }
+        if self.input_config.keyboard && plot_ui.response.has_focus() && ui.input(|i| i.key_pressed(Key::Home)) {
+            mem.auto_bounds = true.into();
+            mem.follow_paused = false;
         }
 
         // Apply bounds modifications.
@@ -993,6 +1627,7 @@ impl<'a> Plot<'a> {
                     bounds.min[0] = *range.start();
                     bounds.max[0] = *range.end();
                     mem.auto_bounds.x = false;
+                    mem.follow_paused = true;
                 }
                 BoundsModification::SetY(range) => {
                     bounds.min[1] = *range.start();
@@ -1001,15 +1636,40 @@ impl<'a> Plot<'a> {
                 }
                 BoundsModification::Translate(delta) => {
                     let delta = (delta.x as f64, delta.y as f64);
-                    bounds.translate(delta);
+                    modify_in_plot_space(&self.axis_transforms, &mut bounds, |bounds| bounds.translate(delta));
                     mem.auto_bounds = false.into();
+                    mem.follow_paused |= delta.0 != 0.0;
                 }
                 BoundsModification::AutoBounds(new_auto_bounds) => {
                     mem.auto_bounds = *new_auto_bounds;
                 }
                 BoundsModification::Zoom(zoom_factor, center) => {
-                    bounds.zoom(*zoom_factor, *center);
+                    let center = PlotPoint::new(
+                        data_to_plot(self.axis_transforms[0].as_ref(), center.x),
+                        data_to_plot(self.axis_transforms[1].as_ref(), center.y),
+                    );
+                    modify_in_plot_space(&self.axis_transforms, &mut bounds, |bounds| {
+                        bounds.zoom(*zoom_factor, center);
+                    });
                     mem.auto_bounds = false.into();
+                    mem.follow_paused |= zoom_factor.x != 1.0;
+                }
+                BoundsModification::UndoView => {
+                    if let Some(previous) = mem.undo_view(bounds) {
+                        bounds = previous;
+                        mem.auto_bounds = false.into();
+                        mem.follow_paused = true;
+                    }
+                }
+                BoundsModification::RedoView => {
+                    if let Some(next) = mem.redo_view(bounds) {
+                        bounds = next;
+                        mem.auto_bounds = false.into();
+                        mem.follow_paused = true;
+                    }
+                }
+                BoundsModification::GoLive => {
+                    mem.follow_paused = false;
                 }
             }
         }
@@ -1032,25 +1692,44 @@ impl<'a> Plot<'a> {
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_y {
+                if auto_y && !self.is_on_secondary_y_axis(&**item) {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
-            if auto_x {
-                bounds.add_relative_margin_x(self.margin_fraction);
-            }
+            modify_in_plot_space(&self.axis_transforms, &mut bounds, |bounds| {
+                if auto_x {
+                    bounds.add_relative_margin_x(self.margin_fraction);
+                }
 
-            if auto_y {
-                bounds.add_relative_margin_y(self.margin_fraction);
+                if auto_y {
+                    bounds.add_relative_margin_y(self.margin_fraction);
+                }
+            });
+        }
+
+        // Keep the latest data in view.
+        if let (Some(x_window), false) = (self.follow_latest, mem.follow_paused) {
+            let latest = plot_ui
+                .items
+                .iter()
+                .map(|item| item.bounds().max[0])
+                .filter(|x| x.is_finite())
+                .reduce(f64::max);
+            if let Some(latest) = latest {
+                bounds.min[0] = latest - x_window;
+                bounds.max[0] = latest;
             }
         }
 
-        mem.transform = PlotTransform::new_with_invert_axis(
+        self.bounds_limits.apply(&mut bounds);
+
+        mem.transform = PlotTransform::new_with_axis_transforms(
             plot_rect,
             bounds,
             self.center_axis,
             Vec2b::new(self.invert_x, self.invert_y),
+            self.axis_transforms.clone(),
         );
 
         // Enforce aspect ratio
@@ -1067,193 +1746,1127 @@ impl<'a> Plot<'a> {
         }
     }
 
-    fn handle_interactions(
-        &self,
-        ui: &Ui,
-        mem: &mut PlotMemory,
-        plot_ui: &mut PlotUi<'_>,
-        plot_rect: Rect,
-        axis_responses: &AxisResponses,
-    ) {
-        let response = &mut plot_ui.response;
-        let allow_drag = self.allow_drag.and(ui.is_enabled());
+    /// Whether the item is drawn against the secondary Y axis.
+    fn is_on_secondary_y_axis(&self, item: &dyn PlotItem) -> bool {
+        self.secondary_y_axis.is_some() && item.y_axis() == YAxis::Secondary
+    }
+
+    /// The [`AxisHints::unit`]s of the X and Y axes of `item`, or of the main
+    /// axes without an item.
+    fn units(&self, item: Option<&dyn PlotItem>) -> [&str; 2] {
+        let y_axis = match (item, self.secondary_y_axis) {
+            (Some(item), Some(index)) if self.is_on_secondary_y_axis(item) => index,
+            _ => 0,
+        };
+        [self.x_axes.first(), self.y_axes.get(y_axis)].map(|hints| hints.map_or("", |hints| hints.unit.as_str()))
+    }
+
+    /// Compute the transform of the secondary Y axis, after the primary one is
+    /// final.
+    fn compute_secondary_y_transform(&self, mem: &mut PlotMemory, plot_ui: &PlotUi<'_>) {
+        let last_secondary = mem.secondary_y_transform.take();
+        if self.secondary_y_axis.is_none() {
+            return;
+        }
+        let transforms: AxisTransforms = [self.axis_transforms[0].clone(), self.secondary_y_axis_transform.clone()];
+        let secondary_transform = self.secondary_y_axis_transform.as_ref();
+
+        // The Y range in the plot space of the secondary axis.
+        let (min, max) = match last_secondary {
+            Some(last_secondary) if !mem.auto_bounds.y => {
+                // Keep the mapping between both Y axes from the last frame, so that they pan
+                // and zoom together.
+                let last_primary = plot_ui.last_plot_transform.linear_bounds();
+                let primary = mem.transform.linear_bounds();
+                let last_secondary = last_secondary.linear_bounds();
+                let scale = last_secondary.height() / last_primary.height();
+                (
+                    last_secondary.min[1] + (primary.min[1] - last_primary.min[1]) * scale,
+                    last_secondary.max[1] + (primary.max[1] - last_primary.max[1]) * scale,
+                )
+            }
+            _ => {
+                // Fit to the items on the secondary axis.
+                let mut bounds = PlotBounds::NOTHING;
+                for item in plot_ui
+                    .items
+                    .iter()
+                    .filter(|item| self.is_on_secondary_y_axis(&***item))
+                {
+                    bounds.merge_y(&item.bounds());
+                }
+                let mut linear = PlotBounds::from_min_max(
+                    [0.0, data_to_plot(secondary_transform, bounds.min[1])],
+                    [0.0, data_to_plot(secondary_transform, bounds.max[1])],
+                );
+                linear.add_relative_margin_y(self.margin_fraction);
+                (linear.min[1], linear.max[1])
+            }
+        };
+
+        let primary_bounds = mem.transform.bounds();
+        let bounds = PlotBounds::from_min_max(
+            [primary_bounds.min[0], plot_to_data(secondary_transform, min)],
+            [primary_bounds.max[0], plot_to_data(secondary_transform, max)],
+        );
+        mem.secondary_y_transform = Some(PlotTransform::new_with_axis_transforms(
+            *mem.transform.frame(),
+            bounds,
+            self.center_axis,
+            Vec2b::new(self.invert_x, self.invert_y),
+            transforms,
+        ));
+    }
+
+    /// The transform of the secondary X axis, derived from the main one.
+    ///
+    /// Both share the same plot space, so only the data-space bounds differ.
+    fn secondary_x_transform(&self, transform: &PlotTransform) -> Option<PlotTransform> {
+        let secondary_transform = self.secondary_x_axis_transform.as_ref()?;
+        let linear = transform.linear_bounds();
+        let bounds = transform.bounds();
+        let bounds = PlotBounds::from_min_max(
+            [plot_to_data(Some(secondary_transform), linear.min[0]), bounds.min[1]],
+            [plot_to_data(Some(secondary_transform), linear.max[0]), bounds.max[1]],
+        );
+        Some(PlotTransform::new_with_axis_transforms(
+            *transform.frame(),
+            bounds,
+            false,
+            Vec2b::new(self.invert_x, self.invert_y),
+            [Some(Arc::clone(secondary_transform)), self.axis_transforms[1].clone()],
+        ))
+    }
+
+    /// The transform to draw the item with.
+    fn item_transform<'t>(
+        &self,
+        item: &dyn PlotItem,
+        transform: &'t PlotTransform,
+        secondary_y_transform: Option<&'t PlotTransform>,
+    ) -> &'t PlotTransform {
+        match secondary_y_transform {
+            Some(secondary) if self.is_on_secondary_y_axis(item) => secondary,
+            _ => transform,
+        }
+    }
+
+    fn handle_interactions(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        plot_ui: &mut PlotUi<'_>,
+        plot_rect: Rect,
+        axis_responses: &AxisResponses,
+    ) -> Interaction {
+        let followed_x = mem.transform.bounds().range_x();
+        let PlotUi { items, response, .. } = plot_ui;
+        let mut interaction = Interaction::default();
+        let allow_drag = self.allow_drag.and(ui.is_enabled());
         let allow_zoom = self.allow_zoom.and(ui.is_enabled());
         let allow_scroll = self.allow_scroll.and(ui.is_enabled());
 
-        // Dragging
-        if allow_drag.any() && response.dragged_by(self.pan_pointer_button) {
+        let lasso_held = self
+            .lasso_modifiers
+            .is_some_and(|modifiers| ui.input(|i| i.modifiers.matches_logically(modifiers)));
+
+        if !lasso_held {
+            interaction.dragged_point = self.drag_point(ui, mem, items, response);
+            if mem.dragged_point.is_none() {
+                interaction.dragged_line = self.drag_line(ui, mem, items, response);
+            }
+        }
+        let dragging_item = interaction.dragged_point.is_some() || interaction.dragged_line.is_some();
+
+        if lasso_held && response.drag_started_by(self.interaction_config.pan_button) {
+            mem.lasso = response.interact_pointer_pos().into_iter().collect();
+        }
+        let lassoing = !mem.lasso.is_empty();
+        if lassoing {
+            interaction.gesture = self.lasso(ui, mem, response, plot_rect);
+        }
+
+        if self.view_history_keys && response.hovered() {
+            interaction.stepped_view = Self::step_view_history(ui, mem);
+        }
+
+        // Keyboard navigation
+        if self.input_config.keyboard {
+            self.handle_keys(ui, mem, response, plot_rect);
+        }
+
+        if self.measure_mode && !lassoing && !dragging_item {
+            self.measure(mem, response);
+        }
+        if response.hovered() && ui.input(|i| i.key_pressed(Key::Escape)) {
+            mem.measurement = None;
+        }
+
+        // Editing regions
+        if let (Some(kind), false, false) = (self.region_mode, lassoing, dragging_item) {
+            self.edit_regions(ui, mem, response, kind);
+        }
+
+        let panning = !lassoing
+            && !dragging_item
+            && !self.measure_mode
+            && self.region_mode.is_none()
+            && allow_drag.any()
+            && response.dragged_by(self.interaction_config.pan_button);
+        if panning {
+            self.pan(ui, mem, response, allow_drag);
+        }
+        if let Some(friction) = self.kinetic_friction {
+            Self::coast(ui, mem, response, panning, friction, allow_drag);
+        }
+
+        self.handle_axis_interactions(ui, mem, items, axis_responses, plot_rect);
+
+        if self.allow_boxed_zoom || self.box_select {
+            if let Some(gesture) = self.box_zoom(ui, mem, response, plot_rect) {
+                interaction.gesture = Some(gesture);
+            }
+        }
+
+        // Note: we catch zoom/pan if the response contains the pointer, even if it
+        // isn't hovered. For instance: The user is painting another interactive
+        // widget on top of the plot but they still want to be able to pan/zoom
+        // the plot.
+        if let (true, Some(hover_pos)) = (response.contains_pointer(), ui.input(|i| i.pointer.hover_pos())) {
+            self.zoom_and_scroll(ui, mem, hover_pos, allow_zoom, allow_scroll);
+        }
+
+        let mut bounds = *mem.transform.bounds();
+        self.bounds_limits.apply(&mut bounds);
+        if bounds != *mem.transform.bounds() {
+            mem.transform.set_bounds(bounds);
+        }
+
+        // Moving away from the latest data pauses following it.
+        if self.follow_latest.is_some() && mem.transform.bounds().range_x() != followed_x {
+            mem.follow_paused = true;
+        }
+
+        interaction
+    }
+
+    /// Drag a point of a [`PlotItem::draggable`] item, starting on the point
+    /// near the pointer.
+    fn drag_point(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        items: &[Box<dyn PlotItem + '_>],
+        response: &mut Response,
+    ) -> Option<DraggedPoint> {
+        if response.drag_started_by(self.interaction_config.pan_button) {
+            mem.dragged_point = response
+                .interact_pointer_pos()
+                .and_then(|pointer| self.draggable_point_at(ui, items, pointer, mem));
+        }
+        let (id, index) = mem.dragged_point?;
+        let mut dragged = None;
+        let item = items.iter().find(|item| item.id() == id);
+        if let (Some(item), Some(pointer)) = (item, response.interact_pointer_pos()) {
+            dragged = Some(DraggedPoint {
+                id,
+                index,
+                position: self.snapped_drag_value(ui, items, item.as_ref(), pointer, mem),
+            });
             *response = response.clone().on_hover_cursor(CursorIcon::Grabbing);
-            let mut delta = -response.drag_delta();
-            if !allow_drag.x {
-                delta.x = 0.0;
+        }
+        if !response.dragged() {
+            mem.dragged_point = None;
+        }
+        dragged
+    }
+
+    /// Drag an item along its [`PlotItem::drag_axis`], starting on the item
+    /// near the pointer.
+    fn drag_line(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        items: &[Box<dyn PlotItem + '_>],
+        response: &mut Response,
+    ) -> Option<DraggedLine> {
+        if response.drag_started_by(self.interaction_config.pan_button) {
+            mem.dragged_line = response
+                .interact_pointer_pos()
+                .and_then(|pointer| self.draggable_line_at(ui, items, pointer, mem));
+        }
+        let Some(id) = mem.dragged_line else {
+            if let Some(pointer) = response.hover_pos() {
+                if self.draggable_line_at(ui, items, pointer, mem).is_some() {
+                    *response = response.clone().on_hover_cursor(CursorIcon::Grab);
+                }
+            }
+            return None;
+        };
+        let mut dragged = None;
+        let item = items.iter().find(|item| item.id() == id);
+        if let (Some(item), Some(pointer)) = (item, response.interact_pointer_pos()) {
+            if let Some((axis, _)) = item.drag_axis() {
+                let value = self.snapped_drag_value(ui, items, item.as_ref(), pointer, mem);
+                let value = match axis {
+                    Axis::X => value.x,
+                    Axis::Y => value.y,
+                };
+                dragged = Some(DraggedLine {
+                    id,
+                    value: item.snap_drag(value),
+                });
+                *response = response.clone().on_hover_cursor(CursorIcon::Grabbing);
+            }
+        }
+        if !response.dragged() {
+            mem.dragged_line = None;
+        }
+        dragged
+    }
+
+    /// Draw the lasso while it is dragged, and return it once released.
+    fn lasso(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        response: &mut Response,
+        plot_rect: Rect,
+    ) -> Option<SelectionGesture> {
+        if let Some(pos) = response.interact_pointer_pos() {
+            if mem.lasso.last().is_none_or(|last| last.distance(pos) >= 1.0) {
+                mem.lasso.push(pos);
             }
-            if !allow_drag.y {
+        }
+        if response.drag_stopped() {
+            return Some(SelectionGesture::Lasso(std::mem::take(&mut mem.lasso)));
+        }
+        if self.show_lasso {
+            *response = response.clone().on_hover_cursor(CursorIcon::Crosshair);
+            let painter = ui.painter().with_clip_rect(plot_rect);
+            painter.add(Shape::closed_line(
+                mem.lasso.clone(),
+                Stroke::new(4., Color32::DARK_BLUE),
+            ));
+            painter.add(Shape::closed_line(mem.lasso.clone(), Stroke::new(2., Color32::WHITE)));
+        }
+        None
+    }
+
+    /// Step through the earlier views with the undo and redo shortcuts, and
+    /// return whether the view changed.
+    fn step_view_history(ui: &Ui, mem: &mut PlotMemory) -> bool {
+        let (redo, undo) = ui.input_mut(|i| (i.consume_shortcut(&REDO_VIEW), i.consume_shortcut(&UNDO_VIEW)));
+        let bounds = *mem.transform.bounds();
+        let stepped = if redo {
+            mem.redo_view(bounds)
+        } else if undo {
+            mem.undo_view(bounds)
+        } else {
+            None
+        };
+        let Some(bounds) = stepped else {
+            return false;
+        };
+        mem.transform.set_bounds(bounds);
+        mem.auto_bounds = false.into();
+        true
+    }
+
+    /// Measure between where a drag started and the pointer, see
+    /// [`Self::measure_mode`].
+    fn measure(&self, mem: &mut PlotMemory, response: &Response) {
+        let pointer = response
+            .interact_pointer_pos()
+            .map(|pos| mem.transform.value_from_position(pos));
+        if let (true, Some(pointer)) = (response.drag_started_by(self.interaction_config.pan_button), pointer) {
+            mem.measurement = Some(Measurement {
+                start: pointer,
+                end: pointer,
+            });
+        }
+        if let (true, Some(measurement), Some(pointer)) = (
+            response.dragged_by(self.interaction_config.pan_button),
+            mem.measurement.as_mut(),
+            pointer,
+        ) {
+            measurement.end = pointer;
+        }
+        if response.clicked_by(self.interaction_config.pan_button) {
+            mem.measurement = None;
+        }
+    }
+
+    /// Pan the plot by the drag of the pointer.
+    fn pan(&self, ui: &Ui, mem: &mut PlotMemory, response: &mut Response, allow_drag: Vec2b) {
+        *response = response.clone().on_hover_cursor(CursorIcon::Grabbing);
+        let mut delta = -response.drag_delta();
+        let mut velocity = -ui.input(|i| i.pointer.velocity());
+        if !allow_drag.x {
+            delta.x = 0.0;
+            velocity.x = 0.0;
+        }
+        if !allow_drag.y {
+            delta.y = 0.0;
+            velocity.y = 0.0;
+        }
+        let [x_only, y_only] = self
+            .interaction_config
+            .pan_only_modifiers
+            .map(|modifiers| modifiers.is_some_and(|modifiers| ui.input(|i| i.modifiers.matches_logically(modifiers))));
+        if x_only && !y_only {
+            delta.y = 0.0;
+            velocity.y = 0.0;
+        } else if y_only && !x_only {
+            delta.x = 0.0;
+            velocity.x = 0.0;
+        }
+        let axis_locked = self
+            .interaction_config
+            .axis_lock_modifiers
+            .is_some_and(|modifiers| ui.input(|i| i.modifiers.matches_logically(modifiers)));
+        if let (true, Some(origin), Some(pointer)) = (
+            axis_locked,
+            ui.input(|i| i.pointer.press_origin()),
+            response.interact_pointer_pos(),
+        ) {
+            // Lock to the axis the drag has moved furthest along.
+            let moved = pointer - origin;
+            if moved.x.abs() >= moved.y.abs() {
                 delta.y = 0.0;
+                velocity.y = 0.0;
+            } else {
+                delta.x = 0.0;
+                velocity.x = 0.0;
             }
+        }
+        mem.transform.translate_bounds((delta.x as f64, delta.y as f64));
+        mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+        if self.kinetic_friction.is_some() {
+            mem.pan_velocity = velocity;
+        }
+    }
+
+    /// Keep panning after a fling, slowing down.
+    fn coast(ui: &Ui, mem: &mut PlotMemory, response: &Response, panning: bool, friction: f32, allow_drag: Vec2b) {
+        if response.is_pointer_button_down_on() {
+            if !panning {
+                mem.pan_velocity = Vec2::ZERO;
+            }
+        } else if mem.pan_velocity != Vec2::ZERO {
+            let dt = ui.input(|i| i.stable_dt).min(0.1);
+            let delta = mem.pan_velocity * dt;
             mem.transform.translate_bounds((delta.x as f64, delta.y as f64));
             mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+            mem.pan_velocity *= (-friction * dt).exp();
+            if mem.pan_velocity.length() < MIN_KINETIC_SPEED {
+                mem.pan_velocity = Vec2::ZERO;
+            } else {
+                ui.ctx().request_repaint();
+            }
         }
+    }
 
-        // Drag axes to zoom:
+    /// Zoom or pan by dragging or scrolling over the axes, and fit them to
+    /// the items by double-clicking.
+    fn handle_axis_interactions(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        items: &[Box<dyn PlotItem + '_>],
+        axis_responses: &AxisResponses,
+        plot_rect: Rect,
+    ) {
+        let allow_zoom = self.allow_zoom.and(ui.is_enabled());
+        let allow_scroll = self.allow_scroll.and(ui.is_enabled());
+        // Drag axes to zoom or pan:
         for d in 0..2 {
             if self.allow_axis_zoom_drag[d] {
-                if let Some(axis_response) = axis_responses[d].iter().find(|r| r.dragged_by(PointerButton::Primary)) {
-                    if let Some(drag_start_pos) = ui.input(|i| i.pointer.press_origin()) {
-                        let delta = axis_response.drag_delta();
-
-                        let axis_zoom = 1.0 + (0.02 * delta[d]).clamp(-1.0, 1.0);
-
-                        let zoom = if self.data_aspect.is_some() {
-                            // Zoom both axes equally to maintain aspect ratio:
-                            Vec2::splat(axis_zoom)
-                        } else {
-                            let mut zoom = Vec2::splat(1.0);
-                            zoom[d] = axis_zoom;
-                            zoom
-                        };
-
-                        if zoom != Vec2::splat(1.0) {
-                            let mut zoom_center = plot_rect.center();
-                            zoom_center[d] = drag_start_pos[d];
-                            mem.transform.zoom(zoom, zoom_center);
-                            mem.auto_bounds = false.into();
+                if let Some(axis_response) = axis_responses[d]
+                    .iter()
+                    .find(|r| r.response.dragged_by(PointerButton::Primary))
+                {
+                    let delta = axis_response.response.drag_delta();
+                    match axis_response.drag {
+                        AxisInteraction::Zoom => {
+                            if let Some(drag_start_pos) = ui.input(|i| i.pointer.press_origin()) {
+                                let axis_zoom = 1.0 + (0.02 * delta[d]).clamp(-1.0, 1.0);
+                                self.zoom_axis(mem, d, axis_zoom, drag_start_pos, plot_rect);
+                            }
                         }
+                        AxisInteraction::Pan => Self::pan_axis(mem, d, -delta[d]),
+                        AxisInteraction::None => {}
                     }
                 }
             }
         }
 
-        // Zooming
-        if self.allow_boxed_zoom {
-            // Save last click to allow boxed zooming
-            if response.drag_started() && response.dragged_by(self.boxed_zoom_pointer_button) {
-                // it would be best for egui that input has a memory of the last click pos
-                // because it's a common pattern
-                mem.last_click_pos_for_zoom = response.hover_pos();
-            }
-            let box_start_pos = mem.last_click_pos_for_zoom;
-            let box_end_pos = response.hover_pos();
-            if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
-                // while dragging prepare a Shape and draw it later on top of the plot
-                if response.dragged_by(self.boxed_zoom_pointer_button) {
-                    *response = response.clone().on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
-                    let boxed_zoom_rect = (
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(4., Color32::DARK_BLUE),
-                            egui::StrokeKind::Middle,
-                        ), // Outer stroke
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(2., Color32::WHITE),
-                            egui::StrokeKind::Middle,
-                        ), // Inner stroke
-                    );
-                    ui.painter().with_clip_rect(plot_rect).add(boxed_zoom_rect.0);
-                    ui.painter().with_clip_rect(plot_rect).add(boxed_zoom_rect.1);
-                }
-                // when the click is release perform the zoom
-                if response.drag_stopped() {
-                    let box_start_pos = mem.transform.value_from_position(box_start_pos);
-                    let box_end_pos = mem.transform.value_from_position(box_end_pos);
-                    let new_bounds = PlotBounds {
-                        min: [box_start_pos.x.min(box_end_pos.x), box_start_pos.y.min(box_end_pos.y)],
-                        max: [box_start_pos.x.max(box_end_pos.x), box_start_pos.y.max(box_end_pos.y)],
-                    };
-                    if new_bounds.is_valid() {
-                        mem.transform.set_bounds(new_bounds);
-                        mem.auto_bounds = false.into();
+        // Scroll over axes to zoom or pan:
+        for d in 0..2 {
+            let hovered_axis = axis_responses[d].iter().find(|r| r.response.contains_pointer());
+            if let (Some(axis_response), Some(hover_pos)) = (hovered_axis, ui.input(|i| i.pointer.hover_pos())) {
+                let scroll_delta = ui.input(|i| i.smooth_scroll_delta);
+                // A mouse wheel only scrolls vertically, also over the X axis.
+                let amount = scroll_delta.x + scroll_delta.y;
+                match axis_response.scroll {
+                    AxisInteraction::Zoom if allow_zoom[d] && amount != 0.0 => {
+                        let axis_zoom = (amount / 200.0).exp();
+                        let center = self.zoom_center(&mem.transform, hover_pos);
+                        self.zoom_axis(mem, d, axis_zoom, center, plot_rect);
                     }
-                    // reset the boxed zoom state
-                    mem.last_click_pos_for_zoom = None;
+                    AxisInteraction::Pan if allow_scroll[d] && amount != 0.0 => {
+                        Self::pan_axis(mem, d, -amount);
+                    }
+                    _ => {}
                 }
             }
         }
 
-        // Note: we catch zoom/pan if the response contains the pointer, even if it
-        // isn't hovered. For instance: The user is painting another interactive
-        // widget on top of the plot but they still want to be able to pan/zoom
-        // the plot.
-        if let (true, Some(hover_pos)) = (response.contains_pointer(), ui.input(|i| i.pointer.hover_pos())) {
-            if allow_zoom.any() {
-                let mut zoom_factor = if self.data_aspect.is_some() {
-                    Vec2::splat(ui.input(|i| i.zoom_delta()))
+        // Double-click axes to fit only them:
+        for (d, responses) in axis_responses.iter().enumerate() {
+            if self.allow_double_click_reset && responses.iter().any(|r| r.response.double_clicked()) {
+                self.fit_axis(items, mem, d);
+            }
+        }
+    }
+
+    /// Draw the box while it is dragged, and zoom to it once released, or
+    /// return it to select in with [`Self::box_select`].
+    fn box_zoom(
+        &self,
+        ui: &Ui,
+        mem: &mut PlotMemory,
+        response: &mut Response,
+        plot_rect: Rect,
+    ) -> Option<SelectionGesture> {
+        // Save last click to allow boxed zooming
+        if response.drag_started() && response.dragged_by(self.interaction_config.box_zoom_button) {
+            // it would be best for egui that input has a memory of the last click pos
+            // because it's a common pattern
+            mem.last_click_pos_for_zoom = response.hover_pos();
+        }
+        let box_start_pos = mem.last_click_pos_for_zoom;
+        let box_end_pos = response.hover_pos();
+        if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
+            // while dragging prepare a Shape and draw it later on top of the plot
+            if response.dragged_by(self.interaction_config.box_zoom_button) {
+                let cursor = if self.box_select {
+                    CursorIcon::Crosshair
                 } else {
-                    ui.input(|i| i.zoom_delta_2d())
+                    CursorIcon::ZoomIn
                 };
-                if !allow_zoom.x {
-                    zoom_factor.x = 1.0;
-                }
-                if !allow_zoom.y {
-                    zoom_factor.y = 1.0;
+                *response = response.clone().on_hover_cursor(cursor);
+                let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
+                let boxed_zoom_rect = (
+                    epaint::RectShape::stroke(
+                        rect,
+                        0.0,
+                        epaint::Stroke::new(4., Color32::DARK_BLUE),
+                        egui::StrokeKind::Middle,
+                    ), // Outer stroke
+                    epaint::RectShape::stroke(
+                        rect,
+                        0.0,
+                        epaint::Stroke::new(2., Color32::WHITE),
+                        egui::StrokeKind::Middle,
+                    ), // Inner stroke
+                );
+                ui.painter().with_clip_rect(plot_rect).add(boxed_zoom_rect.0);
+                ui.painter().with_clip_rect(plot_rect).add(boxed_zoom_rect.1);
+            }
+            // when the click is release perform the zoom, or select
+            if response.drag_stopped() && self.box_select {
+                mem.last_click_pos_for_zoom = None;
+                return Some(SelectionGesture::Box(Rect::from_two_pos(box_start_pos, box_end_pos)));
+            } else if response.drag_stopped() {
+                let box_start_pos = mem.transform.value_from_position(box_start_pos);
+                let box_end_pos = mem.transform.value_from_position(box_end_pos);
+                let new_bounds = PlotBounds {
+                    min: [box_start_pos.x.min(box_end_pos.x), box_start_pos.y.min(box_end_pos.y)],
+                    max: [box_start_pos.x.max(box_end_pos.x), box_start_pos.y.max(box_end_pos.y)],
+                };
+                if new_bounds.is_valid() {
+                    mem.transform.set_bounds(new_bounds);
+                    mem.auto_bounds = false.into();
                 }
-                if zoom_factor != Vec2::splat(1.0) {
-                    mem.transform.zoom(zoom_factor, hover_pos);
-                    mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+                // reset the boxed zoom state
+                mem.last_click_pos_for_zoom = None;
+            }
+        }
+        None
+    }
+
+    /// Zoom with the wheel or by pinching, and pan by scrolling, around the
+    /// pointer at `hover_pos`.
+    fn zoom_and_scroll(&self, ui: &Ui, mem: &mut PlotMemory, hover_pos: Pos2, allow_zoom: Vec2b, allow_scroll: Vec2b) {
+        if allow_zoom.any() {
+            let mut zoom_factor = match ui.input(|i| i.multi_touch()) {
+                _ if self.data_aspect.is_some() => Vec2::splat(ui.input(|i| i.zoom_delta())),
+                Some(touch) if self.pinch_proportional => Vec2::splat(touch.zoom_delta),
+                Some(touch) => input::pinch_zoom(touch.zoom_delta_2d, touch.zoom_delta, self.pinch_axis_threshold),
+                None => ui.input(|i| i.zoom_delta_2d()),
+            };
+            if self.interaction_config.scroll_action == ScrollAction::Zoom {
+                let scroll_delta = ui.input(|i| i.smooth_scroll_delta);
+                zoom_factor *= ((scroll_delta.x + scroll_delta.y) / 200.0).exp();
+            }
+            let zoom_speed = self.interaction_config.zoom_speed;
+            zoom_factor = vec2(zoom_factor.x.powf(zoom_speed), zoom_factor.y.powf(zoom_speed));
+            if !allow_zoom.x {
+                zoom_factor.x = 1.0;
+            }
+            if !allow_zoom.y {
+                zoom_factor.y = 1.0;
+            }
+            if zoom_factor != Vec2::splat(1.0) {
+                let center = self.zoom_center(&mem.transform, hover_pos);
+                mem.transform.zoom(zoom_factor, center);
+                mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+            }
+        }
+        if allow_scroll.any() && self.interaction_config.scroll_action == ScrollAction::Pan {
+            let mut scroll_delta = ui.input(|i| i.smooth_scroll_delta) * self.interaction_config.scroll_speed;
+            if !allow_scroll.x {
+                scroll_delta.x = 0.0;
+            }
+            if !allow_scroll.y {
+                scroll_delta.y = 0.0;
+            }
+            if scroll_delta != Vec2::ZERO {
+                mem.transform
+                    .translate_bounds((-scroll_delta.x as f64, -scroll_delta.y as f64));
+                mem.auto_bounds = false.into();
+            }
+        }
+    }
+
+    /// Create regions by dragging, resize them by their edges, and delete
+    /// them by clicking their handles, see [`Self::region_mode`].
+    fn edit_regions(&self, ui: &Ui, mem: &mut PlotMemory, response: &mut Response, kind: RegionKind) {
+        let button = self.interaction_config.pan_button;
+        let interact_radius = ui.style().interaction.interact_radius;
+        let transform = &mem.transform;
+
+        if let (true, Some(pointer)) = (response.clicked_by(button), response.interact_pointer_pos()) {
+            if let Some(index) = mem
+                .regions
+                .iter()
+                .rposition(|region| region.on_delete_handle(pointer, transform))
+            {
+                mem.regions.remove(index);
+            }
+            return;
+        }
+
+        if let (None, Some(pointer)) = (mem.region_drag, response.hover_pos()) {
+            let resize = mem
+                .regions
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, region)| region.resize_at(index, pointer, transform, interact_radius));
+            if let Some(resize) = resize {
+                let cursor = match resize.anchor {
+                    [Some(_), None] => CursorIcon::ResizeHorizontal,
+                    [None, _] => CursorIcon::ResizeVertical,
+                    [Some(x), Some(y)] => {
+                        // The anchors are the opposite corner, so the pointer
+                        // is on the top right or bottom left corner when
+                        // both are on the same side.
+                        let region = &mem.regions[resize.index];
+                        if (x == region.bounds.min[0]) == (y == region.bounds.min[1]) {
+                            CursorIcon::ResizeNeSw
+                        } else {
+                            CursorIcon::ResizeNwSe
+                        }
+                    }
+                };
+                *response = response.clone().on_hover_cursor(cursor);
+            }
+        }
+
+        if response.drag_started_by(button) {
+            let origin = ui.input(|i| i.pointer.press_origin());
+            if let Some(origin) = origin {
+                let resize = mem
+                    .regions
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .find_map(|(index, region)| region.resize_at(index, origin, transform, interact_radius));
+                mem.region_drag = resize.or_else(|| {
+                    let start = transform.value_from_position(origin);
+                    let (bounds, anchor) = match kind {
+                        RegionKind::XSpan => (
+                            PlotBounds::from_min_max([start.x, f64::NEG_INFINITY], [start.x, f64::INFINITY]),
+                            [Some(start.x), None],
+                        ),
+                        RegionKind::Rect => (
+                            PlotBounds::from_min_max([start.x, start.y], [start.x, start.y]),
+                            [Some(start.x), Some(start.y)],
+                        ),
+                    };
+                    mem.regions.push(PlotRegion { kind, bounds });
+                    Some(RegionDrag {
+                        index: mem.regions.len() - 1,
+                        anchor,
+                    })
+                });
+            }
+        }
+
+        if let (true, Some(drag), Some(pointer)) = (
+            response.dragged_by(button),
+            mem.region_drag,
+            response.interact_pointer_pos(),
+        ) {
+            let pointer = mem.transform.value_from_position(pointer);
+            if let Some(region) = mem.regions.get_mut(drag.index) {
+                drag.apply(region, pointer);
+            }
+        }
+
+        if response.drag_stopped() {
+            mem.region_drag = None;
+            // Drop the regions that were clicked rather than dragged out.
+            mem.regions.retain(|region| {
+                let width = region.bounds.width() > 0.0;
+                match region.kind {
+                    RegionKind::XSpan => width,
+                    RegionKind::Rect => width && region.bounds.height() > 0.0,
                 }
+            });
+        }
+    }
+
+    /// Pan and zoom with the keys while the plot has focus, see
+    /// [`PlotInputConfig`].
+    fn handle_keys(&self, ui: &Ui, mem: &mut PlotMemory, response: &Response, plot_rect: Rect) {
+        if response.clicked() {
+            response.request_focus();
+        }
+        if !response.has_focus() {
+            return;
+        }
+        // Keep the arrow keys from moving the focus to other widgets.
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                response.id,
+                EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            );
+        });
+
+        let config = &self.input_config;
+        let allow_drag = self.allow_drag.and(ui.is_enabled());
+        let allow_zoom = self.allow_zoom.and(ui.is_enabled());
+        let (pan, zoom) = ui.input(|i| {
+            // Leave shortcuts like ⌘/Ctrl+Plus to the app.
+            if i.modifiers.command || i.modifiers.alt {
+                return (Vec2::ZERO, 1.0);
+            }
+            let presses = |key: Key| i.num_presses(key) as i32;
+            let pan = vec2(
+                (presses(Key::ArrowRight) - presses(Key::ArrowLeft)) as f32,
+                (presses(Key::ArrowDown) - presses(Key::ArrowUp)) as f32,
+            );
+            let zoom = config
+                .zoom_step
+                .powi(presses(Key::Plus) + presses(Key::Equals) - presses(Key::Minus))
+                * config
+                    .page_zoom_step
+                    .powi(presses(Key::PageUp) - presses(Key::PageDown));
+            (pan * config.pan_step * plot_rect.size(), zoom)
+        });
+
+        if allow_drag.any() && pan != Vec2::ZERO {
+            let pan = vec2(
+                if allow_drag.x { pan.x } else { 0.0 },
+                if allow_drag.y { pan.y } else { 0.0 },
+            );
+            mem.transform.translate_bounds((pan.x as f64, pan.y as f64));
+            mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+        }
+        if allow_zoom.any() && zoom != 1.0 {
+            let zoom = if self.data_aspect.is_some() {
+                Vec2::splat(zoom)
+            } else {
+                vec2(
+                    if allow_zoom.x { zoom } else { 1.0 },
+                    if allow_zoom.y { zoom } else { 1.0 },
+                )
+            };
+            mem.transform.zoom(zoom, plot_rect.center());
+            mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+        }
+    }
+
+    /// The screen position to zoom around when scrolling or pinching with the
+    /// pointer at `pointer`, see [`Self::zoom_anchor`].
+    fn zoom_center(&self, transform: &PlotTransform, pointer: Pos2) -> Pos2 {
+        let frame_center = transform.frame().center();
+        let [x, y] = [0, 1].map(|d| match self.zoom_anchor[d] {
+            ZoomAnchor::Pointer => pointer[d],
+            ZoomAnchor::Center => frame_center[d],
+            ZoomAnchor::Value(value) if d == 0 => transform.position_from_point_x(value),
+            ZoomAnchor::Value(value) => transform.position_from_point_y(value),
+        });
+        Pos2::new(x, y)
+    }
+
+    /// Zoom along axis `d` only by `axis_zoom`, keeping `center` in place.
+    ///
+    /// With a [`Self::data_aspect`] both axes are zoomed, to keep the aspect.
+    fn zoom_axis(&self, mem: &mut PlotMemory, d: usize, axis_zoom: f32, center: Pos2, plot_rect: Rect) {
+        let zoom = if self.data_aspect.is_some() {
+            // Zoom both axes equally to maintain aspect ratio:
+            Vec2::splat(axis_zoom)
+        } else {
+            let mut zoom = Vec2::splat(1.0);
+            zoom[d] = axis_zoom;
+            zoom
+        };
+
+        if zoom != Vec2::splat(1.0) {
+            let mut zoom_center = plot_rect.center();
+            zoom_center[d] = center[d];
+            mem.transform.zoom(zoom, zoom_center);
+            mem.auto_bounds = false.into();
+        }
+    }
+
+    /// Fit axis `d` to the data that is visible along the other axis, keeping
+    /// the other axis as it is.
+    ///
+    /// Series of points only count with the points in view, other items with
+    /// their whole bounds if they overlap the view.
+    fn fit_axis(&self, items: &[Box<dyn PlotItem + '_>], mem: &mut PlotMemory, d: usize) {
+        let mut bounds = *mem.transform.bounds();
+        let other = 1 - d;
+        let in_view = |value: f64| (bounds.min[other]..=bounds.max[other]).contains(&value);
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for item in items {
+            if d == 1 && self.is_on_secondary_y_axis(item.as_ref()) {
+                continue;
             }
-            if allow_scroll.any() {
-                let mut scroll_delta = ui.input(|i| i.smooth_scroll_delta);
-                if !allow_scroll.x {
-                    scroll_delta.x = 0.0;
+            if let Some(points) = item.geometry().points() {
+                for point in points.iter() {
+                    let (value, along) = if d == 0 { (point.x, point.y) } else { (point.y, point.x) };
+                    if value.is_finite() && in_view(along) {
+                        min = min.min(value);
+                        max = max.max(value);
+                    }
                 }
-                if !allow_scroll.y {
-                    scroll_delta.y = 0.0;
+            } else {
+                let item_bounds = item.bounds();
+                if item_bounds.min[other] <= bounds.max[other] && item_bounds.max[other] >= bounds.min[other] {
+                    min = min.min(item_bounds.min[d]);
+                    max = max.max(item_bounds.max[d]);
                 }
-                if scroll_delta != Vec2::ZERO {
-                    mem.transform
-                        .translate_bounds((-scroll_delta.x as f64, -scroll_delta.y as f64));
-                    mem.auto_bounds = false.into();
+            }
+        }
+        if !(min.is_finite() && max.is_finite()) {
+            return;
+        }
+
+        bounds.min[d] = min;
+        bounds.max[d] = max;
+        modify_in_plot_space(&self.axis_transforms, &mut bounds, |bounds| {
+            if d == 0 {
+                bounds.add_relative_margin_x(self.margin_fraction);
+            } else {
+                bounds.add_relative_margin_y(self.margin_fraction);
+            }
+        });
+        if bounds.min[d] == bounds.max[d] {
+            // A single value, e.g. a constant series: center it instead.
+            if d == 0 {
+                bounds.expand_x(0.5);
+            } else {
+                bounds.expand_y(0.5);
+            }
+        }
+        mem.transform.set_bounds(bounds);
+        if d == 0 {
+            mem.auto_bounds.x = false;
+        } else {
+            mem.auto_bounds.y = false;
+        }
+    }
+
+    /// Pan along axis `d` only, by `delta` ui points.
+    fn pan_axis(mem: &mut PlotMemory, d: usize, delta: f32) {
+        let mut translation = Vec2::ZERO;
+        translation[d] = delta;
+        mem.transform
+            .translate_bounds((translation.x as f64, translation.y as f64));
+        if d == 0 {
+            mem.auto_bounds.x = false;
+        } else {
+            mem.auto_bounds.y = false;
+        }
+    }
+
+    /// The topmost draggable line within the interaction radius of `pointer`.
+    fn draggable_line_at(
+        &self,
+        ui: &Ui,
+        items: &[Box<dyn PlotItem + '_>],
+        pointer: Pos2,
+        mem: &PlotMemory,
+    ) -> Option<Id> {
+        let interact_radius = ui.style().interaction.interact_radius;
+        items
+            .iter()
+            .filter(|item| item.draggable())
+            .filter(|item| {
+                let Some((axis, value)) = item.drag_axis() else {
+                    return false;
+                };
+                let item_transform =
+                    self.item_transform(item.as_ref(), &mem.transform, mem.secondary_y_transform.as_ref());
+                let pos = match axis {
+                    Axis::X => item_transform.position_from_point_x(value),
+                    Axis::Y => item_transform.position_from_point_y(value),
+                };
+                (pos - pointer[usize::from(axis)]).abs() <= interact_radius
+            })
+            .map(|item| item.id())
+            .next_back()
+    }
+
+    /// Where an item dragged to `pointer` goes, in the coordinates of its
+    /// axes, snapped as set with [`Self::drag_snap`].
+    fn snapped_drag_value(
+        &self,
+        ui: &Ui,
+        items: &[Box<dyn PlotItem + '_>],
+        item: &dyn PlotItem,
+        pointer: Pos2,
+        mem: &PlotMemory,
+    ) -> PlotPoint {
+        let item_transform = self.item_transform(item, &mem.transform, mem.secondary_y_transform.as_ref());
+        let bypassed = self
+            .interaction_config
+            .snap_bypass_modifiers
+            .is_some_and(|modifiers| ui.input(|i| i.modifiers.matches_logically(modifiers)));
+        let snap = match &self.drag_snap {
+            Some(snap) if !bypassed => snap,
+            _ => return item_transform.value_from_position(pointer),
+        };
+        let radius = ui.style().interaction.interact_radius;
+
+        match snap {
+            DragSnap::Grid => {
+                let mut pos = pointer;
+                for axis in [Axis::X, Axis::Y] {
+                    let d = usize::from(axis);
+                    // Only the grid lines that are drawn.
+                    let lines = self
+                        .grid_marks(&mem.transform, axis)
+                        .into_iter()
+                        .filter(|mark| {
+                            (mem.transform.dpos_dvalue()[d] * mark.step_size).abs() as f32 > self.grid_spacing.min
+                        })
+                        .map(|mark| match axis {
+                            Axis::X => mem.transform.position_from_point_x(mark.value),
+                            Axis::Y => mem.transform.position_from_point_y(mark.value),
+                        });
+                    pos[d] = snap::nearest_within(pos[d], lines, radius);
                 }
+                item_transform.value_from_position(pos)
             }
+            DragSnap::Points => {
+                let nearest = items
+                    .iter()
+                    .filter(|other| other.id() != item.id())
+                    .filter_map(|other| {
+                        let points = other.geometry().points()?;
+                        let other_transform =
+                            self.item_transform(other.as_ref(), &mem.transform, mem.secondary_y_transform.as_ref());
+                        let closest = nearest_point(points, pointer, other_transform, SnapDistance::Euclidean)?;
+                        Some((
+                            closest.dist_sq,
+                            other_transform.position_from_point(&points.get(closest.index)),
+                        ))
+                    })
+                    .filter(|(dist_sq, _)| *dist_sq <= radius * radius)
+                    .min_by(|a, b| a.0.total_cmp(&b.0));
+                item_transform.value_from_position(nearest.map_or(pointer, |(_, pos)| pos))
+            }
+            DragSnap::Custom(snap) => snap(item_transform.value_from_position(pointer)),
         }
     }
 
-    fn render_axis_widgets(&self, ui: &mut Ui, mem: &mut PlotMemory, mut axis_widgets: AxisWidgets<'_>) {
+    /// The topmost draggable data point within the interaction radius of
+    /// `pointer`, as its item id and index.
+    fn draggable_point_at(
+        &self,
+        ui: &Ui,
+        items: &[Box<dyn PlotItem + '_>],
+        pointer: Pos2,
+        mem: &PlotMemory,
+    ) -> Option<(Id, usize)> {
+        let interact_radius_sq = ui.style().interaction.interact_radius.powi(2);
+        items
+            .iter()
+            .filter(|item| item.draggable())
+            .filter_map(|item| {
+                let item_transform =
+                    self.item_transform(item.as_ref(), &mem.transform, mem.secondary_y_transform.as_ref());
+                let closest = item.find_closest(pointer, item_transform)?;
+                (closest.dist_sq <= interact_radius_sq).then(|| (item.id(), closest.index))
+            })
+            .next_back()
+    }
+
+    /// The points of every item within `rect`, in screen coordinates.
+    fn box_selection(
+        &self,
+        items: &[Box<dyn PlotItem + '_>],
+        rect: Rect,
+        transform: &PlotTransform,
+        secondary_y_transform: Option<&PlotTransform>,
+    ) -> BoxSelection {
+        let bounds_in = |transform: &PlotTransform| {
+            let (a, b) = (
+                transform.value_from_position(rect.min),
+                transform.value_from_position(rect.max),
+            );
+            PlotBounds::from_min_max([a.x.min(b.x), a.y.min(b.y)], [a.x.max(b.x), a.y.max(b.y)])
+        };
+        let items = items
+            .iter()
+            .filter_map(|item| {
+                let bounds = bounds_in(self.item_transform(item.as_ref(), transform, secondary_y_transform));
+                SelectedPoints::of_item(item.as_ref(), |point| bounds_contain(&bounds, point))
+            })
+            .collect();
+        BoxSelection {
+            bounds: bounds_in(transform),
+            items,
+        }
+    }
+
+    /// The points of every item within `lasso`, in screen coordinates.
+    fn lasso_selection(
+        &self,
+        items: &[Box<dyn PlotItem + '_>],
+        lasso: &[Pos2],
+        transform: &PlotTransform,
+        secondary_y_transform: Option<&PlotTransform>,
+    ) -> LassoSelection {
+        let polygon_in = |transform: &PlotTransform| -> Vec<PlotPoint> {
+            lasso.iter().map(|pos| transform.value_from_position(*pos)).collect()
+        };
+        let items = items
+            .iter()
+            .filter_map(|item| {
+                let polygon = polygon_in(self.item_transform(item.as_ref(), transform, secondary_y_transform));
+                SelectedPoints::of_item(item.as_ref(), |point| polygon_contains(&polygon, point))
+            })
+            .collect();
+        LassoSelection {
+            polygon: polygon_in(transform),
+            items,
+        }
+    }
+
+    /// Returns the rendered widgets if the crosshair readouts are shown, and
+    /// no widgets otherwise.
+    fn render_axis_widgets<'w>(
+        &self,
+        ui: &mut Ui,
+        mem: &mut PlotMemory,
+        mut axis_widgets: AxisWidgets<'w>,
+        grid_marks: &mut [Arc<Vec<GridMark>>; 2],
+    ) -> AxisWidgets<'w> {
+        let mut rendered: AxisWidgets<'w> = Default::default();
         let bounds = mem.transform.bounds();
         let x_axis_range = bounds.range_x();
-        let x_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * self.grid_spacing.min as f64,
-            };
-            (self.grid_spacers[0])(input)
-        });
+        self.fill_grid_marks(&mem.transform, Axis::X, Arc::make_mut(&mut grid_marks[0]));
+        let x_steps = grid_marks[0].clone();
         let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * self.grid_spacing.min as f64,
-            };
-            (self.grid_spacers[1])(input)
-        });
+        self.fill_grid_marks(&mem.transform, Axis::Y, Arc::make_mut(&mut grid_marks[1]));
+        let y_steps = grid_marks[1].clone();
 
         // Process X-axis widgets
-        for widget in &mut axis_widgets[0] {
-            widget.range = x_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = x_steps.clone();
+        let secondary_x_transform = self.secondary_x_transform(&mem.transform);
+        // The main X grid spacer doesn't apply to the secondary axis, which has its own
+        // transform.
+        let secondary_x_steps = secondary_x_transform.as_ref().and_then(|secondary| {
+            let axis_transform = secondary.axis_transform(Axis::X)?;
+            Some(Arc::new(axis_transform.grid_marks(self.grid_input(secondary, Axis::X))))
+        });
+        for (i, widget) in axis_widgets[0].iter_mut().enumerate() {
+            if let (true, Some(secondary), Some(steps)) = (
+                self.secondary_x_axis == Some(i),
+                &secondary_x_transform,
+                &secondary_x_steps,
+            ) {
+                widget.range = secondary.bounds().range_x();
+                widget.transform = Some(secondary.clone());
+                widget.steps = steps.clone();
+            } else {
+                widget.range = x_axis_range.clone();
+                widget.transform = Some(mem.transform.clone());
+                widget.steps = x_steps.clone();
+            }
         }
         let x_axis_widgets = std::mem::take(&mut axis_widgets[0]);
         for (i, widget) in x_axis_widgets.into_iter().enumerate() {
+            if self.show_axis_readouts {
+                rendered[0].push(widget.clone());
+            }
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
         }
 
         // Process Y-axis widgets
-        for widget in &mut axis_widgets[1] {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
+        let secondary_y_steps = mem
+            .secondary_y_transform
+            .as_ref()
+            .map(|secondary| Arc::new(self.grid_marks(secondary, Axis::Y)));
+        for (i, widget) in axis_widgets[1].iter_mut().enumerate() {
+            if let (true, Some(secondary), Some(steps)) = (
+                self.secondary_y_axis == Some(i),
+                &mem.secondary_y_transform,
+                &secondary_y_steps,
+            ) {
+                widget.range = secondary.bounds().range_y();
+                widget.transform = Some(secondary.clone());
+                widget.steps = steps.clone();
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform.clone());
+                widget.steps = y_steps.clone();
+            }
         }
         let y_axis_widgets = std::mem::take(&mut axis_widgets[1]);
         for (i, widget) in y_axis_widgets.into_iter().enumerate() {
+            if self.show_axis_readouts {
+                rendered[1].push(widget.clone());
+            }
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
+        rendered
+    }
+
+    /// Label the crosshair on each axis it crosses.
+    fn paint_axis_readouts(ui: &Ui, axis_widgets: &AxisWidgets<'_>, cursors: &[Cursor], transform: &PlotTransform) {
+        for cursor in cursors {
+            let (axis, pos) = match *cursor {
+                Cursor::Vertical { x } => (Axis::X, transform.position_from_point_x(x)),
+                Cursor::Horizontal { y } => (Axis::Y, transform.position_from_point_y(y)),
+            };
+            for widget in &axis_widgets[usize::from(axis)] {
+                widget.paint_readout(ui, axis, pos);
+            }
+        }
     }
 
     fn collect_cursors(&self, ui: &Ui, plot_id: Id) -> Vec<Cursor> {
@@ -1288,10 +2901,15 @@ impl<'a> Plot<'a> {
         &self,
         ui: &mut Ui,
         plot_ui: &PlotUi<'_>,
-        plot_id: Id,
-        transform: &PlotTransform,
-        show_xy: Vec2b,
-    ) -> (Vec<Shape>, Vec<Cursor>, Option<Id>) {
+        frame: &ShapesFrame<'_>,
+    ) -> (Vec<Shape>, Vec<Cursor>, Option<PlotItemHit>) {
+        let ShapesFrame {
+            plot_id,
+            transform,
+            secondary_y_transform,
+            grid_marks,
+            show_xy,
+        } = *frame;
         let mut child_ui = ui.new_child(
             egui::UiBuilder::new()
                 .max_rect(*transform.frame())
@@ -1300,20 +2918,20 @@ impl<'a> Plot<'a> {
         child_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
 
         let mut shapes = Vec::new();
-        self.paint_grid(ui, &mut shapes, &plot_ui.items, transform);
+        self.paint_grid(ui, &mut shapes, &plot_ui.items, transform, grid_marks);
 
         // Use plot_ui to provide context for items to generate their shapes
-        for item in &plot_ui.items {
-            item.shapes(&child_ui, transform, &mut shapes);
+        for (index, item) in plot_ui.items.iter().enumerate() {
+            let item_transform = self.item_transform(&**item, transform, secondary_y_transform);
+            // The index tells apart items of the same name.
+            let cache_id = plot_id.with("shape_cache").with(item.id()).with(index);
+            shape_cache::item_shapes(&child_ui, cache_id, &**item, item_transform, &mut shapes);
         }
+        Self::paint_axis_breaks(ui, &mut shapes, transform);
 
-        let hover_pos = plot_ui.response.hover_pos();
         // Use ui to access style and context information for hover detection
-        let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
-            self.handle_hover(ui, pointer, &mut shapes, plot_ui, transform, show_xy)
-        } else {
-            (Vec::new(), None)
-        };
+        let (cursors, hovered_element) =
+            self.handle_hover(ui, &mut shapes, plot_ui, transform, secondary_y_transform, show_xy);
 
         // Draw cursors
         // Use ui to get the default ruler color from the UI style if not explicitly set
@@ -1332,8 +2950,75 @@ impl<'a> Plot<'a> {
             transform,
         );
         Self::draw_cursor(&cursors, true, &mut shapes, line_color, draw_cursor_xy, transform);
+        if self.show_linked_readouts && draw_cursor_xy.x {
+            for cursor in &neighbour_cursors {
+                if let Cursor::Vertical { x } = *cursor {
+                    self.paint_linked_readouts(ui, &mut shapes, &plot_ui.items, x, transform, secondary_y_transform);
+                }
+            }
+        }
+
+        (shapes, cursors, hovered_element)
+    }
+
+    /// The user's context menu entries, followed by the built-in ones.
+    fn context_menu_ui(&self, ui: &mut Ui, mem: &mut PlotMemory, items: &[Box<dyn PlotItem + '_>]) {
+        let (Some(add_contents), Some(target)) = (&self.context_menu, mem.context_menu_target) else {
+            return;
+        };
+        add_contents(ui, &target);
+        if !self.context_menu_builtins {
+            return;
+        }
 
-        (shapes, cursors, hovered_item_id)
+        ui.separator();
+        let item = target.item.and_then(|id| items.iter().find(|item| item.id() == id));
+        if let Some(item) = item {
+            if ui.button(format!("Hide {}", item.name())).clicked() {
+                mem.hidden_items.insert(item.id());
+                ui.close();
+            }
+        }
+        if ui.button("Reset view").clicked() {
+            mem.auto_bounds = true.into();
+            ui.close();
+        }
+        if ui.button("Copy coordinates").clicked() {
+            ui.ctx().copy_text(format!("{}, {}", target.value.x, target.value.y));
+            ui.close();
+        }
+    }
+
+    /// Mark the value of each series at `x` and write it next to the mark.
+    fn paint_linked_readouts(
+        &self,
+        ui: &Ui,
+        shapes: &mut Vec<Shape>,
+        items: &[Box<dyn PlotItem + '_>],
+        x: f64,
+        transform: &PlotTransform,
+        secondary_y_transform: Option<&PlotTransform>,
+    ) {
+        let font_id = TextStyle::Small.resolve(ui.style());
+        for item in items {
+            let Some(y) = item.geometry().points().and_then(|points| y_at_x(points, x)) else {
+                continue;
+            };
+            let item_transform = self.item_transform(item.as_ref(), transform, secondary_y_transform);
+            let pos = item_transform.position_from_point(&PlotPoint::new(x, y));
+            let text = format!("{}: {}", item.name(), format_number(y, 3));
+            shapes.push(Shape::circle_filled(pos, 3.0, item.color()));
+            ui.fonts_mut(|f| {
+                shapes.push(Shape::text(
+                    f,
+                    pos + vec2(6.0, 0.0),
+                    Align2::LEFT_CENTER,
+                    text,
+                    font_id.clone(),
+                    item.color(),
+                ));
+            });
+        }
     }
 
     fn paint_grid(
@@ -1342,14 +3027,15 @@ impl<'a> Plot<'a> {
         shapes: &mut Vec<Shape>,
         items: &[Box<dyn PlotItem + '_>],
         transform: &PlotTransform,
+        grid_marks: &[Arc<Vec<GridMark>>; 2],
     ) {
         let mut axes_shapes = Vec::new();
         // Use ui to access style information and context for painting grid lines
         if self.show_grid.x {
-            self.paint_grid_direction(ui, &mut axes_shapes, Axis::X, items, transform);
+            self.paint_grid_direction(ui, &mut axes_shapes, Axis::X, items, transform, &grid_marks[0]);
         }
         if self.show_grid.y {
-            self.paint_grid_direction(ui, &mut axes_shapes, Axis::Y, items, transform);
+            self.paint_grid_direction(ui, &mut axes_shapes, Axis::Y, items, transform, &grid_marks[1]);
         }
         // Sort the axes by strength so that those with higher strength are drawn in
         // front.
@@ -1359,6 +3045,32 @@ impl<'a> Plot<'a> {
         shapes.extend(axes_shapes.into_iter().map(|(shape, _)| shape));
     }
 
+    /// Draw a zig-zag across the plot wherever an axis transform skips a range.
+    fn paint_axis_breaks(ui: &Ui, shapes: &mut Vec<Shape>, transform: &PlotTransform) {
+        let frame = *transform.frame();
+        let background = Stroke::new(5.0, ui.visuals().extreme_bg_color);
+        let line = Stroke::new(1.0, ui.visuals().widgets.noninteractive.fg_stroke.color);
+
+        for axis in [Axis::X, Axis::Y] {
+            let Some(axis_transform) = transform.axis_transform(axis) else {
+                continue;
+            };
+            let iaxis = usize::from(axis);
+            for value in axis_transform.breaks() {
+                let pos = match axis {
+                    Axis::X => transform.position_from_point_x(value),
+                    Axis::Y => transform.position_from_point_y(value),
+                };
+                if !(frame.min[iaxis]..=frame.max[iaxis]).contains(&pos) {
+                    continue;
+                }
+                for (offset, stroke) in [(0.0, background), (-3.0, line), (3.0, line)] {
+                    shapes.push(Shape::line(break_zigzag(frame, axis, pos + offset), stroke));
+                }
+            }
+        }
+    }
+
     fn draw_cursor(
         cursors: &[Cursor],
         always: bool,
@@ -1397,13 +3109,14 @@ impl<'a> Plot<'a> {
         transform: &PlotTransform,
         painter: &Painter,
         coordinates_formatter: &Option<(Corner, CoordinatesFormatter<'_>)>,
+        units: [&str; 2],
     ) {
         if let Some((corner, formatter)) = coordinates_formatter.as_ref() {
             let hover_pos = response.hover_pos();
             if let Some(pointer) = hover_pos {
                 let font_id = TextStyle::Monospace.resolve(ui.style());
                 let coordinate = transform.value_from_position(pointer);
-                let text = formatter.format(&coordinate, transform.bounds());
+                let text = formatter.format(&coordinate, transform.bounds(), units);
                 let padded_frame = transform.frame().shrink(4.0);
                 let (anchor, position) = match corner {
                     Corner::LeftTop => (Align2::LEFT_TOP, padded_frame.left_top()),
@@ -1425,6 +3138,96 @@ impl<'a> Plot<'a> {
         }
     }
 
+    /// The grid marks along the given axis, from its grid spacer or else its
+    /// axis transform.
+    fn grid_marks(&self, transform: &PlotTransform, axis: Axis) -> Vec<GridMark> {
+        let mut marks = Vec::new();
+        self.fill_grid_marks(transform, axis, &mut marks);
+        marks
+    }
+
+    /// Like [`Self::grid_marks`], but reusing the allocation of `marks` for
+    /// the built-in spacers.
+    fn fill_grid_marks(&self, transform: &PlotTransform, axis: Axis, marks: &mut Vec<GridMark>) {
+        let iaxis = usize::from(axis);
+        let mut input = self.grid_input(transform, axis);
+        if let Some(ticks) = &self.ticks[iaxis] {
+            *marks = explicit_grid_marks(ticks, transform, axis, input.bounds);
+            return;
+        }
+        if let Some(count) = self.tick_counts[iaxis] {
+            if self.grid_spacers[iaxis].is_none() && transform.axis_transform(axis).is_none() {
+                crate::grid::nice_grid_marks_into(marks, input.bounds, count);
+                return;
+            }
+            input.base_step_size = input
+                .base_step_size
+                .max((input.bounds.1 - input.bounds.0) / count.max(1) as f64);
+        }
+        match (&self.grid_spacers[iaxis], transform.axis_transform(axis)) {
+            (Some(spacer), _) => *marks = spacer(input),
+            (None, Some(axis_transform)) => *marks = axis_transform.grid_marks(input),
+            (None, None) => crate::grid::log_grid_marks_into(marks, &input, 10.0),
+        }
+    }
+
+    /// The minor marks along the given axis, from its axis transform.
+    ///
+    /// A custom grid spacer or explicit ticks replace these as well.
+    fn minor_grid_marks(&self, transform: &PlotTransform, axis: Axis) -> Vec<GridMark> {
+        let iaxis = usize::from(axis);
+        if self.ticks[iaxis].is_some() {
+            return Vec::new();
+        }
+        match (&self.grid_spacers[iaxis], transform.axis_transform(axis)) {
+            (None, Some(axis_transform)) => axis_transform.minor_grid_marks(self.grid_input(transform, axis)),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The input of the grid spacers, in plot space.
+    fn grid_input(&self, transform: &PlotTransform, axis: Axis) -> GridInput {
+        let iaxis = usize::from(axis);
+        let bounds = transform.linear_bounds();
+        GridInput {
+            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
+            base_step_size: transform.dvalue_dpos()[iaxis].abs() * self.grid_spacing.min as f64,
+        }
+    }
+
+    /// Paint minor marks as short, faint ticks along the edges of the plot.
+    fn paint_minor_ticks(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, transform: &PlotTransform) {
+        const TICK_LENGTH: f32 = 4.0;
+
+        let iaxis = usize::from(axis);
+        let frame = transform.frame();
+        for mark in self.minor_grid_marks(transform, axis) {
+            let spacing_in_points = (transform.dpos_dvalue()[iaxis] * mark.step_size).abs() as f32;
+            if spacing_in_points <= self.grid_spacing.min {
+                continue; // Too close together
+            }
+
+            let line_strength = 0.5 * remap_clamp(spacing_in_points, self.grid_spacing, 0.0..=1.0);
+            let stroke = Stroke::new(1.0, crate::colors::color_from_strength(ui, line_strength));
+
+            let pos = match axis {
+                Axis::X => transform.position_from_point_x(mark.value),
+                Axis::Y => transform.position_from_point_y(mark.value),
+            };
+            for (edge, inwards) in [
+                (frame.min[1 - iaxis], TICK_LENGTH),
+                (frame.max[1 - iaxis], -TICK_LENGTH),
+            ] {
+                let mut p0 = Pos2::ZERO;
+                p0[iaxis] = pos;
+                p0[1 - iaxis] = edge;
+                let mut p1 = p0;
+                p1[1 - iaxis] += inwards;
+                shapes.push((Shape::line_segment([p0, p1], stroke), line_strength));
+            }
+        }
+    }
+
     fn paint_grid_direction(
         &self,
         ui: &Ui,
@@ -1432,6 +3235,7 @@ impl<'a> Plot<'a> {
         axis: Axis,
         items: &[Box<dyn PlotItem + '_>],
         transform: &PlotTransform,
+        steps: &[GridMark],
     ) {
         let iaxis = usize::from(axis);
 
@@ -1439,12 +3243,6 @@ impl<'a> Plot<'a> {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
-        let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * self.grid_spacing.min as f64,
-        };
-        let steps = (self.grid_spacers[iaxis])(input);
-
         let clamp_range = self.clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
             for item in items {
@@ -1512,40 +3310,29 @@ impl<'a> Plot<'a> {
                 line_strength,
             ));
         }
+
+        self.paint_minor_ticks(ui, shapes, axis, transform);
     }
 
     fn handle_hover(
         &self,
         ui: &Ui,
-        pointer: Pos2,
         shapes: &mut Vec<Shape>,
         plot_ui: &PlotUi<'_>,
         transform: &PlotTransform,
+        secondary_y_transform: Option<&PlotTransform>,
         show_xy: Vec2b,
-    ) -> (Vec<Cursor>, Option<Id>) {
+    ) -> (Vec<Cursor>, Option<PlotItemHit>) {
+        let Some(pointer) = plot_ui.response.hover_pos() else {
+            return (Vec::new(), None);
+        };
         if !show_xy.any() {
             return (Vec::new(), None);
         }
 
-        let interact_radius_sq = ui.style().interaction.interact_radius.powi(2);
-
-        let candidates = plot_ui
-            .items
-            .iter()
-            .filter(|entry| entry.allow_hover())
-            .filter_map(|item| {
-                let item = &**item;
-                let closest = item.find_closest(pointer, transform);
-
-                Some(item).zip(closest)
-            });
-
-        // Since many items can have same distance,
-        // and dist_sq can be zero for some items (e.g. rectangle)
-        // we pick topmost item within interact radius
-        let topmost = candidates
-            .filter(|(_, elem)| elem.dist_sq <= interact_radius_sq)
-            .next_back();
+        let topmost = self.item_at(ui, &plot_ui.items, pointer, transform, secondary_y_transform, |item| {
+            item.allow_hover()
+        });
 
         let plot = crate::PlotConfig {
             ui,
@@ -1557,16 +3344,42 @@ impl<'a> Plot<'a> {
 
         let mut cursors = Vec::new();
 
-        let hovered_plot_item_id = if let Some((item, elem)) = topmost {
-            item.on_hover(
-                &plot_ui.response,
-                elem,
-                shapes,
-                &mut cursors,
-                &plot,
-                &self.label_formatter,
-            );
-            Some(item.id())
+        let hovered_element = if let Some((item, elem)) = topmost {
+            let item_transform = self.item_transform(item, transform, secondary_y_transform);
+            let item_plot = crate::PlotConfig {
+                transform: item_transform,
+                ..plot
+            };
+            let hit = Self::item_hit(item, &elem, pointer, item_transform);
+            match (self.hover_snap, item.geometry().points()) {
+                (Some(_), Some(points)) => {
+                    let value = points.get(elem.index);
+                    let label = match &self.label_formatter {
+                        Some(formatter) => formatter(item.name(), &value),
+                        None => label_with_units(item.name(), &value, self.units(Some(item))),
+                    };
+                    show_snapped_point(&plot_ui.response, item, value, shapes, &mut cursors, &item_plot, label);
+                }
+                _ => item.on_hover(
+                    &plot_ui.response,
+                    elem,
+                    shapes,
+                    &mut cursors,
+                    &item_plot,
+                    &self.label_formatter,
+                ),
+            }
+
+            // Cursors are drawn with the primary transform.
+            if !std::ptr::eq(item_transform, transform) {
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        let pos = item_transform.position_from_point_y(*y);
+                        *y = transform.value_from_position(Pos2::new(0.0, pos)).y;
+                    }
+                }
+            }
+            Some(hit)
         } else {
             let value = transform.value_from_position(pointer);
             items::rulers_and_tooltip_at_value(
@@ -1580,7 +3393,112 @@ impl<'a> Plot<'a> {
             None
         };
 
-        (cursors, hovered_plot_item_id)
+        (cursors, hovered_element)
+    }
+
+    /// The highlights of the points brushed in `group`.
+    fn brush_shapes(
+        &self,
+        ui: &Ui,
+        group: BrushGroup,
+        items: &[Box<dyn PlotItem + '_>],
+        mem: &PlotMemory,
+    ) -> Vec<Shape> {
+        let brushed = group.brushed(ui.ctx());
+        let mut shapes = Vec::new();
+        if brushed.is_empty() {
+            return shapes;
+        }
+        for item in items {
+            if let Some(points) = item.geometry().points() {
+                let item_transform =
+                    self.item_transform(item.as_ref(), &mem.transform, mem.secondary_y_transform.as_ref());
+                brush::mark_points(points, &brushed, item.color(), item_transform, &mut shapes);
+            }
+        }
+        shapes
+    }
+
+    /// Rings around the points marked as selected in the memory, see
+    /// [`PlotMemory::select`].
+    fn selection_shapes(&self, ui: &Ui, items: &[Box<dyn PlotItem + '_>], mem: &PlotMemory) -> Vec<Shape> {
+        let mut shapes = Vec::new();
+        let color = ui.visuals().selection.stroke.color;
+        for item in items {
+            if let (Some(indices), Some(points)) = (mem.selected.get(&item.id()), item.geometry().points()) {
+                let item_transform =
+                    self.item_transform(item.as_ref(), &mem.transform, mem.secondary_y_transform.as_ref());
+                brush::mark_points(points, indices, color, item_transform, &mut shapes);
+            }
+        }
+        shapes
+    }
+
+    /// The element of an item under `pointer`, if any, among the items that
+    /// pass `filter`, e.g. the hoverable ones.
+    fn item_at<'i>(
+        &self,
+        ui: &Ui,
+        items: &'i [Box<dyn PlotItem + '_>],
+        pointer: Pos2,
+        transform: &PlotTransform,
+        secondary_y_transform: Option<&PlotTransform>,
+        filter: impl Fn(&dyn PlotItem) -> bool,
+    ) -> Option<(&'i dyn PlotItem, ClosestElem)> {
+        let default_radius = match self.hover_snap {
+            Some((radius, _)) => radius,
+            None => ui.style().interaction.interact_radius,
+        };
+
+        let candidates: Vec<_> = items
+            .iter()
+            .filter_map(|item| {
+                let item: &dyn PlotItem = &**item;
+                if !filter(item) {
+                    return None;
+                }
+                let item_transform = self.item_transform(item, transform, secondary_y_transform);
+                let closest = match (self.hover_snap, item.geometry().points()) {
+                    (Some((_, distance)), Some(points)) => nearest_point(points, pointer, item_transform, distance),
+                    _ => item.find_closest(pointer, item_transform),
+                };
+
+                Some(item).zip(closest)
+            })
+            .filter(|(item, elem)| elem.dist_sq <= item.hover_radius().unwrap_or(default_radius).powi(2))
+            .collect();
+
+        // Only the items of the highest priority near the pointer compete.
+        let priority = candidates.iter().map(|(item, _)| item.hover_priority()).max()?;
+        let mut candidates = candidates
+            .into_iter()
+            .filter(|(item, _)| item.hover_priority() == priority);
+
+        // Since many items can have same distance,
+        // and dist_sq can be zero for some items (e.g. rectangle)
+        // we pick topmost item within interact radius, or the nearest one
+        // when snapping, preferring the topmost among equally near ones.
+        if self.hover_snap.is_some() {
+            candidates
+                .rev()
+                .min_by(|(_, a), (_, b)| a.dist_sq.total_cmp(&b.dist_sq))
+        } else {
+            candidates.next_back()
+        }
+    }
+
+    /// The element `elem` of `item` under `pointer`, as reported to the app.
+    fn item_hit(item: &dyn PlotItem, elem: &ClosestElem, pointer: Pos2, item_transform: &PlotTransform) -> PlotItemHit {
+        let value = match item.geometry().points() {
+            Some(points) => points.get(elem.index),
+            None => item_transform.value_from_position(pointer),
+        };
+        PlotItemHit {
+            id: item.id(),
+            name: item.name().to_owned(),
+            index: elem.index,
+            value,
+        }
     }
 
     fn show_dyn<R>(self, ui: &mut Ui, build_fn: Box<dyn FnOnce(&mut PlotUi<'a>) -> R + 'a>) -> PlotResponse<R> {
@@ -1589,18 +3507,26 @@ impl<'a> Plot<'a> {
         // Get complete rect for drawing.
         let complete_rect = self.calculate_widget_complete_rect(ui);
 
+        let axes = [self.labeled_axes(Axis::X), self.labeled_axes(Axis::Y)];
         let (axis_widgets, plot_rect) = axis_widgets(
             PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(#164): avoid loading plot memory twice
             self.show_axes,
             complete_rect,
-            [&self.x_axes, &self.y_axes],
+            [&axes[0], &axes[1]],
         );
         let response = ui.allocate_rect(plot_rect, self.sense);
         let axis_responses = self.allocate_axis_responses(ui, &axis_widgets);
 
         // Load or initialize memory
         let mut mem = self.load_or_init_memory(ui, plot_id, plot_rect);
-        let last_plot_transform = mem.transform;
+        let last_plot_transform = mem.transform.clone();
+
+        // The retained series are taken out of the memory while the app
+        // pushes to them, so that their points aren't shared and copied.
+        let retained_id = plot_id.with("retained_series");
+        let retained = ui.ctx().data_mut(|data| {
+            std::mem::take(data.get_temp_mut_or_default::<ahash::HashMap<Id, RetainedSeries>>(retained_id))
+        });
 
         // Call the plot build function.
         let mut plot_ui = PlotUi {
@@ -1609,12 +3535,16 @@ impl<'a> Plot<'a> {
             next_auto_color_idx: 0,
             last_plot_transform,
             last_auto_bounds: mem.auto_bounds,
+            last_live: self.follow_latest.is_some() && !mem.follow_paused,
             response,
             bounds_modifications: Vec::new(),
+            retained,
         };
 
         // Populates items and does other modifications to plot_ui.
         let inner = build_fn(&mut plot_ui);
+        let retained = std::mem::take(&mut plot_ui.retained);
+        ui.ctx().data_mut(|data| data.insert_temp(retained_id, retained));
 
         // Background
         self.paint_background(ui, plot_rect);
@@ -1627,23 +3557,103 @@ impl<'a> Plot<'a> {
         self.compute_bounds(ui, &mut mem, &plot_ui, plot_rect);
 
         // Handle interactions (modifies plot_ui.response in place)
-        self.handle_interactions(ui, &mut mem, &mut plot_ui, plot_rect, &axis_responses);
+        let interaction = self.handle_interactions(ui, &mut mem, &mut plot_ui, plot_rect, &axis_responses);
+        self.compute_secondary_y_transform(&mut mem, &plot_ui);
 
         // Render axis widgets
-        self.render_axis_widgets(ui, &mut mem, axis_widgets);
+        // The grid marks are generated into the buffers of the last frame,
+        // taken out of the memory so that they aren't shared.
+        let grid_marks_id = plot_id.with("grid_marks");
+        let mut grid_marks: [Arc<Vec<GridMark>>; 2] = ui
+            .ctx()
+            .data_mut(|data| std::mem::take(data.get_temp_mut_or_default(grid_marks_id)));
+        let rendered_axis_widgets = self.render_axis_widgets(ui, &mut mem, axis_widgets, &mut grid_marks);
 
         // Initialize values from functions.
         for item in &mut plot_ui.items {
             item.initialize(mem.transform.bounds().range_x());
         }
 
-        let (shapes, plot_cursors, mut hovered_plot_item) =
-            self.collect_shapes(ui, &plot_ui, plot_id, &mem.transform, show_xy);
+        let (mut box_selection, mut lasso_selection) = (None, None);
+        match interaction.gesture {
+            Some(SelectionGesture::Box(rect)) => {
+                box_selection =
+                    Some(self.box_selection(&plot_ui.items, rect, &mem.transform, mem.secondary_y_transform.as_ref()));
+            }
+            Some(SelectionGesture::Lasso(lasso)) => {
+                lasso_selection = Some(self.lasso_selection(
+                    &plot_ui.items,
+                    &lasso,
+                    &mem.transform,
+                    mem.secondary_y_transform.as_ref(),
+                ));
+            }
+            None => {}
+        }
+        if let Some(group) = self.brush_group {
+            let selected = box_selection
+                .as_ref()
+                .map(|selection| &selection.items)
+                .or_else(|| lasso_selection.as_ref().map(|selection| &selection.items));
+            if let Some(selected) = selected {
+                group.set_brushed(ui.ctx(), selected.iter().flat_map(|item| item.indices.iter().copied()));
+            }
+        }
+
+        let (shapes, plot_cursors, hovered_element) = self.collect_shapes(
+            ui,
+            &plot_ui,
+            &ShapesFrame {
+                plot_id,
+                transform: &mem.transform,
+                secondary_y_transform: mem.secondary_y_transform.as_ref(),
+                grid_marks: &grid_marks,
+                show_xy,
+            },
+        );
+        ui.ctx().data_mut(|data| data.insert_temp(grid_marks_id, grid_marks));
+        let mut hovered_plot_item = hovered_element.as_ref().map(|hit| hit.id);
+
+        let clicked_item = plot_ui
+            .response
+            .clicked()
+            .then(|| plot_ui.response.interact_pointer_pos())
+            .flatten()
+            .and_then(|pointer| {
+                let (item, elem) = self.item_at(
+                    ui,
+                    &plot_ui.items,
+                    pointer,
+                    &mem.transform,
+                    mem.secondary_y_transform.as_ref(),
+                    |item| item.clickable(),
+                )?;
+                let item_transform = self.item_transform(item, &mem.transform, mem.secondary_y_transform.as_ref());
+                Some(Self::item_hit(item, &elem, pointer, item_transform))
+            });
 
         // Get the painter from ui and configure it with the plot's clip rect
         // The painter is used to render all accumulated shapes
         let painter = ui.painter().with_clip_rect(*mem.transform.frame());
         painter.extend(shapes);
+        if let Some(group) = self.brush_group {
+            painter.extend(self.brush_shapes(ui, group, &plot_ui.items, &mem));
+        }
+        painter.extend(self.selection_shapes(ui, &plot_ui.items, &mem));
+        let mut region_shapes = Vec::new();
+        let pointer = plot_ui.response.hover_pos();
+        for region in &mem.regions {
+            // The delete handles are only shown while regions can be edited.
+            let handle = self
+                .region_mode
+                .map(|_| pointer.is_some_and(|pos| region.on_delete_handle(pos, &mem.transform)));
+            region.paint(ui, &mem.transform, handle, &mut region_shapes);
+        }
+        painter.extend(region_shapes);
+        if let Some(measurement) = &mem.measurement {
+            measurement.paint(ui, &painter, &mem.transform);
+        }
+        Self::paint_axis_readouts(ui, &rendered_axis_widgets, &plot_cursors, &mem.transform);
 
         // Show coordinates in a corner of the plot
         // Use ui to access style information and draw the coordinate text overlay
@@ -1653,8 +3663,22 @@ impl<'a> Plot<'a> {
             &mem.transform,
             &painter,
             &self.coordinates_formatter,
+            self.units(None),
         );
 
+        // Remember what the context menu was opened on, and show it
+        if self.context_menu.is_some() {
+            if plot_ui.response.secondary_clicked() {
+                mem.context_menu_target = plot_ui.response.interact_pointer_pos().map(|pos| ContextMenuTarget {
+                    item: hovered_plot_item,
+                    value: mem.transform.value_from_position(pos),
+                });
+            }
+            plot_ui
+                .response
+                .context_menu(|ui| self.context_menu_ui(ui, &mut mem, &plot_ui.items));
+        }
+
         // Show legend and update memory
         Self::show_legend_and_update_memory(legend, ui, &mut mem, &mut hovered_plot_item);
 
@@ -1685,6 +3709,24 @@ impl<'a> Plot<'a> {
             });
         }
 
+        // Remember the view from before the user changed it. Changes while the
+        // bounds follow the data, and stepping through the history, don't count.
+        let changed = mem.transform.bounds() != plot_ui.last_plot_transform.bounds();
+        let live = self.follow_latest.is_some() && !mem.follow_paused;
+        let automatic = live || (mem.auto_bounds.any() && mem.auto_bounds == plot_ui.last_auto_bounds);
+        let stepped_view = interaction.stepped_view
+            || plot_ui.bounds_modifications.iter().any(|modification| {
+                matches!(
+                    modification,
+                    BoundsModification::UndoView | BoundsModification::RedoView
+                )
+            });
+        let user_change = changed && !automatic && !stepped_view;
+        if user_change && !mem.view_changing {
+            mem.record_view(*plot_ui.last_plot_transform.bounds());
+        }
+        mem.view_changing = user_change;
+
         let response = if show_xy.any() {
             plot_ui.response.on_hover_cursor(CursorIcon::Crosshair)
         } else {
@@ -1702,10 +3744,69 @@ impl<'a> Plot<'a> {
             response,
             transform,
             hovered_plot_item,
+            box_selection,
+            lasso_selection,
+            dragged_point: interaction.dragged_point,
+            dragged_line: interaction.dragged_line,
+            clicked_item,
+            hovered_element,
         }
     }
 }
 
+/// A zig-zag line across `frame` at the screen position `pos` along `axis`.
+fn break_zigzag(frame: Rect, axis: Axis, pos: f32) -> Vec<Pos2> {
+    const AMPLITUDE: f32 = 2.0;
+    const STEP: f32 = 4.0;
+
+    let iaxis = usize::from(axis);
+    let cross = frame.min[1 - iaxis]..=frame.max[1 - iaxis];
+    let num_points = ((cross.end() - cross.start()) / STEP).ceil() as usize + 1;
+    (0..num_points)
+        .map(|i| {
+            let mut point = Pos2::ZERO;
+            point[1 - iaxis] = (cross.start() + i as f32 * STEP).min(*cross.end());
+            point[iaxis] = pos + if i % 2 == 0 { -AMPLITUDE } else { AMPLITUDE };
+            point
+        })
+        .collect()
+}
+
+/// The grid marks of explicit ticks, within the plot-space `bounds`.
+///
+/// All marks get the same step size: the smallest gap between two ticks in
+/// plot space, so that the grid doesn't change when panning.
+fn explicit_grid_marks(ticks: &[Tick], transform: &PlotTransform, axis: Axis, bounds: (f64, f64)) -> Vec<GridMark> {
+    let axis_transform = transform.axis_transform(axis);
+    let to_plot = |value: f64| axis_transform.map_or(value, |t| t.data_to_plot(value));
+
+    let mut positions: Vec<f64> = ticks
+        .iter()
+        .map(|tick| to_plot(tick.value))
+        .filter(|position| position.is_finite())
+        .collect();
+    positions.sort_by(f64::total_cmp);
+    let step_size = positions
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|gap| *gap > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    let step_size = if step_size.is_finite() {
+        step_size
+    } else {
+        bounds.1 - bounds.0
+    };
+
+    ticks
+        .iter()
+        .filter(|tick| (bounds.0..=bounds.1).contains(&to_plot(tick.value)))
+        .map(|tick| GridMark {
+            value: tick.value,
+            step_size,
+        })
+        .collect()
+}
+
 /// Returns the rect left after adding axes.
 fn axis_widgets<'a>(
     mem: Option<&PlotMemory>,
@@ -1844,6 +3945,86 @@ pub struct PlotResponse<R> {
     /// A plot item can be hovered either by hovering its representation in the
     /// plot (line, marker, etc.) or by hovering the item in the legend.
     pub hovered_plot_item: Option<Id>,
+
+    /// The box the user just finished dragging out and the points within
+    /// it, if [`Plot::box_select`] is on.
+    ///
+    /// This is only `Some` in the frame the drag is released.
+    pub box_selection: Option<BoxSelection>,
+
+    /// The lasso the user just finished drawing and the points within it, if
+    /// [`Plot::lasso_select`] is on.
+    ///
+    /// This is only `Some` in the frame the drag is released.
+    pub lasso_selection: Option<LassoSelection>,
+
+    /// The data point being dragged and where to, see
+    /// [`crate::Points::draggable`].
+    ///
+    /// This is `Some` in every frame of the drag, including the one where it
+    /// is released.
+    pub dragged_point: Option<DraggedPoint>,
+
+    /// The line being dragged and its new value, see
+    /// [`crate::HLine::draggable`].
+    ///
+    /// This is `Some` in every frame of the drag, including the one where it
+    /// is released.
+    pub dragged_line: Option<DraggedLine>,
+
+    /// The element of an item that was clicked this frame, if any, e.g. to
+    /// open a detail view of the clicked sample.
+    ///
+    /// Items are hit like when hovering them, see [`Plot::hover_snap`].
+    pub clicked_item: Option<PlotItemHit>,
+
+    /// The element of an item under the pointer this frame, if any, e.g. to
+    /// mirror the hover state in a side panel or status bar.
+    ///
+    /// Unlike [`Self::hovered_plot_item`], this is only set by hovering the
+    /// item in the plot, not in the legend.
+    pub hovered_element: Option<PlotItemHit>,
+}
+
+/// The speed below which kinetic panning stops, in ui points per second.
+const MIN_KINETIC_SPEED: f32 = 10.0;
+
+/// Steps back through the earlier views, see [`Plot::view_history_keys`].
+const UNDO_VIEW: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+
+/// Steps forth through the undone views, see [`Plot::view_history_keys`].
+const REDO_VIEW: KeyboardShortcut = KeyboardShortcut::new(
+    Modifiers {
+        shift: true,
+        ..Modifiers::COMMAND
+    },
+    Key::Z,
+);
+
+/// A selection gesture finished this frame, in screen coordinates.
+enum SelectionGesture {
+    Box(Rect),
+    Lasso(Vec<Pos2>),
+}
+
+/// Where and how [`Plot::collect_shapes`] draws the plot this frame.
+struct ShapesFrame<'p> {
+    plot_id: Id,
+    transform: &'p PlotTransform,
+    secondary_y_transform: Option<&'p PlotTransform>,
+
+    /// The marks of the grid along x and y.
+    grid_marks: &'p [Arc<Vec<GridMark>>; 2],
+    show_xy: Vec2b,
+}
+
+/// What the user did in the plot this frame, besides moving the bounds.
+#[derive(Default)]
+struct Interaction {
+    gesture: Option<SelectionGesture>,
+    dragged_point: Option<DraggedPoint>,
+    dragged_line: Option<DraggedLine>,
+    stepped_view: bool,
 }
 
 /// Provides methods to interact with a plot while building it. It is the single
@@ -1855,8 +4036,10 @@ pub struct PlotUi<'a> {
     pub(crate) next_auto_color_idx: usize,
     pub(crate) last_plot_transform: PlotTransform,
     pub(crate) last_auto_bounds: Vec2b,
+    pub(crate) last_live: bool,
     pub(crate) response: Response,
     pub(crate) bounds_modifications: Vec<BoundsModification>,
+    pub(crate) retained: ahash::HashMap<Id, RetainedSeries>,
 }
 
 impl<'a> PlotUi<'a> {
@@ -1918,11 +4101,47 @@ impl<'a> PlotUi<'a> {
             .push(BoundsModification::AutoBounds(auto_bounds.into()));
     }
 
+    /// Resume following the latest data, see [`Plot::follow_latest`].
+    pub fn go_live(&mut self) {
+        self.bounds_modifications.push(BoundsModification::GoLive);
+    }
+
+    /// Whether the plot followed the latest data in the last frame, e.g. to
+    /// show a "go live" button while it doesn't. See [`Plot::follow_latest`].
+    pub fn is_live(&self) -> bool {
+        self.last_live
+    }
+
     /// Can be used to check if the plot was hovered or clicked.
     pub fn response(&self) -> &Response {
         &self.response
     }
 
+    /// The series kept by this plot under `id`, created empty the first time,
+    /// to push the new samples of a stream to every frame instead of building
+    /// the whole series anew.
+    ///
+    /// ```
+    /// # use egui_plot::{Plot, PlotPoint};
+    /// # egui::__run_test_ui(|ui| {
+    /// # let new_samples: Vec<PlotPoint> = Vec::new();
+    /// Plot::new("telemetry").show(ui, |plot_ui| {
+    ///     let series = plot_ui.series("sensor");
+    ///     series.extend(new_samples);
+    ///     let line = series.line("Sensor");
+    ///     plot_ui.line(line);
+    /// });
+    /// # });
+    /// ```
+    pub fn series(&mut self, id: impl std::hash::Hash) -> &mut RetainedSeries {
+        self.retained.entry(Id::new(id)).or_default()
+    }
+
+    /// Forget the series kept under `id`, see [`Self::series`].
+    pub fn remove_series(&mut self, id: impl std::hash::Hash) {
+        self.retained.remove(&Id::new(id));
+    }
+
     /// Scale the plot bounds around a position in plot coordinates.
     ///
     /// Can be useful for implementing alternative plot navigation methods.
@@ -1952,6 +4171,20 @@ impl<'a> PlotUi<'a> {
         }
     }
 
+    /// Go back to the view before the last pan or zoom, if any.
+    ///
+    /// Continuous pans and zooms, like dragging or scrolling, are one step
+    /// each. See also [`Plot::view_history_keys`].
+    pub fn undo_view(&mut self) {
+        self.bounds_modifications.push(BoundsModification::UndoView);
+    }
+
+    /// Go forth to the view before the last [`Self::undo_view`], if the view
+    /// wasn't changed since.
+    pub fn redo_view(&mut self) {
+        self.bounds_modifications.push(BoundsModification::RedoView);
+    }
+
     /// The pointer position in plot coordinates. Independent of whether the
     /// pointer is in the plot area.
     pub fn pointer_coordinate(&self) -> Option<PlotPoint> {
@@ -2007,7 +4240,40 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(line));
     }
 
-    /// Add a polygon. The polygon has to be convex.
+    /// Add a parametric curve, sampled adaptively at the current zoom.
+    pub fn parametric_line(&mut self, mut line: crate::ParametricLine<'a>) {
+        // Give the stroke an automatic color if no color has been assigned.
+        if line.stroke.color == Color32::TRANSPARENT {
+            line.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(line));
+    }
+
+    /// Add the curve where a function of `(x, y)` is zero, traced over the
+    /// visible area.
+    pub fn implicit_curve(&mut self, mut curve: crate::ImplicitCurve<'a>) {
+        // Give the stroke an automatic color if no color has been assigned.
+        if curve.stroke.color == Color32::TRANSPARENT {
+            curve.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(curve));
+    }
+
+    /// Add a Bézier curve or spline.
+    pub fn bezier(&mut self, mut bezier: crate::CubicBezier<'a>) {
+        if bezier.series.is_empty() {
+            return;
+        }
+
+        // Give the stroke an automatic color if no color has been assigned.
+        if bezier.stroke.color == Color32::TRANSPARENT {
+            bezier.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(bezier));
+    }
+
+    /// Add a polygon. The polygon has to be convex, unless it has holes or a
+    /// fill rule.
     pub fn polygon(&mut self, mut polygon: crate::Polygon<'a>) {
         if polygon.series.is_empty() {
             return;
@@ -2029,6 +4295,15 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(text));
     }
 
+    /// Add an annotation.
+    pub fn annotation(&mut self, annotation: crate::Annotation) {
+        if annotation.text.is_empty() {
+            return;
+        }
+
+        self.items.push(Box::new(annotation));
+    }
+
     /// Add data points.
     pub fn points(&mut self, mut points: crate::Points<'a>) {
         if points.series.is_empty() {
@@ -2042,6 +4317,87 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(points));
     }
 
+    /// Add error bars.
+    pub fn error_bars(&mut self, mut error_bars: crate::ErrorBars<'a>) {
+        if error_bars.series.is_empty() {
+            return;
+        }
+
+        // Give the bars an automatic color if no color has been assigned.
+        if error_bars.stroke.color == Color32::TRANSPARENT {
+            error_bars.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(error_bars));
+    }
+
+    /// Add a confidence band.
+    pub fn band(&mut self, mut band: crate::Band) {
+        if band.samples.is_empty() {
+            return;
+        }
+
+        // Give the band an automatic color if no color has been assigned.
+        if band.color == Color32::TRANSPARENT {
+            band.color = self.auto_color();
+        }
+        self.items.push(Box::new(band));
+    }
+
+    /// Add event ticks on lanes.
+    pub fn events(&mut self, mut events: crate::Events) {
+        if events.lanes.is_empty() {
+            return;
+        }
+
+        // Give the ticks an automatic color if no color has been assigned.
+        if events.color == Color32::TRANSPARENT {
+            events.color = self.auto_color();
+        }
+        self.items.push(Box::new(events));
+    }
+
+    /// Add a stacked area chart, one item per series.
+    pub fn stacked_lines(&mut self, stack: crate::StackedLines) {
+        for band in stack.into_bands(|| self.auto_color()) {
+            self.items.push(Box::new(band));
+        }
+    }
+
+    /// Add a stem plot.
+    pub fn stem(&mut self, mut stem: crate::Stem<'a>) {
+        if stem.series.is_empty() {
+            return;
+        }
+
+        // Give the stems an automatic color if no color has been assigned.
+        if stem.stroke.color == Color32::TRANSPARENT {
+            stem.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(stem));
+    }
+
+    /// Add a vector field.
+    pub fn quiver(&mut self, mut quiver: crate::Quiver<'a>) {
+        if quiver.positions.is_empty() {
+            return;
+        }
+
+        // Give the arrows an automatic color if no color has been assigned.
+        if quiver.stroke.color == Color32::TRANSPARENT {
+            quiver.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(quiver));
+    }
+
+    /// Add streamlines of a vector field.
+    pub fn streamlines(&mut self, mut streamlines: crate::Streamlines) {
+        // Give the streamlines an automatic color if no color has been assigned.
+        if streamlines.stroke.color == Color32::TRANSPARENT {
+            streamlines.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(streamlines));
+    }
+
     /// Add arrows.
     pub fn arrows(&mut self, mut arrows: crate::Arrows<'a>) {
         if arrows.origins.is_empty() || arrows.tips.is_empty() {
@@ -2060,6 +4416,24 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(image));
     }
 
+    /// Add an image of scalar values, colored through a palette.
+    pub fn color_image(&mut self, image: crate::ColorImageItem) {
+        if image.values.is_empty() {
+            return;
+        }
+
+        self.items.push(Box::new(image));
+    }
+
+    /// Add a spectrogram.
+    pub fn spectrogram(&mut self, spectrogram: crate::Spectrogram<'a>) {
+        if spectrogram.buffer.is_empty() {
+            return;
+        }
+
+        self.items.push(Box::new(spectrogram));
+    }
+
     /// Add a horizontal line.
     /// Can be useful e.g. to show min/max bounds or similar.
     /// Always fills the full width of the plot.
@@ -2113,6 +4487,19 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(box_plot));
     }
 
+    /// Add a violin plot.
+    pub fn violin_plot(&mut self, mut violin_plot: crate::ViolinPlot) {
+        if violin_plot.violins.is_empty() {
+            return;
+        }
+
+        // Give the elements an automatic color if no color has been assigned.
+        if PlotItem::color(&violin_plot) == Color32::TRANSPARENT {
+            violin_plot = violin_plot.color(self.auto_color());
+        }
+        self.items.push(Box::new(violin_plot));
+    }
+
     /// Add a bar chart.
     pub fn bar_chart(&mut self, mut chart: crate::BarChart) {
         if chart.bars.is_empty() {
@@ -2126,6 +4513,75 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(chart));
     }
 
+    /// Add a waterfall chart.
+    pub fn waterfall(&mut self, mut waterfall: crate::Waterfall) {
+        if waterfall.steps.is_empty() {
+            return;
+        }
+
+        // Give the totals an automatic color if no color has been assigned.
+        if waterfall.total_color == Color32::TRANSPARENT {
+            waterfall.total_color = self.auto_color();
+        }
+        self.items.push(Box::new(waterfall));
+    }
+
+    /// Add a Gantt chart.
+    pub fn gantt(&mut self, mut gantt: crate::Gantt) {
+        if gantt.bars.is_empty() {
+            return;
+        }
+
+        // Give the bars an automatic color if no color has been assigned.
+        if gantt.color == Color32::TRANSPARENT {
+            gantt.color = self.auto_color();
+        }
+        self.items.push(Box::new(gantt));
+    }
+
+    /// Add a grouped bar chart, one [`crate::BarChart`] per series.
+    pub fn grouped_bars(&mut self, group: crate::GroupedBars) {
+        for chart in group.into_charts(|| self.auto_color()) {
+            self.bar_chart(chart);
+        }
+    }
+
+    /// Add a pie chart, one item per slice.
+    pub fn pie_chart(&mut self, pie: crate::PieChart) {
+        for slice in pie.into_slices(|| self.auto_color()) {
+            self.items.push(Box::new(slice));
+        }
+    }
+
+    /// Add a radar chart: its grid, then one item per series.
+    pub fn radar_chart(&mut self, radar: crate::RadarChart) {
+        let (grid, series) = radar.into_items(|| self.auto_color());
+        self.items.push(Box::new(grid));
+        for series in series {
+            self.items.push(Box::new(series));
+        }
+    }
+
+    /// Add a stacked bar chart, one [`crate::BarChart`] per segment.
+    pub fn stacked_bars(&mut self, stack: crate::StackedBars) {
+        for chart in stack.into_charts(|| self.auto_color()) {
+            self.bar_chart(chart);
+        }
+    }
+
+    /// Add contour lines.
+    pub fn contour(&mut self, contour: crate::Contour) {
+        self.items.push(Box::new(contour));
+    }
+
+    /// Add a 2D histogram.
+    pub fn hist2d(&mut self, hist2d: crate::Hist2d<'a>) {
+        if hist2d.series.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(hist2d));
+    }
+
     /// Add a heatmap.
     pub fn heatmap(&mut self, heatmap: crate::Heatmap) {
         if heatmap.values.is_empty() {
@@ -2174,4 +4630,16 @@ mod plot_tests {
         assert!((final_bounds.max[0] - MAX[0]).abs() <= f64::EPSILON);
         assert!((final_bounds.max[1] - MAX[1]).abs() <= f64::EPSILON);
     }
+
+    /// Labels of explicit ticks survive a formatter set afterwards.
+    #[test]
+    fn tick_labels_outlive_later_formatters() {
+        let plot = Plot::new("test_plot")
+            .x_ticks([Tick::new(0.0).label("start"), Tick::new(1.0)])
+            .x_axis_formatter(|mark, _range| format!("{} s", mark.value));
+        let axes = plot.labeled_axes(Axis::X);
+        let label = |value| (axes[0].formatter)(GridMark { value, step_size: 1.0 }, &(0.0..=1.0));
+        assert_eq!(label(0.0), "start");
+        assert_eq!(label(1.0), "1 s");
+    }
 }
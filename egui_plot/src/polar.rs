@@ -0,0 +1,417 @@
+//! A [`Plot`] in polar coordinates.
+
+use std::f64::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::Align2;
+use egui::Color32;
+use egui::Shape;
+use egui::Stroke;
+use egui::TextStyle;
+use egui::Ui;
+use emath::Vec2;
+
+use crate::Legend;
+use crate::Plot;
+use crate::PlotPoints;
+use crate::PlotResponse;
+use crate::PlotUi;
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::colors::color_from_strength;
+use crate::grid::nice_grid_marks;
+use crate::items::PlotGeometry;
+use crate::items::PlotItem;
+use crate::items::PlotItemBase;
+use crate::label::format_number;
+
+/// How angles are measured in a [`PolarPlot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PolarConfig {
+    degrees: bool,
+
+    /// Direction of `theta = 0`, in radians counter-clockwise from the
+    /// positive X axis.
+    theta_zero: f64,
+
+    clockwise: bool,
+    spokes: usize,
+}
+
+impl PolarConfig {
+    /// A full turn, in the units of theta.
+    fn full_turn(&self) -> f64 {
+        if self.degrees { 360.0 } else { TAU }
+    }
+
+    /// The screen-independent angle of `theta`, in radians counter-clockwise
+    /// from the positive X axis.
+    fn angle(&self, theta: f64) -> f64 {
+        let theta = if self.degrees { theta.to_radians() } else { theta };
+        if self.clockwise {
+            self.theta_zero - theta
+        } else {
+            self.theta_zero + theta
+        }
+    }
+
+    fn position(&self, theta: f64, r: f64) -> PlotPoint {
+        let angle = self.angle(theta);
+        PlotPoint::new(r * angle.cos(), r * angle.sin())
+    }
+
+    /// `(theta, r)` of a point, with theta in `[0, full turn)`.
+    fn polar(&self, point: &PlotPoint) -> (f64, f64) {
+        let angle = point.y.atan2(point.x) - self.theta_zero;
+        let theta = if self.clockwise { -angle } else { angle }.rem_euclid(TAU);
+        let theta = if self.degrees { theta.to_degrees() } else { theta };
+        (theta, point.x.hypot(point.y))
+    }
+}
+
+/// A plot in polar coordinates: items are given as `(theta, r)` instead of
+/// `(x, y)`, and the grid is made of concentric circles and radial spokes.
+///
+/// Zooming (with ctrl+scroll or a pinch) and dragging both act radially,
+/// keeping the pole at the center of the plot. Double-click to reset.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, PolarPlot};
+/// let pattern: Vec<[f64; 2]> = (0..=360)
+///     .map(|deg| [deg as f64, (deg as f64).to_radians().cos().abs()])
+///     .collect();
+/// PolarPlot::new("antenna").show(ui, |polar_ui| polar_ui.line(Line::new("gain", pattern)));
+/// # });
+/// ```
+pub struct PolarPlot<'a> {
+    plot: Plot<'a>,
+    config: PolarConfig,
+}
+
+impl<'a> PolarPlot<'a> {
+    /// Give a unique id for each plot within the same [`Ui`].
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            plot: Plot::new(id_salt)
+                .data_aspect(1.0)
+                .show_axes(false)
+                .show_grid(false)
+                .allow_zoom(false)
+                .allow_drag(false)
+                .allow_scroll(false)
+                .allow_boxed_zoom(false),
+            config: PolarConfig {
+                degrees: true,
+                theta_zero: 0.0,
+                clockwise: false,
+                spokes: 12,
+            },
+        }
+    }
+
+    /// Measure theta in degrees if `true`, in radians otherwise. Default:
+    /// `true`.
+    #[inline]
+    pub fn degrees(mut self, degrees: bool) -> Self {
+        self.config.degrees = degrees;
+        self
+    }
+
+    /// Direction of `theta = 0`, in degrees counter-clockwise from the right.
+    /// Use `90.0` for compass-style plots. Default: `0.0`.
+    #[inline]
+    pub fn theta_zero(mut self, degrees: f64) -> Self {
+        self.config.theta_zero = degrees.to_radians();
+        self
+    }
+
+    /// Increase theta clockwise instead of counter-clockwise. Default:
+    /// `false`.
+    #[inline]
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.config.clockwise = clockwise;
+        self
+    }
+
+    /// Number of radial spokes of the grid, evenly spaced. Default: `12`.
+    #[inline]
+    pub fn spokes(mut self, spokes: usize) -> Self {
+        self.config.spokes = spokes;
+        self
+    }
+
+    /// Show a legend including all named items.
+    #[inline]
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.plot = self.plot.legend(legend);
+        self
+    }
+
+    /// Width of plot. By default a plot will fill the ui it is in.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.plot = self.plot.width(width);
+        self
+    }
+
+    /// Height of plot. By default a plot will fill the ui it is in.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.plot = self.plot.height(height);
+        self
+    }
+
+    /// Interact with and add items to the plot and finally draw it.
+    pub fn show<R>(self, ui: &mut Ui, build_fn: impl FnOnce(&mut PolarPlotUi<'_, 'a>) -> R + 'a) -> PlotResponse<R> {
+        let config = self.config;
+        self.plot
+            .label_formatter(move |name, point| {
+                let (theta, r) = config.polar(point);
+                let unit = if config.degrees { "°" } else { "" };
+                let coordinates = format!("θ = {}{unit}\nr = {}", format_number(theta, 2), format_number(r, 3));
+                if name.is_empty() {
+                    coordinates
+                } else {
+                    format!("{name}\n{coordinates}")
+                }
+            })
+            .show(ui, move |plot_ui| {
+                let mut polar_ui = PolarPlotUi {
+                    plot_ui,
+                    config,
+                    max_r: 0.0,
+                };
+                polar_ui.navigate();
+                let inner = build_fn(&mut polar_ui);
+
+                // Draw the grid below everything else.
+                let grid = PolarGrid {
+                    base: PlotItemBase::new(String::new()),
+                    config,
+                    max_r: polar_ui.max_r,
+                };
+                polar_ui.plot_ui.items.insert(0, Box::new(grid));
+                inner
+            })
+    }
+}
+
+/// Provides methods to interact with a [`PolarPlot`] and add items to it.
+///
+/// Items added through it take their positions as `[theta, r]`.
+pub struct PolarPlotUi<'p, 'a> {
+    plot_ui: &'p mut PlotUi<'a>,
+    config: PolarConfig,
+
+    /// The largest radius of the items added so far.
+    max_r: f64,
+}
+
+impl<'a> PolarPlotUi<'_, 'a> {
+    /// Add a line through points given as `[theta, r]`.
+    pub fn line(&mut self, mut line: crate::Line<'a>) {
+        self.convert(&mut line.series);
+        self.plot_ui.line(line);
+    }
+
+    /// Add data points given as `[theta, r]`.
+    pub fn points(&mut self, mut points: crate::Points<'a>) {
+        self.convert(&mut points.series);
+        self.plot_ui.points(points);
+    }
+
+    /// Add a polygon with corners given as `[theta, r]`. It has to be convex
+    /// once converted, unless it has holes or a fill rule.
+    pub fn polygon(&mut self, mut polygon: crate::Polygon<'a>) {
+        self.convert(&mut polygon.series);
+        for hole in &mut polygon.holes {
+            self.convert(hole);
+        }
+        self.plot_ui.polygon(polygon);
+    }
+
+    /// The plot position of `(theta, r)`, to place other items.
+    pub fn to_cartesian(&self, theta: f64, r: f64) -> PlotPoint {
+        self.config.position(theta, r)
+    }
+
+    /// The pointer position as `(theta, r)`, if any.
+    pub fn pointer_polar(&self) -> Option<(f64, f64)> {
+        self.plot_ui.pointer_coordinate().map(|point| self.config.polar(&point))
+    }
+
+    /// The underlying plot, to add items in cartesian coordinates.
+    pub fn plot_ui(&mut self) -> &mut PlotUi<'a> {
+        self.plot_ui
+    }
+
+    /// Replace `[theta, r]` points by their plot positions. Generators are
+    /// evaluated over a full turn.
+    fn convert(&mut self, series: &mut PlotPoints<'a>) {
+        series.generate_points(0.0..=self.config.full_turn());
+        let points: Vec<PlotPoint> = series
+            .points()
+            .iter()
+            .map(|point| self.config.position(point.x, point.y))
+            .collect();
+        for point in &points {
+            self.max_r = self.max_r.max(point.x.hypot(point.y));
+        }
+        *series = PlotPoints::Owned(points);
+    }
+
+    /// Zoom around the pole, with ctrl+scroll or dragging.
+    fn navigate(&mut self) {
+        let response = self.plot_ui.response();
+        if !response.contains_pointer() && !response.dragged() {
+            return;
+        }
+        let mut zoom = self.plot_ui.ctx().input(|i| i.zoom_delta()) as f64;
+
+        // Keep the grabbed radius under the pointer.
+        let delta = response.drag_delta();
+        if let (true, Some(pos)) = (delta != Vec2::ZERO, response.interact_pointer_pos()) {
+            let before = self.plot_ui.plot_from_screen(pos - delta);
+            let after = self.plot_ui.plot_from_screen(pos);
+            let (r_before, r_after) = (before.x.hypot(before.y), after.x.hypot(after.y));
+            if r_before > 0.0 && r_after > 0.0 {
+                zoom *= r_after / r_before;
+            }
+        }
+
+        if zoom != 1.0 && zoom.is_finite() {
+            self.plot_ui
+                .zoom_bounds(Vec2::splat(zoom as f32), PlotPoint::new(0.0, 0.0));
+        }
+    }
+}
+
+/// The circles, spokes and their labels of a [`PolarPlot`].
+struct PolarGrid {
+    base: PlotItemBase,
+    config: PolarConfig,
+    max_r: f64,
+}
+
+impl PlotItem for PolarGrid {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let bounds = transform.bounds();
+        let center = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+        let px_per_unit = transform.dpos_dvalue_x().abs() as f32;
+
+        // Rings out to the farthest corner; labels on the ones fully visible.
+        let far = bounds.min()[0]
+            .abs()
+            .max(bounds.max()[0].abs())
+            .hypot(bounds.min()[1].abs().max(bounds.max()[1].abs()));
+        let near = [-bounds.min()[0], bounds.max()[0], -bounds.min()[1], bounds.max()[1]]
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+            .max(0.0);
+        let Some(step) = nice_grid_marks((0.0, near), 4).first().map(|mark| mark.step_size) else {
+            return;
+        };
+
+        let stroke = Stroke::new(1.0, color_from_strength(ui, 0.2));
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = color_from_strength(ui, 0.8);
+        let label_angle = self
+            .config
+            .angle(0.5 * self.config.full_turn() / self.config.spokes.max(1) as f64);
+        let label_dir = Vec2::new(label_angle.cos() as f32, -label_angle.sin() as f32);
+
+        let mut outer = 0.0;
+        for k in 1..=(far / step).ceil() as usize {
+            let r = k as f64 * step;
+            let radius = r as f32 * px_per_unit;
+            shapes.push(Shape::circle_stroke(center, radius, stroke));
+            if r <= near {
+                outer = radius;
+                ui.fonts_mut(|f| {
+                    shapes.push(Shape::text(
+                        f,
+                        center + radius * label_dir,
+                        Align2::LEFT_BOTTOM,
+                        format_number(r, 3),
+                        font_id.clone(),
+                        text_color,
+                    ));
+                });
+            }
+        }
+
+        let far_px = far as f32 * px_per_unit;
+        for spoke in 0..self.config.spokes {
+            let theta = self.config.full_turn() * spoke as f64 / self.config.spokes as f64;
+            let angle = self.config.angle(theta);
+            let dir = Vec2::new(angle.cos() as f32, -angle.sin() as f32);
+            shapes.push(Shape::line_segment([center, center + far_px * dir], stroke));
+
+            let text = if self.config.degrees {
+                format!("{}°", format_number(theta, 1))
+            } else {
+                format_number(theta, 2)
+            };
+            ui.fonts_mut(|f| {
+                shapes.push(Shape::text(
+                    f,
+                    center + (outer + 8.0) * dir,
+                    Align2::CENTER_CENTER,
+                    text,
+                    font_id.clone(),
+                    text_color,
+                ));
+            });
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if self.max_r > 0.0 {
+            PlotBounds::from_min_max([-self.max_r; 2], [self.max_r; 2])
+        } else {
+            PlotBounds::NOTHING
+        }
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polar_round_trip() {
+        let config = PolarConfig {
+            degrees: true,
+            theta_zero: 90_f64.to_radians(),
+            clockwise: true,
+            spokes: 12,
+        };
+        // Compass style: 90° points right.
+        let point = config.position(90.0, 2.0);
+        assert!((point.x - 2.0).abs() < 1e-12 && point.y.abs() < 1e-12);
+
+        let (theta, r) = config.polar(&config.position(250.0, 1.5));
+        assert!((theta - 250.0).abs() < 1e-9);
+        assert!((r - 1.5).abs() < 1e-12);
+    }
+}
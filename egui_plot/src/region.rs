@@ -0,0 +1,196 @@
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use emath::Pos2;
+use emath::Rect;
+use emath::vec2;
+
+use crate::axis::PlotTransform;
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+
+/// Radius of the handle that deletes a region, in ui points.
+const DELETE_HANDLE_RADIUS: f32 = 6.0;
+
+/// What dragging creates in [`crate::Plot::region_mode`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionKind {
+    /// A range of X over the full height of the plot, like a vertical
+    /// [`crate::Span`], e.g. to label a time interval.
+    XSpan,
+
+    /// A rectangle.
+    Rect,
+}
+
+/// A region created by dragging over the plot, see
+/// [`crate::Plot::region_mode`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlotRegion {
+    /// Whether the region is a span or a rectangle.
+    pub kind: RegionKind,
+
+    /// The area of the region, in plot coordinates of the primary axes. The
+    /// Y range of an [`RegionKind::XSpan`] is infinite.
+    pub bounds: PlotBounds,
+}
+
+impl PlotRegion {
+    /// The region on screen.
+    pub(crate) fn screen_rect(&self, transform: &PlotTransform) -> Rect {
+        let frame = transform.frame();
+        let x = [self.bounds.min[0], self.bounds.max[0]].map(|x| transform.position_from_point_x(x));
+        let y = match self.kind {
+            RegionKind::XSpan => [frame.top(), frame.bottom()],
+            RegionKind::Rect => [self.bounds.min[1], self.bounds.max[1]].map(|y| transform.position_from_point_y(y)),
+        };
+        Rect::from_two_pos(Pos2::new(x[0], y[0]), Pos2::new(x[1], y[1]))
+    }
+
+    /// Where the handle that deletes the region is on screen.
+    pub(crate) fn delete_handle(&self, transform: &PlotTransform) -> Pos2 {
+        let rect = self.screen_rect(transform);
+        let inset = (DELETE_HANDLE_RADIUS + 2.0)
+            .min(0.5 * rect.width())
+            .min(0.5 * rect.height());
+        rect.right_top() + vec2(-inset, inset)
+    }
+
+    /// Whether `pointer` is on the handle that deletes the region.
+    pub(crate) fn on_delete_handle(&self, pointer: Pos2, transform: &PlotTransform) -> bool {
+        self.delete_handle(transform).distance(pointer) <= DELETE_HANDLE_RADIUS
+    }
+
+    /// The drag that resizes the region by the edges within `radius` of
+    /// `pointer`, if there are any.
+    pub(crate) fn resize_at(
+        &self,
+        index: usize,
+        pointer: Pos2,
+        transform: &PlotTransform,
+        radius: f32,
+    ) -> Option<RegionDrag> {
+        let rect = self.screen_rect(transform);
+        if !rect.expand(radius).contains(pointer) {
+            return None;
+        }
+        // Drag the edge near the pointer, keeping the opposite edge in place.
+        let near = |a: f32, b: f32| (a - b).abs() <= radius;
+        let anchor_x = if near(pointer.x, transform.position_from_point_x(self.bounds.min[0])) {
+            Some(self.bounds.max[0])
+        } else if near(pointer.x, transform.position_from_point_x(self.bounds.max[0])) {
+            Some(self.bounds.min[0])
+        } else {
+            None
+        };
+        let anchor_y = match self.kind {
+            RegionKind::Rect if near(pointer.y, transform.position_from_point_y(self.bounds.min[1])) => {
+                Some(self.bounds.max[1])
+            }
+            RegionKind::Rect if near(pointer.y, transform.position_from_point_y(self.bounds.max[1])) => {
+                Some(self.bounds.min[1])
+            }
+            RegionKind::XSpan | RegionKind::Rect => None,
+        };
+        (anchor_x.is_some() || anchor_y.is_some()).then_some(RegionDrag {
+            index,
+            anchor: [anchor_x, anchor_y],
+        })
+    }
+
+    /// Draw the region, with its delete handle if `handle` is set to whether
+    /// the handle is hovered.
+    pub(crate) fn paint(&self, ui: &Ui, transform: &PlotTransform, handle: Option<bool>, shapes: &mut Vec<Shape>) {
+        let visuals = ui.visuals();
+        let rect = self.screen_rect(transform);
+        let stroke = visuals.selection.stroke;
+        shapes.push(Shape::rect_filled(
+            rect,
+            0.0,
+            visuals.selection.bg_fill.gamma_multiply(0.25),
+        ));
+        shapes.push(Shape::rect_stroke(rect, 0.0, stroke, egui::StrokeKind::Inside));
+
+        let Some(handle_hovered) = handle else {
+            return;
+        };
+        let center = self.delete_handle(transform);
+        let color = if handle_hovered {
+            visuals.strong_text_color()
+        } else {
+            visuals.text_color()
+        };
+        let arm = 0.5 * DELETE_HANDLE_RADIUS;
+        shapes.push(Shape::circle_filled(
+            center,
+            DELETE_HANDLE_RADIUS,
+            visuals.extreme_bg_color,
+        ));
+        for direction in [vec2(arm, arm), vec2(arm, -arm)] {
+            shapes.push(Shape::line_segment(
+                [center - direction, center + direction],
+                Stroke::new(1.5, color),
+            ));
+        }
+    }
+}
+
+/// A region being created or resized, see [`crate::Plot::region_mode`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RegionDrag {
+    /// The index of the region in the memory.
+    pub index: usize,
+
+    /// For each axis, the value the region keeps while its other edge follows
+    /// the pointer, or `None` if the region keeps its range on that axis.
+    pub anchor: [Option<f64>; 2],
+}
+
+impl RegionDrag {
+    /// Stretch `region` from the anchors to `pointer`.
+    pub(crate) fn apply(&self, region: &mut PlotRegion, pointer: PlotPoint) {
+        for (d, value) in [pointer.x, pointer.y].into_iter().enumerate() {
+            if let Some(anchor) = self.anchor[d] {
+                region.bounds.min[d] = anchor.min(value);
+                region.bounds.max[d] = anchor.max(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn edges_resize_and_keep_the_opposite_edge() {
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]), false);
+        let mut region = PlotRegion {
+            kind: RegionKind::Rect,
+            bounds: PlotBounds::from_min_max([2.0, 2.0], [6.0, 6.0]),
+        };
+
+        // Near the left edge, halfway up.
+        let pointer = transform.position_from_point(&PlotPoint::new(2.1, 4.0));
+        let Some(drag) = region.resize_at(0, pointer, &transform, 3.0) else {
+            panic!("the left edge is near");
+        };
+        assert_eq!(drag.anchor, [Some(6.0), None]);
+
+        // Dragging the left edge past the right one flips the region.
+        drag.apply(&mut region, PlotPoint::new(8.0, 1.0));
+        assert_eq!(region.bounds, PlotBounds::from_min_max([6.0, 2.0], [8.0, 6.0]));
+
+        let inside = transform.position_from_point(&PlotPoint::new(7.0, 4.0));
+        assert!(
+            region.resize_at(0, inside, &transform, 3.0).is_none(),
+            "no edge is near"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::data::PlotPoints;
+use crate::items::Line;
+use crate::items::Points;
+
+/// A series kept by the plot between frames, to push new samples to instead
+/// of building it from scratch every frame, see [`crate::PlotUi::series`].
+///
+/// The bounds are updated as points are pushed, and the caches for drawing
+/// large lines are extended by the points pushed since the last frame rather
+/// than rebuilt, so that drawing doesn't go through the earlier points again.
+///
+/// Push the new points before drawing the series in a frame: points pushed
+/// while the series is drawn are pushed to a copy of it.
+#[derive(Debug)]
+pub struct RetainedSeries {
+    points: Arc<Vec<PlotPoint>>,
+    bounds: PlotBounds,
+    generation: u64,
+}
+
+impl Default for RetainedSeries {
+    fn default() -> Self {
+        Self {
+            points: Default::default(),
+            bounds: PlotBounds::NOTHING,
+            generation: next_generation(),
+        }
+    }
+}
+
+impl Clone for RetainedSeries {
+    /// A copy in a generation of its own, since the copies may be pushed
+    /// different points.
+    fn clone(&self) -> Self {
+        Self {
+            points: self.points.clone(),
+            bounds: self.bounds,
+            generation: next_generation(),
+        }
+    }
+}
+
+/// A generation no series had before.
+fn next_generation() -> u64 {
+    static GENERATION: AtomicU64 = AtomicU64::new(0);
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+impl RetainedSeries {
+    /// Append a point.
+    #[inline]
+    pub fn push(&mut self, point: impl Into<PlotPoint>) {
+        let point = point.into();
+        self.bounds.extend_with(&point);
+        Arc::make_mut(&mut self.points).push(point);
+    }
+
+    /// Remove all points.
+    pub fn clear(&mut self) {
+        Arc::make_mut(&mut self.points).clear();
+        self.bounds = PlotBounds::NOTHING;
+        self.generation = next_generation();
+    }
+
+    /// Which generation the points are: the same as long as points are only
+    /// pushed, and a new one after [`Self::clear`] and for copies. Drawing
+    /// keeps what it cached for a generation, and only goes through the
+    /// points pushed since.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The points, in the order they were pushed.
+    #[inline]
+    pub fn points(&self) -> &[PlotPoint] {
+        &self.points
+    }
+
+    /// The number of points.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether no point was pushed since creation or the last clear.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The bounds of all points.
+    #[inline]
+    pub fn bounds(&self) -> PlotBounds {
+        self.bounds
+    }
+
+    /// A line through the points, sharing them with the series.
+    pub fn line(&self, name: impl Into<String>) -> Line<'static> {
+        Line::new(name, self.plot_points())
+    }
+
+    /// Markers at the points, sharing them with the series.
+    pub fn markers(&self, name: impl Into<String>) -> Points<'static> {
+        Points::new(name, self.plot_points())
+    }
+
+    fn plot_points(&self) -> PlotPoints<'static> {
+        PlotPoints::Shared(self.points.clone(), self.bounds, self.generation)
+    }
+}
+
+impl<P: Into<PlotPoint>> Extend<P> for RetainedSeries {
+    fn extend<T: IntoIterator<Item = P>>(&mut self, iter: T) {
+        let points = Arc::make_mut(&mut self.points);
+        for point in iter {
+            let point = point.into();
+            self.bounds.extend_with(&point);
+            points.push(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Context;
+    use egui::Id;
+    use emath::Rect;
+    use emath::pos2;
+
+    use super::*;
+    use crate::axis::PlotTransform;
+    use crate::downsample::DownsampleMethod;
+    use crate::lod;
+    use crate::lod::LodPyramid;
+
+    #[test]
+    fn pushed_points_are_shared_with_lines() {
+        let mut series = RetainedSeries::default();
+        series.extend([[0.0, 1.0], [2.0, -1.0]]);
+        series.push([1.0, 3.0]);
+        assert_eq!(series.bounds(), PlotBounds::from_min_max([0.0, -1.0], [2.0, 3.0]));
+
+        let points = series.plot_points();
+        assert_eq!(points.points().len(), 3);
+        assert_eq!(points.bounds(), series.bounds());
+        assert!(
+            std::ptr::eq(points.points(), series.points()),
+            "the points aren't copied"
+        );
+    }
+
+    #[test]
+    fn pushing_points_does_not_go_through_the_earlier_ones() {
+        let ctx = Context::default();
+        let id = Id::new("line");
+        let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::from_min_max([0.0, 0.0], [11_000.0, 7.0]), false);
+        let point = |x: usize| PlotPoint::new(x as f64, (x % 7) as f64);
+        let draw = |series: &RetainedSeries| {
+            let points = series.plot_points();
+            let visible = 0..points.view().len();
+            let key = points.series_key(None);
+            let pyramid = lod::cached_pyramid(&ctx, id.with("lod"), points.view(), key);
+            (
+                pyramid.indices(points.view(), visible.clone(), &transform),
+                DownsampleMethod::MinMax.indices(&ctx, id, points.view(), key, visible, &transform),
+            )
+        };
+
+        let mut series = RetainedSeries::default();
+        series.extend((0..10_000).map(point));
+        draw(&series);
+
+        // Change an early point behind the back of the series, so that only
+        // drawing that goes through the earlier points again would see it.
+        Arc::make_mut(&mut series.points)[10].y = 1e9;
+        series.extend((10_000..11_000).map(point));
+        let expected: Vec<PlotPoint> = (0..11_000).map(point).collect();
+        let fresh = (
+            LodPyramid::new(expected[..].into()).indices(expected[..].into(), 0..expected.len(), &transform),
+            DownsampleMethod::MinMax.indices(
+                &ctx,
+                id.with("fresh"),
+                expected[..].into(),
+                None,
+                0..expected.len(),
+                &transform,
+            ),
+        );
+        assert_eq!(draw(&series), fresh, "only the pushed points are gone through");
+
+        // After clearing, the caches are built anew.
+        series.clear();
+        series.extend((0..11_000).map(point));
+        Arc::make_mut(&mut series.points)[10].y = 1e9;
+        let (_, columns) = draw(&series);
+        assert!(columns.contains(&10), "the changed point is seen");
+    }
+}
@@ -0,0 +1,127 @@
+use egui::Id;
+
+use crate::bounds::PlotBounds;
+use crate::bounds::PlotPoint;
+use crate::items::PlotItem;
+
+/// The points of one plot item that were selected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectedPoints {
+    /// The id of the item.
+    pub id: Id,
+
+    /// The name of the item.
+    pub name: String,
+
+    /// The indices of the selected points in the item's data.
+    pub indices: Vec<usize>,
+
+    /// The selected points, in the same order as [`Self::indices`].
+    pub points: Vec<PlotPoint>,
+}
+
+impl SelectedPoints {
+    /// The points of `item` for which `contains` is true, if there are any.
+    ///
+    /// Only items with [`crate::PlotGeometry::Points`] or
+    /// [`crate::PlotGeometry::PointsF32`] can be selected.
+    pub(crate) fn of_item(item: &dyn PlotItem, contains: impl Fn(&PlotPoint) -> bool) -> Option<Self> {
+        let points = item.geometry().points()?;
+        let (indices, points): (Vec<usize>, Vec<PlotPoint>) =
+            points.iter().enumerate().filter(|(_, point)| contains(point)).unzip();
+        (!indices.is_empty()).then(|| Self {
+            id: item.id(),
+            name: item.name().to_owned(),
+            indices,
+            points,
+        })
+    }
+}
+
+/// The result of dragging out a selection box, see [`crate::Plot::box_select`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxSelection {
+    /// The selected area, in plot coordinates of the primary axes.
+    pub bounds: PlotBounds,
+
+    /// The selected points of each item with at least one point inside the
+    /// box, in the order the items were added.
+    pub items: Vec<SelectedPoints>,
+}
+
+/// The result of drawing a lasso, see [`crate::Plot::lasso_select`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LassoSelection {
+    /// The lasso, in plot coordinates of the primary axes. It is implicitly
+    /// closed from the last point back to the first.
+    pub polygon: Vec<PlotPoint>,
+
+    /// The selected points of each item with at least one point inside the
+    /// lasso, in the order the items were added.
+    pub items: Vec<SelectedPoints>,
+}
+
+/// Whether `point` lies within `bounds`, edges included.
+pub(crate) fn bounds_contain(bounds: &PlotBounds, point: &PlotPoint) -> bool {
+    (bounds.min[0]..=bounds.max[0]).contains(&point.x) && (bounds.min[1]..=bounds.max[1]).contains(&point.y)
+}
+
+/// Whether `point` lies within the closed `polygon`, by the even-odd rule.
+pub(crate) fn polygon_contains(polygon: &[PlotPoint], point: &PlotPoint) -> bool {
+    let mut inside = false;
+    let Some(mut previous) = polygon.last() else {
+        return false;
+    };
+    for current in polygon {
+        if (current.y > point.y) != (previous.y > point.y) {
+            let x = current.x + (point.y - current.y) / (previous.y - current.y) * (previous.x - current.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Points;
+
+    #[test]
+    fn only_points_inside_are_selected() {
+        let points = Points::new("samples", vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 0.5]]);
+        let bounds = PlotBounds::from_min_max([0.5, 0.5], [3.0, 2.0]);
+
+        let selected = SelectedPoints::of_item(&points, |p| bounds_contain(&bounds, p));
+        let Some(selected) = selected else {
+            panic!("three points are inside");
+        };
+        assert_eq!(selected.name, "samples");
+        assert_eq!(selected.indices, [1, 2, 3]);
+        assert_eq!(selected.points[0], PlotPoint::new(1.0, 1.0));
+
+        let empty = PlotBounds::from_min_max([5.0, 5.0], [6.0, 6.0]);
+        assert!(
+            SelectedPoints::of_item(&points, |p| bounds_contain(&empty, p)).is_none(),
+            "nothing is inside"
+        );
+    }
+
+    #[test]
+    fn lasso_follows_concave_outlines() {
+        // An L shape, missing the top right quarter of the unit square.
+        let polygon: Vec<PlotPoint> = [[0.0, 0.0], [1.0, 0.0], [1.0, 0.5], [0.5, 0.5], [0.5, 1.0], [0.0, 1.0]]
+            .into_iter()
+            .map(PlotPoint::from)
+            .collect();
+        assert!(
+            polygon_contains(&polygon, &PlotPoint::new(0.25, 0.75)),
+            "in the upright"
+        );
+        assert!(polygon_contains(&polygon, &PlotPoint::new(0.75, 0.25)), "in the foot");
+        assert!(!polygon_contains(&polygon, &PlotPoint::new(0.75, 0.75)), "in the notch");
+        assert!(!polygon_contains(&[], &PlotPoint::new(0.0, 0.0)), "empty lasso");
+    }
+}
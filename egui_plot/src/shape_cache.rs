@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use egui::Color32;
+use egui::Id;
+use egui::Shape;
+use egui::Ui;
+use emath::Rect;
+
+use crate::axis::Axis;
+use crate::axis::PlotTransform;
+use crate::axis::YAxis;
+use crate::bounds::PlotBounds;
+use crate::items::PlotItem;
+
+/// Everything the shapes of an item with [`PlotItem::cache_key`] depend on,
+/// to check whether cached shapes can be reused.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ShapeCacheKey {
+    key: Id,
+    frame: Rect,
+    bounds: PlotBounds,
+
+    /// Tells inverted axes apart.
+    dpos_dvalue: [f64; 2],
+    y_axis: YAxis,
+    color: Color32,
+    highlight: bool,
+    dark_mode: bool,
+    pixels_per_point: f32,
+}
+
+impl ShapeCacheKey {
+    fn of(ui: &Ui, item: &dyn PlotItem, key: Id, transform: &PlotTransform) -> Self {
+        Self {
+            key,
+            frame: *transform.frame(),
+            bounds: *transform.bounds(),
+            dpos_dvalue: transform.dpos_dvalue(),
+            y_axis: item.y_axis(),
+            color: item.color(),
+            highlight: item.highlighted(),
+            dark_mode: ui.visuals().dark_mode,
+            pixels_per_point: ui.pixels_per_point(),
+        }
+    }
+}
+
+/// Add the shapes of `item` to `shapes`, from the cache under `id` if the
+/// item has a [`PlotItem::cache_key`] and nothing they depend on changed
+/// since they were cached.
+///
+/// With an axis transform, the shapes aren't cached, since transforms can't
+/// be compared.
+pub(crate) fn item_shapes(ui: &Ui, id: Id, item: &dyn PlotItem, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    let transformed = transform.axis_transform(Axis::X).is_some() || transform.axis_transform(Axis::Y).is_some();
+    let Some(key) = item.cache_key().filter(|_| !transformed) else {
+        item.shapes(ui, transform, shapes);
+        return;
+    };
+
+    let key = ShapeCacheKey::of(ui, item, key, transform);
+    let cached = ui
+        .ctx()
+        .data(|data| data.get_temp::<(ShapeCacheKey, Arc<Vec<Shape>>)>(id));
+    if let Some((cached_key, cached_shapes)) = cached {
+        if cached_key == key {
+            shapes.extend(cached_shapes.iter().cloned());
+            return;
+        }
+    }
+
+    let mut item_shapes = Vec::new();
+    item.shapes(ui, transform, &mut item_shapes);
+    shapes.extend(item_shapes.iter().cloned());
+    ui.ctx()
+        .data_mut(|data| data.insert_temp(id, (key, Arc::new(item_shapes))));
+}
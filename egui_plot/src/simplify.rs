@@ -0,0 +1,85 @@
+use emath::Pos2;
+
+/// The vertices of the path `points` to draw so that it moves by at most
+/// `tolerance`, picked with the Douglas–Peucker algorithm: keep the vertex
+/// farthest from the segment between the first and the last one if it is
+/// farther than `tolerance`, and go on with the two halves.
+///
+/// The first and the last vertex are always kept.
+pub(crate) fn simplify(points: &[Pos2], tolerance: f32) -> Vec<Pos2> {
+    if points.len() < 3 || tolerance.is_nan() || tolerance <= 0.0 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // The halves still to simplify, on a stack rather than by recursion, so
+    // that long paths don't overflow the call stack.
+    let mut halves = vec![(0, points.len() - 1)];
+    let tolerance_sq = tolerance * tolerance;
+    while let Some((start, end)) = halves.pop() {
+        let (a, b) = (points[start], points[end]);
+        let (farthest, distance_sq) = (start + 1..end)
+            .map(|index| (index, distance_sq_to_segment(points[index], a, b)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((start, 0.0));
+        if distance_sq > tolerance_sq {
+            keep[farthest] = true;
+            halves.push((start, farthest));
+            halves.push((farthest, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
+/// The squared distance of `point` to the segment from `a` to `b`.
+///
+/// To the segment rather than the line through it, so vertices where the
+/// path turns back are kept.
+fn distance_sq_to_segment(point: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    let t = if length_sq > 0.0 {
+        ((point - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance_sq(a + t * ab)
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::pos2;
+
+    use super::*;
+
+    #[test]
+    fn sub_pixel_wiggles_are_removed() {
+        let wiggly: Vec<Pos2> = (0..1_000)
+            .map(|i| pos2(i as f32 * 0.1, if i % 2 == 0 { 0.0 } else { 0.2 }))
+            .collect();
+        let simplified = simplify(&wiggly, 0.5);
+        assert_eq!(simplified, vec![wiggly[0], wiggly[999]], "only the ends are left");
+
+        // A spike and a turn back are kept.
+        let path = [
+            pos2(0.0, 0.0),
+            pos2(5.0, 4.1),
+            pos2(10.0, 8.0),
+            pos2(15.0, 0.0),
+            pos2(-5.0, 0.0),
+        ];
+        assert_eq!(
+            simplify(&path, 0.5),
+            vec![path[0], path[2], path[3], path[4]],
+            "visible vertices are kept"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::bounds::PlotPoint;
+
+/// What dragged points and lines snap to, see [`crate::Plot::drag_snap`].
+pub enum DragSnap<'a> {
+    /// The grid lines near the pointer.
+    Grid,
+
+    /// The points of other items near the pointer.
+    Points,
+
+    /// Where the function moves the pointer, in the coordinates of the
+    /// dragged item's axes, e.g. to whole seconds.
+    Custom(Box<dyn Fn(PlotPoint) -> PlotPoint + 'a>),
+}
+
+impl<'a> DragSnap<'a> {
+    /// Snap to where `snap` moves the pointer.
+    pub fn custom(snap: impl Fn(PlotPoint) -> PlotPoint + 'a) -> Self {
+        Self::Custom(Box::new(snap))
+    }
+}
+
+/// The candidate nearest to `pos`, if it is within `radius`, and `pos`
+/// otherwise.
+pub(crate) fn nearest_within(pos: f32, candidates: impl IntoIterator<Item = f32>, radius: f32) -> f32 {
+    candidates
+        .into_iter()
+        .filter(|candidate| (candidate - pos).abs() <= radius)
+        .min_by(|a, b| (a - pos).abs().total_cmp(&(b - pos).abs()))
+        .unwrap_or(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_only_within_the_radius() {
+        let lines = [0.0, 10.0, 20.0];
+        assert_eq!(nearest_within(8.0, lines, 3.0), 10.0);
+        assert_eq!(nearest_within(12.5, lines, 3.0), 10.0);
+        assert_eq!(nearest_within(15.0, lines, 3.0), 15.0);
+        assert_eq!(nearest_within(15.0, [], 3.0), 15.0);
+    }
+}